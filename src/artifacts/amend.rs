@@ -0,0 +1,591 @@
+//! Section-Level Amendments
+//!
+//! Adds, replaces or removes a single `##` section (or a `###` principle
+//! inside one) while leaving the rest of the document untouched. Every
+//! amendment bumps the document's semantic version, refreshes its
+//! `**Last Amended**` date and appends an entry to `## Amendment History`,
+//! following spec-kit's constitution versioning rules.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Range;
+
+use super::markdown::MarkdownDocument;
+
+/// Heading of the section that records amendments
+pub const HISTORY_SECTION: &str = "Amendment History";
+
+/// Marker of the version metadata line
+const VERSION_MARKER: &str = "**Version**:";
+
+/// Marker of the amendment date on the version line
+const AMENDED_MARKER: &str = "**Last Amended**:";
+
+/// What an amendment does to its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AmendAction {
+    /// Add a section or principle that does not exist yet
+    Add,
+
+    /// Replace the body of an existing section or principle
+    Replace,
+
+    /// Remove an existing section or principle
+    Remove,
+}
+
+impl AmendAction {
+    /// Version bump implied by the action
+    ///
+    /// Removals are backward incompatible (major), additions extend the
+    /// document (minor) and replacements are treated as clarifications
+    /// (patch) unless the caller says otherwise.
+    pub fn default_bump(&self) -> VersionBump {
+        match self {
+            Self::Add => VersionBump::Minor,
+            Self::Replace => VersionBump::Patch,
+            Self::Remove => VersionBump::Major,
+        }
+    }
+
+    fn past_tense(&self) -> &'static str {
+        match self {
+            Self::Add => "Added",
+            Self::Replace => "Replaced",
+            Self::Remove => "Removed",
+        }
+    }
+}
+
+/// Semantic version component to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionBump {
+    /// Incompatible change (removed or redefined section)
+    Major,
+
+    /// Backward-compatible addition
+    Minor,
+
+    /// Clarification or wording fix
+    Patch,
+}
+
+impl fmt::Display for VersionBump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Semantic version of an amended document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    /// Major version
+    pub major: u32,
+
+    /// Minor version
+    pub minor: u32,
+
+    /// Patch version
+    pub patch: u32,
+}
+
+impl Version {
+    /// Version assumed for documents without version metadata
+    pub const INITIAL: Self = Self {
+        major: 1,
+        minor: 0,
+        patch: 0,
+    };
+
+    /// Parse `MAJOR.MINOR.PATCH` (a missing patch or minor counts as 0)
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().trim_start_matches('v').split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+        let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    /// The next version for a bump
+    pub fn bump(self, bump: VersionBump) -> Self {
+        match bump {
+            VersionBump::Major => Self {
+                major: self.major + 1,
+                minor: 0,
+                patch: 0,
+            },
+            VersionBump::Minor => Self {
+                minor: self.minor + 1,
+                patch: 0,
+                ..self
+            },
+            VersionBump::Patch => Self {
+                patch: self.patch + 1,
+                ..self
+            },
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A section-level change to a document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Amendment {
+    /// What to do
+    pub action: AmendAction,
+
+    /// `##` section to change (or that contains the principle)
+    pub section: String,
+
+    /// `###` principle inside the section to change instead of the section
+    #[serde(default)]
+    pub principle: Option<String>,
+
+    /// New body (required for add and replace)
+    #[serde(default)]
+    pub content: Option<String>,
+
+    /// Version bump, overriding the action's default
+    #[serde(default)]
+    pub bump: Option<VersionBump>,
+
+    /// Why the change was made, recorded in the history entry
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+impl Amendment {
+    /// Human-readable description of the target
+    pub fn target(&self) -> String {
+        match &self.principle {
+            Some(principle) => format!("principle \"{}\" in \"{}\"", principle, self.section),
+            None => format!("section \"{}\"", self.section),
+        }
+    }
+}
+
+/// Result of applying an amendment
+#[derive(Debug, Clone)]
+pub struct AmendOutcome {
+    /// Amended document
+    pub content: String,
+
+    /// Version before the amendment (`None` if the document was unversioned)
+    pub previous_version: Option<Version>,
+
+    /// Version after the amendment
+    pub version: Version,
+
+    /// Bump that was applied
+    pub bump: VersionBump,
+
+    /// History entry that was appended
+    pub entry: String,
+}
+
+/// Apply an amendment to a document
+pub fn amend_document(
+    document: &str,
+    amendment: &Amendment,
+    date: NaiveDate,
+) -> Result<AmendOutcome> {
+    if heading_matches(&amendment.section, HISTORY_SECTION) {
+        anyhow::bail!(
+            "The {} section is maintained automatically",
+            HISTORY_SECTION
+        );
+    }
+    let content = match (amendment.action, &amendment.content) {
+        (AmendAction::Remove, _) => None,
+        (_, Some(content)) if !content.trim().is_empty() => Some(content.trim()),
+        _ => anyhow::bail!("content is required to add or replace a section"),
+    };
+
+    let amended = apply(document, amendment, content)?;
+
+    let previous_version = current_version(&amended);
+    let bump = amendment
+        .bump
+        .unwrap_or_else(|| amendment.action.default_bump());
+    let version = previous_version.unwrap_or(Version::INITIAL).bump(bump);
+    let amended = set_version(&amended, version, date);
+
+    let mut entry = format!(
+        "- {} ({}): {} {}",
+        version,
+        date,
+        amendment.action.past_tense(),
+        amendment.target()
+    );
+    if let Some(rationale) = amendment.rationale.as_deref().map(str::trim) {
+        if !rationale.is_empty() {
+            entry.push_str(&format!(". Rationale: {}", rationale));
+        }
+    }
+    let amended = append_history(&amended, &entry);
+
+    Ok(AmendOutcome {
+        content: amended,
+        previous_version,
+        version,
+        bump,
+        entry,
+    })
+}
+
+/// Version recorded on the document's `**Version**:` line
+pub fn current_version(document: &str) -> Option<Version> {
+    MarkdownDocument::parse(document)
+        .prose_lines()
+        .into_iter()
+        .find_map(|(_, line)| {
+            let (_, rest) = line.split_once(VERSION_MARKER)?;
+            Version::parse(rest.split('|').next()?)
+        })
+}
+
+/// Apply the section operation itself
+fn apply(document: &str, amendment: &Amendment, content: Option<&str>) -> Result<String> {
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    let headings = headings(&lines);
+    let whole = 0..lines.len();
+    let section = find_block(&headings, &lines, 2, &amendment.section, whole.clone());
+
+    let Some(principle) = &amendment.principle else {
+        return Ok(match (amendment.action, section) {
+            (AmendAction::Add, Some(_)) => anyhow::bail!(
+                "Section \"{}\" already exists; use replace to change it",
+                amendment.section
+            ),
+            (AmendAction::Add, None) => {
+                // New sections go before the history so it stays last
+                let at = find_block(&headings, &lines, 2, HISTORY_SECTION, whole)
+                    .map_or(lines.len(), |history| history.start);
+                let block = format!("## {}\n\n{}", amendment.section, content.unwrap_or(""));
+                splice(&lines, at..at, &block)
+            }
+            (AmendAction::Replace, Some(range)) => {
+                let block = format!(
+                    "{}\n\n{}",
+                    lines[range.start].trim_end(),
+                    content.unwrap_or("")
+                );
+                splice(&lines, range, &block)
+            }
+            (AmendAction::Remove, Some(range)) => splice(&lines, range, ""),
+            (_, None) => anyhow::bail!("Section \"{}\" not found", amendment.section),
+        });
+    };
+
+    let Some(section) = section else {
+        anyhow::bail!("Section \"{}\" not found", amendment.section);
+    };
+    let target = find_block(&headings, &lines, 3, principle, section.clone());
+
+    Ok(match (amendment.action, target) {
+        (AmendAction::Add, Some(_)) => anyhow::bail!(
+            "Principle \"{}\" already exists in \"{}\"; use replace to change it",
+            principle,
+            amendment.section
+        ),
+        (AmendAction::Add, None) => {
+            let block = format!("### {}\n\n{}", principle, content.unwrap_or(""));
+            splice(&lines, section.end..section.end, &block)
+        }
+        (AmendAction::Replace, Some(range)) => {
+            let block = format!(
+                "{}\n\n{}",
+                lines[range.start].trim_end(),
+                content.unwrap_or("")
+            );
+            splice(&lines, range, &block)
+        }
+        (AmendAction::Remove, Some(range)) => splice(&lines, range, ""),
+        (_, None) => anyhow::bail!(
+            "Principle \"{}\" not found in \"{}\"",
+            principle,
+            amendment.section
+        ),
+    })
+}
+
+/// A markdown heading, indexed by 0-based line
+pub(super) struct Heading {
+    line: usize,
+    level: usize,
+    text: String,
+}
+
+pub(super) fn headings(lines: &[&str]) -> Vec<Heading> {
+    MarkdownDocument::parse(&lines.concat())
+        .sections()
+        .iter()
+        .map(|section| Heading {
+            line: section.line - 1,
+            level: section.level,
+            text: section.heading.clone(),
+        })
+        .collect()
+}
+
+/// Line range of the block headed `name` at `level` within `within`
+///
+/// The block runs until the next heading of the same or a higher level.
+pub(super) fn find_block(
+    headings: &[Heading],
+    lines: &[&str],
+    level: usize,
+    name: &str,
+    within: Range<usize>,
+) -> Option<Range<usize>> {
+    let start = headings
+        .iter()
+        .filter(|h| within.contains(&h.line))
+        .find(|h| h.level == level && heading_matches(&h.text, name))?
+        .line;
+    let end = headings
+        .iter()
+        .find(|h| h.line > start && h.level <= level)
+        .map_or(lines.len(), |h| h.line)
+        .min(within.end);
+    Some(start..end)
+}
+
+/// Whether a heading names the target
+///
+/// Matching is case-insensitive and ignores spec-kit's principle numbering
+/// and section annotations, so `Library-First` matches
+/// `### I. Library-First` and `Requirements` matches
+/// `## Requirements *(mandatory)*`.
+pub(crate) fn heading_matches(heading: &str, name: &str) -> bool {
+    let strip = |s: &str| -> String {
+        let s = s.trim();
+        let s = match s.strip_suffix(")*").and_then(|rest| rest.rsplit_once("*(")) {
+            Some((rest, _annotation)) => rest.trim_end(),
+            None => s,
+        };
+        let unnumbered = match s.split_once(". ") {
+            Some((number, rest))
+                if !number.is_empty()
+                    && number
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || "IVXLCDM".contains(c)) =>
+            {
+                rest
+            }
+            _ => s,
+        };
+        unnumbered.trim().to_lowercase()
+    };
+    strip(heading) == strip(name)
+}
+
+/// Replace a line range with a block, keeping one blank line around it
+pub(super) fn splice(lines: &[&str], range: Range<usize>, block: &str) -> String {
+    let before = lines[..range.start].concat();
+    let after = lines[range.end..].concat();
+
+    let mut output = before.trim_end_matches('\n').to_string();
+    for part in [block.trim_end(), after.trim_matches('\n')] {
+        if part.is_empty() {
+            continue;
+        }
+        if !output.is_empty() {
+            output.push_str("\n\n");
+        }
+        output.push_str(part);
+    }
+    output.push('\n');
+    output
+}
+
+/// Write the version and amendment date, adding the metadata line if missing
+fn set_version(document: &str, version: Version, date: NaiveDate) -> String {
+    let mut found = false;
+    let mut output = String::with_capacity(document.len() + 80);
+
+    for line in document.split_inclusive('\n') {
+        if found || !line.contains(VERSION_MARKER) {
+            output.push_str(line);
+            continue;
+        }
+        found = true;
+
+        let ending = if line.ends_with('\n') { "\n" } else { "" };
+        let fields: Vec<String> = line
+            .trim_end()
+            .split('|')
+            .map(|field| {
+                let field = field.trim();
+                if field.contains(VERSION_MARKER) {
+                    format!("{} {}", VERSION_MARKER, version)
+                } else if field.contains(AMENDED_MARKER) {
+                    format!("{} {}", AMENDED_MARKER, date)
+                } else {
+                    field.to_string()
+                }
+            })
+            .collect();
+        output.push_str(&fields.join(" | "));
+        if !line.contains(AMENDED_MARKER) {
+            output.push_str(&format!(" | {} {}", AMENDED_MARKER, date));
+        }
+        output.push_str(ending);
+    }
+
+    if found {
+        return output;
+    }
+
+    // Unversioned: record the metadata right below the title
+    let metadata = format!(
+        "{} {} | **Ratified**: {} | {} {}",
+        VERSION_MARKER, version, date, AMENDED_MARKER, date
+    );
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    let at = lines
+        .iter()
+        .position(|line| line.starts_with("# "))
+        .map_or(0, |title| title + 1);
+    splice(&lines, at..at, &metadata)
+}
+
+/// Append an entry to the history section, creating it at the end if needed
+fn append_history(document: &str, entry: &str) -> String {
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    let headings = headings(&lines);
+
+    match find_block(&headings, &lines, 2, HISTORY_SECTION, 0..lines.len()) {
+        Some(range) => {
+            let existing = lines[range.clone()].concat();
+            splice(
+                &lines,
+                range,
+                &format!("{}\n{}", existing.trim_end(), entry),
+            )
+        }
+        None => {
+            let block = format!("## {}\n\n{}", HISTORY_SECTION, entry);
+            splice(&lines, lines.len()..lines.len(), &block)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONSTITUTION: &str = "# Project Constitution\n\n\
+        **Version**: 1.2.0 | **Ratified**: 2025-06-13 | **Last Amended**: 2025-07-16\n\n\
+        ## Core Principles\n\n\
+        ### I. Library-First\n\nEvery feature starts as a library.\n\n\
+        ### II. Test-First\n\nTests are written before code.\n\n\
+        ## Governance\n\nAmendments require review.\n";
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+    }
+
+    fn amendment(action: AmendAction, section: &str, principle: Option<&str>) -> Amendment {
+        Amendment {
+            action,
+            section: section.to_string(),
+            principle: principle.map(str::to_string),
+            content: Some("Everything is observable.".to_string()),
+            bump: None,
+            rationale: None,
+        }
+    }
+
+    #[test]
+    fn test_add_principle_bumps_minor_and_records_history() {
+        let mut add = amendment(AmendAction::Add, "Core Principles", Some("Observability"));
+        add.rationale = Some("Production incidents".to_string());
+
+        let outcome = amend_document(CONSTITUTION, &add, date()).unwrap();
+
+        assert_eq!(outcome.previous_version, Version::parse("1.2.0"));
+        assert_eq!(outcome.version.to_string(), "1.3.0");
+        assert!(outcome.content.contains(
+            "**Version**: 1.3.0 | **Ratified**: 2025-06-13 | **Last Amended**: 2026-10-18"
+        ));
+        assert!(outcome.content.contains(
+            "Tests are written before code.\n\n### Observability\n\nEverything is observable.\n\n## Governance"
+        ));
+        assert!(outcome.content.ends_with(
+            "## Amendment History\n\n- 1.3.0 (2026-10-18): Added principle \"Observability\" in \"Core Principles\". Rationale: Production incidents\n"
+        ));
+    }
+
+    #[test]
+    fn test_replace_and_remove_keep_the_rest() {
+        let mut replace = amendment(AmendAction::Replace, "Core Principles", Some("test-first"));
+        replace.content = Some("Tests first, always.".to_string());
+        let replaced = amend_document(CONSTITUTION, &replace, date()).unwrap();
+        assert_eq!(replaced.version.to_string(), "1.2.1");
+        assert!(replaced
+            .content
+            .contains("### II. Test-First\n\nTests first, always.\n\n## Governance"));
+        assert!(replaced
+            .content
+            .contains("Every feature starts as a library."));
+
+        let remove = amendment(AmendAction::Remove, "Governance", None);
+        let removed = amend_document(&replaced.content, &remove, date()).unwrap();
+        assert_eq!(removed.version.to_string(), "2.0.0");
+        assert!(!removed.content.contains("## Governance"));
+        assert!(removed
+            .content
+            .contains("Tests first, always.\n\n## Amendment History"));
+        assert_eq!(
+            removed.content.matches("- ").count(),
+            2,
+            "history keeps earlier entries"
+        );
+    }
+
+    #[test]
+    fn test_unversioned_document_and_errors() {
+        let spec = "# Specification\n\n## Requirements\n\n- FR-001: Login\n";
+
+        let add = amendment(AmendAction::Add, "Edge Cases", None);
+        let outcome = amend_document(spec, &add, date()).unwrap();
+        assert_eq!(outcome.previous_version, None);
+        assert!(outcome.content.starts_with(
+            "# Specification\n\n**Version**: 1.1.0 | **Ratified**: 2026-10-18 | **Last Amended**: 2026-10-18\n\n## Requirements"
+        ));
+        assert!(outcome.content.contains(
+            "- FR-001: Login\n\n## Edge Cases\n\nEverything is observable.\n\n## Amendment History"
+        ));
+
+        let again = amend_document(&outcome.content, &add, date()).unwrap_err();
+        assert!(again.to_string().contains("already exists"));
+
+        let missing = amendment(AmendAction::Replace, "Nope", None);
+        assert!(amend_document(spec, &missing, date()).is_err());
+
+        let history = amendment(AmendAction::Remove, "Amendment History", None);
+        assert!(amend_document(&outcome.content, &history, date()).is_err());
+    }
+}
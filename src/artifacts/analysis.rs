@@ -0,0 +1,614 @@
+//! Cross-Artifact Analysis
+//!
+//! Detects duplicate requirements, ambiguous language, terminology drift,
+//! constitution violations, underspecified tasks and coverage gaps.
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::compliance::{check_compliance, ComplianceStatus};
+use super::findings::{
+    AnalysisMetrics, AnalysisReport, Finding, FindingCategory, Location, Severity,
+};
+use super::model::{ArtifactKind, ArtifactSet};
+use super::requirements::{RequirementId, RequirementKind};
+use super::traceability::TraceabilityMatrix;
+
+/// Vague terms that make a requirement untestable
+pub(super) const VAGUE_TERMS: &[&str] = &[
+    "fast",
+    "quickly",
+    "scalable",
+    "secure",
+    "robust",
+    "intuitive",
+    "user-friendly",
+    "easy",
+    "simple",
+    "efficient",
+    "reasonable",
+    "appropriate",
+    "flexible",
+    "etc",
+    "as needed",
+    "high performance",
+];
+
+/// Placeholders that mark unfinished content
+pub(super) const PLACEHOLDERS: &[&str] = &["TODO", "TBD", "TKTK", "???", "[NEEDS CLARIFICATION"];
+
+/// Similarity above which two requirements are considered duplicates
+const DUPLICATE_THRESHOLD: f64 = 0.8;
+
+/// Minimum number of words in a task description
+const MIN_TASK_WORDS: usize = 4;
+
+/// Which groups of checks to run
+#[derive(Debug, Clone, Copy)]
+pub struct AnalysisOptions {
+    /// Duplication, terminology drift and constitution checks
+    pub check_consistency: bool,
+
+    /// Coverage gap checks
+    pub check_coverage: bool,
+}
+
+impl Default for AnalysisOptions {
+    fn default() -> Self {
+        Self {
+            check_consistency: true,
+            check_coverage: true,
+        }
+    }
+}
+
+/// Analyze a set of artifacts
+pub fn analyze(artifacts: &ArtifactSet, options: AnalysisOptions) -> AnalysisReport {
+    let mut findings = Vec::new();
+
+    findings.extend(check_ambiguity(artifacts));
+    findings.extend(check_underspecified_tasks(artifacts));
+
+    if options.check_consistency {
+        findings.extend(check_duplicates(artifacts));
+        findings.extend(check_terminology(artifacts));
+        findings.extend(check_constitution(artifacts));
+    }
+
+    if options.check_coverage {
+        findings.extend(check_coverage(artifacts));
+    }
+
+    AnalysisReport::new(findings, metrics(artifacts))
+}
+
+fn metrics(artifacts: &ArtifactSet) -> AnalysisMetrics {
+    let matrix = traceability(artifacts);
+    let requirements = matrix.requirements.len();
+    let covered = matrix
+        .requirements
+        .iter()
+        .filter(|r| r.is_covered())
+        .count();
+
+    AnalysisMetrics {
+        artifacts: artifacts.present().len(),
+        requirements,
+        tasks: artifacts
+            .tasks
+            .as_ref()
+            .map(|t| t.tasks().len())
+            .unwrap_or(0),
+        coverage_percent: (covered * 100).checked_div(requirements).unwrap_or(0),
+    }
+}
+
+fn traceability(artifacts: &ArtifactSet) -> TraceabilityMatrix {
+    TraceabilityMatrix::build(
+        artifacts
+            .specification
+            .as_ref()
+            .map(|a| a.content.as_str())
+            .unwrap_or_default(),
+        artifacts.plan.as_ref().map(|a| a.content.as_str()),
+        artifacts.tasks.as_ref().map(|a| a.content.as_str()),
+    )
+}
+
+/// Flag vague terms and placeholders in the specification
+fn check_ambiguity(artifacts: &ArtifactSet) -> Vec<Finding> {
+    let Some(spec) = &artifacts.specification else {
+        return Vec::new();
+    };
+
+    let document = spec.document();
+    let headings: HashSet<usize> = document.sections().iter().map(|s| s.line).collect();
+    let mut findings = Vec::new();
+    for (line, text) in document.prose_lines() {
+        if headings.contains(&line) {
+            continue;
+        }
+
+        let placeholders: Vec<&str> = PLACEHOLDERS
+            .iter()
+            .copied()
+            .filter(|p| text.contains(p))
+            .collect();
+        if !placeholders.is_empty() {
+            findings.push(Finding::new(
+                FindingCategory::Ambiguity,
+                Severity::High,
+                spec.location(line),
+                format!("Unresolved placeholder: {}", placeholders.join(", ")),
+                "Replace the placeholder with a concrete decision (use speckit_clarify)",
+            ));
+        }
+
+        let vague = vague_terms_in(text);
+        if !vague.is_empty() {
+            let non_functional = text.contains(RequirementKind::NonFunctional.prefix());
+            findings.push(Finding::new(
+                FindingCategory::Ambiguity,
+                if non_functional {
+                    Severity::High
+                } else {
+                    Severity::Medium
+                },
+                spec.location(line),
+                format!("Vague language: {}", vague.join(", ")),
+                "Replace with measurable criteria (e.g. \"p95 latency < 200ms\")",
+            ));
+        }
+    }
+
+    findings
+}
+
+fn vague_terms_in(text: &str) -> Vec<&'static str> {
+    let lower = text.to_lowercase();
+    let words: HashSet<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .collect();
+
+    VAGUE_TERMS
+        .iter()
+        .copied()
+        .filter(|term| {
+            if term.contains(' ') {
+                lower.contains(term)
+            } else {
+                words.contains(term)
+            }
+        })
+        .collect()
+}
+
+/// Flag tasks too short or unfinished to act on
+fn check_underspecified_tasks(artifacts: &ArtifactSet) -> Vec<Finding> {
+    let Some(tasks) = &artifacts.tasks else {
+        return Vec::new();
+    };
+
+    tasks
+        .tasks()
+        .into_iter()
+        .filter_map(|task| {
+            let words = task
+                .text
+                .split_whitespace()
+                .filter(|w| !(w.starts_with('[') && w.ends_with(']')))
+                .count();
+            let has_placeholder = PLACEHOLDERS.iter().any(|p| task.text.contains(p));
+
+            if words >= MIN_TASK_WORDS && !has_placeholder {
+                return None;
+            }
+
+            Some(Finding::new(
+                FindingCategory::Underspecification,
+                Severity::Medium,
+                tasks.location(task.line),
+                format!("Task {} is underspecified: \"{}\"", task.id, task.text),
+                "Describe the concrete change, the files involved and the requirement it serves",
+            ))
+        })
+        .collect()
+}
+
+/// Flag near-identical requirements
+fn check_duplicates(artifacts: &ArtifactSet) -> Vec<Finding> {
+    let Some(spec) = &artifacts.specification else {
+        return Vec::new();
+    };
+
+    let requirements = spec.requirements();
+    let word_sets: Vec<HashSet<String>> = requirements
+        .iter()
+        .map(|r| significant_words(&r.text))
+        .collect();
+
+    let mut findings = Vec::new();
+    for (i, later) in requirements.iter().enumerate() {
+        let duplicate_of =
+            (0..i).find(|&j| similarity(&word_sets[i], &word_sets[j]) >= DUPLICATE_THRESHOLD);
+        if let Some(j) = duplicate_of {
+            findings.push(Finding::new(
+                FindingCategory::Duplication,
+                Severity::High,
+                spec.location(later.line),
+                format!("{} duplicates {}", later.id, requirements[j].id),
+                format!(
+                    "Merge {} into {} and update references",
+                    later.id, requirements[j].id
+                ),
+            ));
+        }
+    }
+
+    findings
+}
+
+fn significant_words(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 2)
+        .map(str::to_string)
+        .collect()
+}
+
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / a.union(b).count() as f64
+}
+
+/// Flag compound terms spelled differently across artifacts (`user_id` vs `userId`)
+fn check_terminology(artifacts: &ArtifactSet) -> Vec<Finding> {
+    // Normalized term -> spellings with their first location, in order of appearance
+    let mut variants: BTreeMap<String, Vec<(String, Location)>> = BTreeMap::new();
+
+    for artifact in artifacts.present() {
+        for (line, text) in artifact.prose_lines() {
+            for token in text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-')) {
+                let token = token.trim_matches('-');
+                if token.len() < 4 || !token.chars().next().is_some_and(char::is_alphabetic) {
+                    continue;
+                }
+                if RequirementId::parse(token).is_some() {
+                    continue;
+                }
+
+                let key: String = token
+                    .chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase();
+                let spelling = spelling(token);
+                let spellings = variants.entry(key).or_default();
+                if !spellings.iter().any(|(s, _)| *s == spelling) {
+                    spellings.push((spelling, artifact.location(line)));
+                }
+            }
+        }
+    }
+
+    variants
+        .into_values()
+        .filter(|spellings| spellings.len() > 1 && spellings.iter().any(|(s, _)| is_compound(s)))
+        .map(|spellings| {
+            let (first, location) = &spellings[0];
+            let others: Vec<String> = spellings
+                .iter()
+                .skip(1)
+                .map(|(s, loc)| format!("`{}` ({})", s, loc))
+                .collect();
+            Finding::new(
+                FindingCategory::Terminology,
+                Severity::Medium,
+                location.clone(),
+                format!("`{}` is also written as {}", first, others.join(", ")),
+                "Pick one spelling and use it consistently across artifacts",
+            )
+        })
+        .collect()
+}
+
+/// Spelling of a token, ignoring plain capitalization differences
+fn spelling(token: &str) -> String {
+    let has_inner_upper = token.chars().skip(1).any(char::is_uppercase);
+    let all_upper = token.chars().all(|c| !c.is_lowercase());
+    if has_inner_upper && !all_upper {
+        token.to_string()
+    } else {
+        token.to_lowercase()
+    }
+}
+
+fn is_compound(spelling: &str) -> bool {
+    spelling.contains(['_', '-']) || spelling.chars().skip(1).any(char::is_uppercase)
+}
+
+/// Flag constitution rules the plan or tasks violate, as found by the
+/// compliance gate
+fn check_constitution(artifacts: &ArtifactSet) -> Vec<Finding> {
+    check_compliance(artifacts)
+        .principles
+        .into_iter()
+        .flat_map(|principle| {
+            principle
+                .rules
+                .into_iter()
+                .filter(|rule| rule.status == ComplianceStatus::Fail)
+                .map(move |rule| {
+                    Finding::new(
+                        FindingCategory::Constitution,
+                        Severity::Critical,
+                        rule.location,
+                        format!(
+                            "Principle \"{}\" violated ({}): {}",
+                            principle.principle, rule.rule, rule.summary
+                        ),
+                        "Update the plan and tasks to satisfy the principle, or amend the constitution",
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Flag missing artifacts, uncovered requirements, orphan tasks and dangling references
+fn check_coverage(artifacts: &ArtifactSet) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for kind in ArtifactKind::ALL {
+        if artifacts.get(kind).is_some() {
+            continue;
+        }
+        let severity = match kind {
+            ArtifactKind::Specification => Severity::Critical,
+            ArtifactKind::Plan | ArtifactKind::Tasks => Severity::High,
+            ArtifactKind::Constitution => Severity::Medium,
+        };
+        findings.push(Finding::new(
+            FindingCategory::Coverage,
+            severity,
+            Location::file(artifacts.label_for(kind)),
+            format!("{} is missing", kind),
+            format!("Create it with speckit_{}", tool_for(kind)),
+        ));
+    }
+
+    let Some(spec) = &artifacts.specification else {
+        return findings;
+    };
+
+    let matrix = traceability(artifacts);
+    if matrix.requirements.is_empty() {
+        findings.push(Finding::new(
+            FindingCategory::Coverage,
+            Severity::Medium,
+            Location::file(&spec.label),
+            "Requirements have no IDs, so coverage cannot be traced",
+            "Re-run speckit_specify to assign requirement IDs (FR-001, ...)",
+        ));
+        return findings;
+    }
+
+    let Some(tasks) = &artifacts.tasks else {
+        return findings;
+    };
+
+    for trace in matrix.uncovered() {
+        let non_functional = trace.requirement.id.kind == RequirementKind::NonFunctional;
+        findings.push(Finding::new(
+            FindingCategory::Coverage,
+            if non_functional {
+                Severity::Medium
+            } else {
+                Severity::High
+            },
+            spec.location(trace.requirement.line),
+            format!("{} has no associated task", trace.requirement.id),
+            format!("Add a task referencing {}", trace.requirement.id),
+        ));
+    }
+
+    for task in &matrix.orphan_tasks {
+        findings.push(Finding::new(
+            FindingCategory::Coverage,
+            Severity::Low,
+            tasks.location(task.line),
+            format!("Task {} does not reference any requirement", task.id),
+            "Reference the requirement it implements, or confirm it is setup work",
+        ));
+    }
+
+    let task_entries = tasks.tasks();
+    for reference in &matrix.dangling_references {
+        let location = match reference.location.split_once(':') {
+            Some(("plan", line)) => artifacts
+                .plan
+                .as_ref()
+                .and_then(|plan| line.parse().ok().map(|line| plan.location(line))),
+            Some(("tasks", id)) => task_entries
+                .iter()
+                .find(|t| t.id == id)
+                .map(|t| tasks.location(t.line)),
+            _ => None,
+        };
+        findings.push(Finding::new(
+            FindingCategory::Coverage,
+            Severity::High,
+            location.unwrap_or_else(|| Location::file(&tasks.label)),
+            format!(
+                "{} is referenced but not defined in the specification",
+                reference.id
+            ),
+            "Fix the reference or add the requirement to the specification",
+        ));
+    }
+
+    findings
+}
+
+fn tool_for(kind: ArtifactKind) -> &'static str {
+    match kind {
+        ArtifactKind::Constitution => "constitution",
+        ArtifactKind::Specification => "specify",
+        ArtifactKind::Plan => "plan",
+        ArtifactKind::Tasks => "tasks",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifacts::Artifact;
+
+    fn artifacts(constitution: &str, spec: &str, plan: &str, tasks: &str) -> ArtifactSet {
+        let mut set = ArtifactSet::default();
+        set.insert(Artifact::new(
+            ArtifactKind::Constitution,
+            "constitution.md",
+            constitution,
+        ));
+        set.insert(Artifact::new(ArtifactKind::Specification, "spec.md", spec));
+        set.insert(Artifact::new(ArtifactKind::Plan, "plan.md", plan));
+        set.insert(Artifact::new(ArtifactKind::Tasks, "tasks.md", tasks));
+        set
+    }
+
+    fn categories(report: &AnalysisReport, category: FindingCategory) -> Vec<&Finding> {
+        report
+            .findings
+            .iter()
+            .filter(|f| f.category == category)
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_duplicates_and_ambiguity() {
+        let set = artifacts(
+            "",
+            "- **FR-001**: Users MUST be able to reset their password\n\
+             - **FR-002**: Users MUST be able to reset their password by email\n\
+             - **NFR-001**: Search MUST be fast\n\
+             - **FR-003**: Export format TBD\n",
+            "",
+            "",
+        );
+        let report = analyze(&set, AnalysisOptions::default());
+
+        let duplicates = categories(&report, FindingCategory::Duplication);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].summary, "FR-002 duplicates FR-001");
+        assert_eq!(duplicates[0].location.to_string(), "spec.md:2");
+
+        let ambiguity = categories(&report, FindingCategory::Ambiguity);
+        assert_eq!(ambiguity.len(), 2);
+        assert!(ambiguity.iter().all(|f| f.severity == Severity::High));
+    }
+
+    #[test]
+    fn test_detects_terminology_drift() {
+        let set = artifacts(
+            "",
+            "- **FR-001**: Store the user_id with each order\n",
+            "## Data Model\n\nOrders reference userId.\n",
+            "",
+        );
+        let report = analyze(&set, AnalysisOptions::default());
+
+        let drift = categories(&report, FindingCategory::Terminology);
+        assert_eq!(drift.len(), 1);
+        assert!(drift[0].summary.contains("`user_id`"));
+        assert!(drift[0].summary.contains("`userId` (plan.md:3)"));
+    }
+
+    #[test]
+    fn test_detects_constitution_violation() {
+        let set = artifacts(
+            "## Principles\n\n### I. Test-First\n\nTests MUST be written before implementation.\n\n\
+             ### II. Storage\n\nServices must not use MongoDB.\n\n\
+             ### III. Documentation\n\nCode MUST be documented.\n",
+            "- **FR-001**: Users can log in\n",
+            "## Approach\n\nStore sessions in MongoDB.\n",
+            "- [ ] T001 Write the login test for FR-001 in tests/login.rs\n\
+             - [ ] T002 Implement the login handler for FR-001 in src/login.rs\n",
+        );
+        let report = analyze(&set, AnalysisOptions::default());
+
+        // The same verdicts as the compliance gate: only the rule that fails
+        let violations = categories(&report, FindingCategory::Constitution);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Critical);
+        assert_eq!(violations[0].location.to_string(), "constitution.md:9");
+        assert!(violations[0].summary.contains("II. Storage"));
+        assert!(violations[0]
+            .summary
+            .contains("\"mongodb\" is mentioned 1 time(s)"));
+    }
+
+    #[test]
+    fn test_constitution_terms_match_whole_words() {
+        let set = artifacts(
+            "## Principles\n\n- Services must not use Go\n- Tests MUST be written before implementation\n",
+            "- **FR-001**: Users can log in\n",
+            "## Approach\n\nA good design using the latest Rust.\n",
+            "- [ ] T001 Build the contest page for FR-001\n- [ ] T002 Implement login for FR-001\n",
+        );
+        let report = analyze(&set, AnalysisOptions::default());
+
+        let violations = categories(&report, FindingCategory::Constitution);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].location.to_string(), "constitution.md:4");
+    }
+
+    #[test]
+    fn test_constitution_rules_without_plan_are_not_findings() {
+        let mut set = artifacts(
+            "## Principles\n\n- Services MUST use PostgreSQL\n",
+            "- **FR-001**: Users can log in\n",
+            "",
+            "",
+        );
+        set.plan = None;
+        set.tasks = None;
+        let report = analyze(&set, AnalysisOptions::default());
+
+        assert!(categories(&report, FindingCategory::Constitution).is_empty());
+    }
+
+    #[test]
+    fn test_detects_underspecified_tasks_and_coverage_gaps() {
+        let set = artifacts(
+            "",
+            "- **FR-001**: Users can log in\n- **FR-002**: Users can log out\n",
+            "",
+            "- [ ] T001 Implement login form and session handling (FR-001)\n\
+             - [ ] T002 Fix stuff\n\
+             - [ ] T003 Wire the logout button (FR-009)\n",
+        );
+        let report = analyze(&set, AnalysisOptions::default());
+
+        let underspecified = categories(&report, FindingCategory::Underspecification);
+        assert_eq!(underspecified.len(), 1);
+        assert_eq!(underspecified[0].location.to_string(), "tasks.md:2");
+
+        let coverage: Vec<String> = categories(&report, FindingCategory::Coverage)
+            .iter()
+            .map(|f| f.summary.clone())
+            .collect();
+        assert!(coverage.contains(&"FR-002 has no associated task".to_string()));
+        assert!(coverage.contains(&"Task T002 does not reference any requirement".to_string()));
+        assert!(coverage
+            .contains(&"FR-009 is referenced but not defined in the specification".to_string()));
+        assert_eq!(report.metrics.coverage_percent, 50);
+    }
+
+    #[test]
+    fn test_missing_specification_is_critical() {
+        let report = analyze(&ArtifactSet::default(), AnalysisOptions::default());
+        assert!(report.has_blocking_issues());
+        assert_eq!(report.findings[0].location.to_string(), "speckit.specify");
+    }
+}
@@ -0,0 +1,567 @@
+//! Requirement Checklists
+//!
+//! Domain checklists in the style of spec-kit's `/checklist`: each item is a
+//! question about the quality of the requirements ("unit tests for
+//! English"), tagged with a quality dimension and linked to the requirement
+//! IDs it concerns, or marked as a gap when the spec says nothing about it.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use super::markdown::MarkdownDocument;
+use super::requirements::{Requirement, RequirementId};
+
+/// Section holding checked items that are no longer generated
+pub const RETAINED_SECTION: &str = "Retained";
+
+/// A checklist domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecklistDomain {
+    /// User experience
+    Ux,
+
+    /// API contracts
+    Api,
+
+    /// Security and privacy
+    Security,
+
+    /// Performance and scalability
+    Performance,
+
+    /// Accessibility
+    Accessibility,
+}
+
+impl ChecklistDomain {
+    /// All domains, in checklist order
+    pub const ALL: [ChecklistDomain; 5] = [
+        ChecklistDomain::Ux,
+        ChecklistDomain::Api,
+        ChecklistDomain::Security,
+        ChecklistDomain::Performance,
+        ChecklistDomain::Accessibility,
+    ];
+
+    /// Terms that make a requirement relevant to the domain
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            ChecklistDomain::Ux => &[
+                "screen",
+                "page",
+                "button",
+                "display",
+                "view",
+                "click",
+                "navigat",
+                "dialog",
+                "menu",
+                "dashboard",
+                "layout",
+                "notification",
+                "interface",
+                "ui",
+            ],
+            ChecklistDomain::Api => &[
+                "api",
+                "endpoint",
+                "request",
+                "response",
+                "http",
+                "graphql",
+                "webhook",
+                "json",
+                "status code",
+                "payload",
+                "rate limit",
+            ],
+            ChecklistDomain::Security => &[
+                "auth",
+                "login",
+                "password",
+                "permission",
+                "role",
+                "token",
+                "encrypt",
+                "secret",
+                "privacy",
+                "personal data",
+                "gdpr",
+                "audit",
+                "session",
+                "credential",
+            ],
+            ChecklistDomain::Performance => &[
+                "latency",
+                "performance",
+                "throughput",
+                "response time",
+                "millisecond",
+                "concurrent",
+                "load",
+                "scale",
+                "cache",
+                "timeout",
+                "p95",
+                "p99",
+            ],
+            ChecklistDomain::Accessibility => &[
+                "accessib",
+                "screen reader",
+                "keyboard",
+                "contrast",
+                "wcag",
+                "aria",
+                "alt text",
+                "caption",
+                "focus",
+            ],
+        }
+    }
+
+    /// Question asked for each requirement relevant to the domain
+    fn requirement_item(&self) -> (&'static str, QualityDimension) {
+        match self {
+            ChecklistDomain::Ux => (
+                "Are the loading, empty, error and success states defined for {id}?",
+                QualityDimension::Completeness,
+            ),
+            ChecklistDomain::Api => (
+                "Are request and response formats, status codes and error payloads specified for {id}?",
+                QualityDimension::Completeness,
+            ),
+            ChecklistDomain::Security => (
+                "Are the authentication and authorization rules for {id} stated explicitly?",
+                QualityDimension::Clarity,
+            ),
+            ChecklistDomain::Performance => (
+                "Is the performance expectation for {id} quantified with a measurable threshold?",
+                QualityDimension::Measurability,
+            ),
+            ChecklistDomain::Accessibility => (
+                "Are keyboard and screen-reader behaviours defined for {id}?",
+                QualityDimension::Coverage,
+            ),
+        }
+    }
+
+    /// Domain-wide questions with the terms that link them to requirements
+    fn general_items(
+        &self,
+    ) -> &'static [(&'static str, QualityDimension, &'static [&'static str])] {
+        use QualityDimension::*;
+        match self {
+            ChecklistDomain::Ux => &[
+                ("Are navigation paths between all screens specified?", Completeness, &["navigat", "screen", "page"]),
+                ("Are user-facing error messages and recovery paths defined?", EdgeCases, &["error", "message"]),
+                ("Is the terminology shown to users consistent with the spec's key entities?", Consistency, &[]),
+                ("Are responsive or multi-device layouts addressed?", Coverage, &["mobile", "responsive", "device", "layout"]),
+            ],
+            ChecklistDomain::Api => &[
+                ("Is the API versioning strategy documented?", Completeness, &["version"]),
+                ("Are authentication requirements defined for every endpoint?", Coverage, &["auth", "token", "endpoint"]),
+                ("Are rate limits and pagination rules specified?", Completeness, &["rate limit", "paginat", "page size"]),
+                ("Are error response formats consistent across endpoints?", Consistency, &["error"]),
+                ("Is retry and idempotency behaviour defined for write operations?", EdgeCases, &["retry", "idempot"]),
+            ],
+            ChecklistDomain::Security => &[
+                ("Are handling rules specified for personal or sensitive data?", Completeness, &["personal", "sensitive", "privacy", "pii"]),
+                ("Are session expiry and revocation rules defined?", Completeness, &["session", "token", "logout"]),
+                ("Are storage requirements stated for secrets and credentials?", Clarity, &["secret", "credential", "password"]),
+                ("Are audit logging requirements defined for security-relevant actions?", Coverage, &["audit", "log"]),
+                ("Are abuse cases and threats documented?", EdgeCases, &["abuse", "attack", "threat", "brute"]),
+            ],
+            ChecklistDomain::Performance => &[
+                ("Are latency targets quantified (for example p95 in milliseconds)?", Measurability, &["latency", "response time", "millisecond", "p95"]),
+                ("Are throughput and concurrency expectations specified?", Measurability, &["throughput", "concurrent", "requests per"]),
+                ("Are data volume and growth assumptions stated?", Completeness, &["volume", "records", "scale", "growth"]),
+                ("Is behaviour under overload or degraded dependencies defined?", EdgeCases, &["load", "degrad", "overload", "timeout"]),
+            ],
+            ChecklistDomain::Accessibility => &[
+                ("Is the target conformance level (for example WCAG 2.1 AA) specified?", Measurability, &["wcag", "conformance", "accessib"]),
+                ("Are keyboard-only navigation requirements defined for all interactive elements?", Coverage, &["keyboard", "focus"]),
+                ("Are text alternatives required for non-text content?", Completeness, &["alt text", "image", "icon", "caption"]),
+                ("Are colour contrast requirements stated?", Measurability, &["contrast", "colour", "color"]),
+                ("Are screen-reader announcements defined for dynamic content?", Coverage, &["screen reader", "aria", "announce"]),
+            ],
+        }
+    }
+
+    /// Whether a piece of text concerns the domain
+    pub fn matches(&self, text: &str) -> bool {
+        mentions_any(text, self.keywords())
+    }
+}
+
+impl fmt::Display for ChecklistDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecklistDomain::Ux => write!(f, "UX"),
+            ChecklistDomain::Api => write!(f, "API"),
+            ChecklistDomain::Security => write!(f, "Security"),
+            ChecklistDomain::Performance => write!(f, "Performance"),
+            ChecklistDomain::Accessibility => write!(f, "Accessibility"),
+        }
+    }
+}
+
+/// Requirement quality a checklist item tests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QualityDimension {
+    Completeness,
+    Clarity,
+    Consistency,
+    Measurability,
+    Coverage,
+    EdgeCases,
+}
+
+impl fmt::Display for QualityDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QualityDimension::Completeness => write!(f, "Completeness"),
+            QualityDimension::Clarity => write!(f, "Clarity"),
+            QualityDimension::Consistency => write!(f, "Consistency"),
+            QualityDimension::Measurability => write!(f, "Measurability"),
+            QualityDimension::Coverage => write!(f, "Coverage"),
+            QualityDimension::EdgeCases => write!(f, "Edge Cases"),
+        }
+    }
+}
+
+/// A checklist item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// Item ID (`CHK001`, ...)
+    pub id: String,
+
+    /// The question
+    pub text: String,
+
+    /// Quality dimension tested
+    pub dimension: QualityDimension,
+
+    /// Requirements the item concerns; empty marks a gap in the spec
+    pub requirements: Vec<RequirementId>,
+
+    /// Whether the item is checked off
+    pub checked: bool,
+}
+
+impl ChecklistItem {
+    /// Markdown list line
+    pub fn to_markdown(&self) -> String {
+        let references = if self.requirements.is_empty() {
+            "Gap".to_string()
+        } else {
+            format!(
+                "Spec {}",
+                self.requirements
+                    .iter()
+                    .map(|id| format!("§{}", id))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        format!(
+            "- [{}] {} {} [{}, {}]",
+            if self.checked { "x" } else { " " },
+            self.id,
+            self.text,
+            self.dimension,
+            references
+        )
+    }
+}
+
+/// Checklist items of one domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainChecklist {
+    /// Domain
+    pub domain: ChecklistDomain,
+
+    /// Items, requirement-specific ones first
+    pub items: Vec<ChecklistItem>,
+}
+
+/// Completion of one checklist category (`##` section)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryProgress {
+    /// Section heading
+    pub category: String,
+
+    /// Checked items
+    pub checked: usize,
+
+    /// All items
+    pub total: usize,
+}
+
+impl CategoryProgress {
+    /// Completion percentage
+    pub fn percent(&self) -> usize {
+        (self.checked * 100).checked_div(self.total).unwrap_or(100)
+    }
+}
+
+/// Domains with at least one relevant requirement, or every domain when
+/// none is relevant
+pub fn detect_domains(requirements: &[Requirement]) -> Vec<ChecklistDomain> {
+    let detected: Vec<ChecklistDomain> = ChecklistDomain::ALL
+        .into_iter()
+        .filter(|domain| requirements.iter().any(|r| domain.matches(&r.text)))
+        .collect();
+    if detected.is_empty() {
+        ChecklistDomain::ALL.to_vec()
+    } else {
+        detected
+    }
+}
+
+/// Build the checklists for the given domains, numbering items across
+/// domains
+pub fn build_checklists(
+    requirements: &[Requirement],
+    domains: &[ChecklistDomain],
+) -> Vec<DomainChecklist> {
+    let mut next = 1;
+    let mut item = |text: String, dimension, requirements: Vec<RequirementId>| {
+        let id = format!("CHK{:03}", next);
+        next += 1;
+        ChecklistItem {
+            id,
+            text,
+            dimension,
+            requirements,
+            checked: false,
+        }
+    };
+
+    domains
+        .iter()
+        .map(|domain| {
+            let mut items = Vec::new();
+
+            let (template, dimension) = domain.requirement_item();
+            for requirement in requirements.iter().filter(|r| domain.matches(&r.text)) {
+                items.push(item(
+                    template.replace("{id}", &requirement.id.to_string()),
+                    dimension,
+                    vec![requirement.id],
+                ));
+            }
+
+            for (text, dimension, terms) in domain.general_items() {
+                let linked = requirements
+                    .iter()
+                    .filter(|r| mentions_any(&r.text, terms))
+                    .map(|r| r.id)
+                    .collect();
+                items.push(item(text.to_string(), *dimension, linked));
+            }
+
+            DomainChecklist {
+                domain: *domain,
+                items,
+            }
+        })
+        .collect()
+}
+
+/// Render checklists as markdown, keeping the check marks of an existing
+/// checklist: items with the same question stay checked, and checked items
+/// that are no longer generated move to a `## Retained` section
+pub fn render_checklists(
+    title: &str,
+    source: &str,
+    checklists: &mut [DomainChecklist],
+    existing: Option<&str>,
+) -> String {
+    let checked = existing.map(checked_items).unwrap_or_default();
+    let mut used = HashSet::new();
+
+    let mut markdown = format!(
+        "# {}\n\n**Source**: {}\n\n\
+        Each item tests whether the requirements are complete, clear and measurable. \
+        `[Gap]` marks a topic the specification does not cover yet.\n",
+        title, source
+    );
+
+    for checklist in checklists.iter_mut() {
+        markdown.push_str(&format!("\n## {}\n\n", checklist.domain));
+        for item in &mut checklist.items {
+            if checked.contains_key(&item.text) {
+                item.checked = true;
+                used.insert(item.text.clone());
+            }
+            markdown.push_str(&item.to_markdown());
+            markdown.push('\n');
+        }
+    }
+
+    let mut retained: Vec<(&String, &String)> = checked
+        .iter()
+        .filter(|(text, _)| !used.contains(*text))
+        .collect();
+    if !retained.is_empty() {
+        retained.sort_by(|a, b| a.1.cmp(b.1));
+        markdown.push_str(&format!("\n## {}\n\n", RETAINED_SECTION));
+        for (_, line) in retained {
+            markdown.push_str(line);
+            markdown.push('\n');
+        }
+    }
+
+    markdown
+}
+
+/// Completion per `##` section of a checklist
+pub fn summarize_checklist(markdown: &str) -> Vec<CategoryProgress> {
+    let document = MarkdownDocument::parse(markdown);
+
+    document
+        .sections()
+        .iter()
+        .filter(|section| section.level == 2)
+        .map(|section| {
+            let items: Vec<_> = document
+                .checkbox_items()
+                .filter(|item| section.lines().contains(&item.line))
+                .collect();
+            CategoryProgress {
+                category: section.heading.clone(),
+                checked: items
+                    .iter()
+                    .filter(|item| item.checked == Some(true))
+                    .count(),
+                total: items.len(),
+            }
+        })
+        .filter(|category| category.total > 0)
+        .collect()
+}
+
+/// Checked items of an existing checklist, keyed by question text
+fn checked_items(markdown: &str) -> HashMap<String, String> {
+    let document = MarkdownDocument::parse(markdown);
+    document
+        .checkbox_items()
+        .filter(|item| item.checked == Some(true))
+        .map(|item| {
+            (
+                question_text(&item.text),
+                document.line(item.line).trim().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Question text of an item body, without its ID and trailing tags
+fn question_text(body: &str) -> String {
+    let body = match body.split_once(' ') {
+        Some((id, rest)) if is_item_id(id) => rest,
+        _ => body,
+    };
+    let body = match body.rfind(" [") {
+        Some(index) if body.ends_with(']') => &body[..index],
+        _ => body,
+    };
+    body.trim().to_string()
+}
+
+fn is_item_id(word: &str) -> bool {
+    word.strip_prefix("CHK")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether text mentions any term at a word start (so `auth` matches
+/// "authentication" but not "oauth")
+fn mentions_any(text: &str, terms: &[&str]) -> bool {
+    let text = text.to_lowercase();
+    terms.iter().any(|term| {
+        text.match_indices(term).any(|(index, _)| {
+            !text[..index]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifacts::extract_requirements;
+
+    const SPEC: &str = "## Requirements\n\n\
+        - **FR-001**: Users log in with a password\n\
+        - **FR-002**: The export endpoint returns JSON\n\
+        - **FR-003**: Reports are archived nightly\n";
+
+    #[test]
+    fn test_items_link_requirements() {
+        let requirements = extract_requirements(SPEC);
+        assert_eq!(
+            detect_domains(&requirements),
+            vec![ChecklistDomain::Api, ChecklistDomain::Security]
+        );
+
+        let checklists = build_checklists(&requirements, &[ChecklistDomain::Security]);
+        let items = &checklists[0].items;
+        assert_eq!(
+            items[0].to_markdown(),
+            "- [ ] CHK001 Are the authentication and authorization rules for FR-001 stated explicitly? [Clarity, Spec §FR-001]"
+        );
+        assert!(items
+            .iter()
+            .any(|item| item.text.starts_with("Are abuse cases") && item.requirements.is_empty()));
+        assert!(items[1..]
+            .iter()
+            .any(|item| item.requirements == vec![items[0].requirements[0]]));
+    }
+
+    #[test]
+    fn test_rerender_preserves_checks() {
+        let requirements = extract_requirements(SPEC);
+        let domains = [ChecklistDomain::Api];
+        let first = render_checklists(
+            "Checklist",
+            "spec.md",
+            &mut build_checklists(&requirements, &domains),
+            None,
+        );
+        let edited = first
+            .replacen("- [ ] CHK001", "- [x] CHK001", 1)
+            .replace("- [ ] CHK003", "- [X] CHK003")
+            + "\n## Notes\n\n- [x] CHK099 Legacy item [Gap]\n";
+
+        let second = render_checklists(
+            "Checklist",
+            "spec.md",
+            &mut build_checklists(&requirements, &domains),
+            Some(&edited),
+        );
+        assert!(second.contains("- [x] CHK001 Are request and response formats"));
+        assert!(second.contains("- [x] CHK003 Are authentication requirements"));
+        assert!(second.contains("## Retained\n\n- [x] CHK099 Legacy item [Gap]\n"));
+
+        let summary = summarize_checklist(&second);
+        assert_eq!(summary[0].category, "API");
+        assert_eq!((summary[0].checked, summary[0].total), (2, 6));
+        assert_eq!(summary[1].category, RETAINED_SECTION);
+        assert_eq!(summary[1].percent(), 100);
+    }
+
+    #[test]
+    fn test_summary_ignores_code_blocks() {
+        let checklist = "## API\n\n- [x] CHK001 First\n- [ ] CHK002 Second\n\n\
+            ```markdown\n## Example\n- [x] CHK999 Sample\n```\n";
+
+        let summary = summarize_checklist(checklist);
+        assert_eq!(summary.len(), 1);
+        assert_eq!((summary[0].checked, summary[0].total), (1, 2));
+        assert_eq!(checked_items(checklist).len(), 1);
+    }
+}
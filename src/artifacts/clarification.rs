@@ -0,0 +1,972 @@
+//! Specification Clarification
+//!
+//! Scores a specification against a question taxonomy (functional scope,
+//! data model, non-functional requirements, edge cases, terminology),
+//! proposes up to five prioritized multiple-choice questions, and records
+//! answers in the spec's `## Clarifications` section the way spec-kit's
+//! `/clarify` does.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::amend::{find_block, headings, splice, HISTORY_SECTION};
+use super::analysis::{PLACEHOLDERS, VAGUE_TERMS};
+use super::markdown::MarkdownDocument;
+
+/// Heading of the section answers are recorded in
+pub const CLARIFICATIONS_SECTION: &str = "Clarifications";
+
+/// Default (and spec-kit's) maximum number of questions per session
+pub const DEFAULT_MAX_QUESTIONS: usize = 5;
+
+/// Hedging words that leave scope undecided
+const HEDGE_TERMS: &[&str] = &["maybe", "probably", "might", "could", "should consider"];
+
+/// Question taxonomy category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClarifyCategory {
+    /// Users, goals, acceptance criteria and scope boundaries
+    FunctionalScope,
+
+    /// Entities, identity and data lifecycle
+    DataModel,
+
+    /// Performance, scale, security and reliability
+    NonFunctional,
+
+    /// Errors, boundaries and concurrent use
+    EdgeCases,
+
+    /// Shared vocabulary and vague wording
+    Terminology,
+}
+
+impl ClarifyCategory {
+    /// All categories, in priority order
+    pub const ALL: [ClarifyCategory; 5] = [
+        ClarifyCategory::FunctionalScope,
+        ClarifyCategory::DataModel,
+        ClarifyCategory::NonFunctional,
+        ClarifyCategory::EdgeCases,
+        ClarifyCategory::Terminology,
+    ];
+}
+
+impl fmt::Display for ClarifyCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::FunctionalScope => "Functional Scope",
+            Self::DataModel => "Data Model",
+            Self::NonFunctional => "Non-Functional",
+            Self::EdgeCases => "Edge Cases",
+            Self::Terminology => "Terminology",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Something a well-specified document covers, with the question to ask
+/// when it is missing
+struct Signal {
+    category: ClarifyCategory,
+    topic: &'static str,
+    /// Whole words or phrases; a trailing `*` also matches longer words
+    keywords: &'static [&'static str],
+    question: &'static str,
+    options: &'static [&'static str],
+}
+
+/// The taxonomy, most impactful topics first within each category
+const SIGNALS: &[Signal] = &[
+    Signal {
+        category: ClarifyCategory::FunctionalScope,
+        topic: "Users and goals",
+        keywords: &["as a", "user story", "user stories", "persona*"],
+        question: "Who are the primary users and what is their main goal?",
+        options: &[
+            "A single end-user role",
+            "Several roles with different permissions",
+            "Internal operators or administrators only",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::FunctionalScope,
+        topic: "Acceptance criteria",
+        keywords: &["acceptance", "given", "success criteria"],
+        question: "How will we know the feature is done?",
+        options: &[
+            "Scenario-based acceptance tests (Given/When/Then)",
+            "Measurable success metrics",
+            "Manual review and sign-off",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::FunctionalScope,
+        topic: "Scope boundaries",
+        keywords: &["out of scope", "non-goal*", "not in scope"],
+        question: "What is explicitly out of scope for this feature?",
+        options: &[
+            "Nothing beyond the listed requirements",
+            "Integrations with external systems",
+            "Administration and reporting features",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::DataModel,
+        topic: "Key entities",
+        keywords: &["entity", "entities", "data model", "schema*", "attribute*"],
+        question: "What are the key entities and their main attributes?",
+        options: &[
+            "A single core entity",
+            "A few related entities (to be listed in the spec)",
+            "No persistent data",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::DataModel,
+        topic: "Identity and uniqueness",
+        keywords: &["unique*", "identifier*", "primary key", "uuid*"],
+        question: "How are records identified, and what must be unique?",
+        options: &[
+            "System-generated IDs",
+            "Natural keys (e.g. email address)",
+            "Generated IDs plus a unique natural key",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::DataModel,
+        topic: "Data lifecycle",
+        keywords: &["retention", "retain*", "archiv*", "delet*", "lifecycle"],
+        question: "What happens to data over time?",
+        options: &[
+            "Kept indefinitely",
+            "Deleted on user request",
+            "Archived or purged after a retention period",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::NonFunctional,
+        topic: "Performance",
+        keywords: &["performance", "latency", "response time", "throughput"],
+        question: "What performance target applies?",
+        options: &[
+            "p95 response time under 200 ms",
+            "p95 response time under 1 s",
+            "No specific target",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::NonFunctional,
+        topic: "Scale",
+        keywords: &["concurrent", "scale", "scaling", "load", "volume*"],
+        question: "What scale must be supported?",
+        options: &[
+            "Up to 100 concurrent users",
+            "Up to 10,000 concurrent users",
+            "More than 10,000 concurrent users",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::NonFunctional,
+        topic: "Security and privacy",
+        keywords: &[
+            "security",
+            "auth",
+            "authenticat*",
+            "authoriz*",
+            "permission*",
+            "encrypt*",
+            "privacy",
+        ],
+        question: "What security and privacy requirements apply?",
+        options: &[
+            "Authenticated users only",
+            "Role-based access control",
+            "Sensitive data encrypted at rest and in transit",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::NonFunctional,
+        topic: "Reliability and observability",
+        keywords: &[
+            "availability",
+            "uptime",
+            "recover*",
+            "logging",
+            "monitor*",
+            "metric*",
+        ],
+        question: "What reliability and observability is expected?",
+        options: &[
+            "Best effort",
+            "99.9% availability with monitoring and alerts",
+            "Structured logs and metrics, no availability target",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::EdgeCases,
+        topic: "Error handling",
+        keywords: &["error*", "fail*", "invalid"],
+        question: "How should errors and invalid input be handled?",
+        options: &[
+            "Show a clear message and let the user retry",
+            "Reject and log the request",
+            "Fall back to a degraded mode",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::EdgeCases,
+        topic: "Boundary conditions",
+        keywords: &[
+            "edge case*",
+            "boundary",
+            "boundaries",
+            "empty",
+            "limit*",
+            "maximum",
+        ],
+        question: "Which boundary conditions must be handled?",
+        options: &[
+            "Empty and missing input",
+            "Size and rate limits",
+            "Duplicate submissions",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::EdgeCases,
+        topic: "Concurrent changes",
+        keywords: &["concurren*", "conflict*", "simultaneous*"],
+        question: "What happens when two users change the same data at once?",
+        options: &[
+            "Last write wins",
+            "Reject the later change with a conflict error",
+            "Merge the changes",
+        ],
+    },
+    Signal {
+        category: ClarifyCategory::Terminology,
+        topic: "Glossary",
+        keywords: &["glossary", "terminology", "definitions", "defined as"],
+        question: "Are there domain terms that need a shared definition?",
+        options: &[
+            "No, the terms are self-explanatory",
+            "Yes, add a glossary section",
+            "Reuse an existing project glossary",
+        ],
+    },
+];
+
+/// A multiple-choice option
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClarifyOption {
+    /// Option label (`A`, `B`, ...)
+    pub label: String,
+
+    /// What choosing the option means
+    pub description: String,
+}
+
+/// A clarification question
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Question {
+    /// Question ID (`Q-3f9a2c`), derived from the question's content so it
+    /// stays the same across calls as long as the question does
+    pub id: String,
+
+    /// Taxonomy category (`None` for caller-supplied questions)
+    pub category: Option<ClarifyCategory>,
+
+    /// The question
+    pub question: String,
+
+    /// Why it is asked
+    pub context: String,
+
+    /// Multiple-choice options; a short free-form answer is always accepted
+    pub options: Vec<ClarifyOption>,
+
+    /// 1-based spec line the question is about, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    /// Placeholder text in the spec that the answer replaces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+
+    /// 1-based byte column of the placeholder on `line`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl Question {
+    fn new(category: Option<ClarifyCategory>, question: String, context: String) -> Self {
+        Self {
+            id: String::new(),
+            category,
+            question,
+            context,
+            options: Vec::new(),
+            line: None,
+            marker: None,
+            column: None,
+        }
+    }
+
+    fn with_options(mut self, options: &[&str]) -> Self {
+        self.options = options
+            .iter()
+            .zip('A'..='Z')
+            .map(|(description, label)| ClarifyOption {
+                label: label.to_string(),
+                description: description.to_string(),
+            })
+            .collect();
+        self
+    }
+
+    fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// ID derived from the category, question text and placeholder
+    fn stable_id(&self) -> String {
+        // FNV-1a, so IDs do not depend on the standard library's hasher
+        let category = self.category.map(|c| c.to_string()).unwrap_or_default();
+        let marker = self.marker.as_deref().unwrap_or_default();
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in [category.as_str(), &self.question, marker]
+            .join("\u{1f}")
+            .bytes()
+        {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        format!("Q-{:06x}", hash & 0xff_ffff)
+    }
+
+    /// Expand an option label (`"B"`) to its description; other answers are
+    /// returned unchanged
+    pub fn resolve_answer(&self, answer: &str) -> String {
+        let answer = answer.trim();
+        self.options
+            .iter()
+            .find(|option| option.label.eq_ignore_ascii_case(answer))
+            .map_or_else(|| answer.to_string(), |option| option.description.clone())
+    }
+}
+
+/// Coverage of one taxonomy category
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryCoverage {
+    /// Category
+    pub category: ClarifyCategory,
+
+    /// Percentage of the category's topics the spec covers
+    pub score: u8,
+
+    /// Topics found in the spec
+    pub covered: Vec<String>,
+
+    /// Topics missing from the spec
+    pub missing: Vec<String>,
+}
+
+impl CategoryCoverage {
+    /// spec-kit's coverage status: Clear, Partial or Missing
+    pub fn status(&self) -> &'static str {
+        match self.score {
+            100 => "Clear",
+            50..=99 => "Partial",
+            _ => "Missing",
+        }
+    }
+}
+
+/// Coverage scores and prioritized questions for a specification
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClarificationReport {
+    /// Coverage per category, in taxonomy order
+    pub coverage: Vec<CategoryCoverage>,
+
+    /// Questions to ask, highest priority first
+    pub questions: Vec<Question>,
+}
+
+impl ClarificationReport {
+    /// Score a specification and pick up to `max_questions` questions
+    ///
+    /// Caller-supplied questions come first, then unresolved placeholders,
+    /// then one question per category in turn (taxonomy order) so a session
+    /// spreads across categories instead of exhausting the first one.
+    pub fn build(spec: &str, custom: &[String], max_questions: usize) -> Self {
+        let prose = MarkdownDocument::parse(spec).prose_lines();
+        let lower = prose
+            .iter()
+            .map(|(_, text)| text.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut coverage = Vec::new();
+        let mut by_category: Vec<Vec<Question>> = Vec::new();
+
+        let vague = vague_wording(&prose);
+        for category in ClarifyCategory::ALL {
+            let mut covered = Vec::new();
+            let mut missing = Vec::new();
+            let mut candidates = Vec::new();
+
+            for signal in SIGNALS.iter().filter(|s| s.category == category) {
+                if signal.keywords.iter().any(|k| contains_keyword(&lower, k)) {
+                    covered.push(signal.topic.to_string());
+                } else {
+                    missing.push(signal.topic.to_string());
+                    candidates.push(
+                        Question::new(
+                            Some(category),
+                            signal.question.to_string(),
+                            format!("{} not specified", signal.topic),
+                        )
+                        .with_options(signal.options),
+                    );
+                }
+            }
+
+            if category == ClarifyCategory::Terminology {
+                if vague.is_empty() {
+                    covered.push("Precise wording".to_string());
+                } else {
+                    missing.push("Precise wording".to_string());
+                    candidates.extend(vague.iter().cloned());
+                }
+            }
+
+            let total = covered.len() + missing.len();
+            coverage.push(CategoryCoverage {
+                category,
+                score: (covered.len() * 100 / total.max(1)) as u8,
+                covered,
+                missing,
+            });
+            by_category.push(candidates);
+        }
+
+        let mut questions: Vec<Question> = custom
+            .iter()
+            .map(|q| Question::new(None, q.clone(), "Asked by the caller".to_string()))
+            .collect();
+        questions.extend(placeholders(&prose));
+
+        let mut round = 0;
+        while by_category.iter().any(|c| round < c.len()) {
+            for candidates in &by_category {
+                if let Some(question) = candidates.get(round) {
+                    questions.push(question.clone());
+                }
+            }
+            round += 1;
+        }
+
+        questions.truncate(max_questions);
+        let mut ids: Vec<String> = Vec::new();
+        for question in &mut questions {
+            let base = question.stable_id();
+            let mut id = base.clone();
+            let mut n = 2;
+            while ids.contains(&id) {
+                id = format!("{}-{}", base, n);
+                n += 1;
+            }
+            ids.push(id.clone());
+            question.id = id;
+        }
+
+        Self {
+            coverage,
+            questions,
+        }
+    }
+
+    /// Find a question by ID (case-insensitive)
+    pub fn question(&self, id: &str) -> Option<&Question> {
+        self.questions
+            .iter()
+            .find(|q| q.id.eq_ignore_ascii_case(id.trim()))
+    }
+
+    /// Average coverage across categories
+    pub fn overall_score(&self) -> u8 {
+        let total: usize = self.coverage.iter().map(|c| c.score as usize).sum();
+        (total / self.coverage.len().max(1)) as u8
+    }
+
+    /// Render the report as markdown
+    pub fn to_markdown(&self, source: &str) -> String {
+        let mut report = String::from("# Specification Clarifications\n\n");
+        report.push_str(&format!("Source: {}\n\n", source));
+
+        report.push_str("## Coverage\n\n");
+        report.push_str("| Category | Score | Status | Missing |\n");
+        report.push_str("|----------|-------|--------|---------|\n");
+        for coverage in &self.coverage {
+            report.push_str(&format!(
+                "| {} | {}% | {} | {} |\n",
+                coverage.category,
+                coverage.score,
+                coverage.status(),
+                if coverage.missing.is_empty() {
+                    "-".to_string()
+                } else {
+                    coverage.missing.join(", ")
+                }
+            ));
+        }
+        report.push_str(&format!("\nOverall: {}%\n", self.overall_score()));
+
+        report.push_str("\n## Questions\n\n");
+        if self.questions.is_empty() {
+            report.push_str("✓ No open questions. The specification appears well-defined.\n");
+            return report;
+        }
+
+        for question in &self.questions {
+            report.push_str(&format!(
+                "### {} ({}): {}\n\n",
+                question.id,
+                question
+                    .category
+                    .map_or_else(|| "Custom".to_string(), |c| c.to_string()),
+                question.question
+            ));
+            match question.line {
+                Some(line) => report.push_str(&format!(
+                    "Context: {} (line {})\n\n",
+                    question.context, line
+                )),
+                None => report.push_str(&format!("Context: {}\n\n", question.context)),
+            }
+            for option in &question.options {
+                report.push_str(&format!("- **{}**: {}\n", option.label, option.description));
+            }
+            if question.options.is_empty() {
+                report.push_str("- Short answer (5 words or fewer)\n");
+            } else {
+                report.push_str("- **Short**: a different answer (5 words or fewer)\n");
+            }
+            report.push('\n');
+        }
+
+        report.push_str(
+            "Answer with speckit_clarify `answers` (e.g. `{\"id\": \"<question ID>\", \"answer\": \"B\"}`) \
+            to record them in the spec's ## Clarifications section.\n",
+        );
+        report
+    }
+}
+
+/// Whether lowercase text mentions a keyword on word boundaries; a trailing
+/// `*` lets the keyword's last word continue (`encrypt*` matches
+/// "encrypted")
+pub(crate) fn contains_keyword(text: &str, keyword: &str) -> bool {
+    let (stem, prefix) = match keyword.strip_suffix('*') {
+        Some(stem) => (stem, true),
+        None => (keyword, false),
+    };
+    text.match_indices(stem).any(|(start, _)| {
+        let end = start + stem.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric)
+            && (prefix || !after.is_some_and(char::is_alphanumeric))
+    })
+}
+
+/// Questions for vague or hedging wording, one per term
+fn vague_wording(prose: &[(usize, &str)]) -> Vec<Question> {
+    let mut questions: Vec<Question> = Vec::new();
+    let mut seen = Vec::new();
+
+    for &(line_number, line) in prose {
+        let lower = line.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_alphanumeric() && c != '-')
+            .collect();
+        let contains = |term: &str| {
+            if term.contains(' ') {
+                lower.contains(term)
+            } else {
+                words.contains(&term)
+            }
+        };
+
+        for term in HEDGE_TERMS {
+            if !seen.contains(term) && contains(term) {
+                seen.push(*term);
+                questions.push(
+                    Question::new(
+                        Some(ClarifyCategory::Terminology),
+                        format!("The spec says \"{}\": is this part of the feature?", term),
+                        format!("Hedging term \"{}\"", term),
+                    )
+                    .with_options(&[
+                        "Required in this release",
+                        "Optional, nice to have",
+                        "Out of scope",
+                    ])
+                    .at_line(line_number),
+                );
+            }
+        }
+        for term in VAGUE_TERMS {
+            if !seen.contains(term) && contains(term) {
+                seen.push(*term);
+                questions.push(
+                    Question::new(
+                        Some(ClarifyCategory::Terminology),
+                        format!("What does \"{}\" mean in measurable terms?", term),
+                        format!("Vague term \"{}\"", term),
+                    )
+                    .with_options(&[
+                        "Define a measurable threshold",
+                        "Drop it; there is no specific requirement",
+                        "Define it in a glossary",
+                    ])
+                    .at_line(line_number),
+                );
+            }
+        }
+    }
+
+    questions
+}
+
+/// Questions for unresolved placeholders (`[NEEDS CLARIFICATION: ...]`, `TBD`)
+fn placeholders(prose: &[(usize, &str)]) -> Vec<Question> {
+    let mut questions = Vec::new();
+
+    for &(line_number, line) in prose {
+        for placeholder in PLACEHOLDERS {
+            let Some(start) = line.find(placeholder) else {
+                continue;
+            };
+            let marker = if placeholder.starts_with('[') {
+                let end = line[start..]
+                    .find(']')
+                    .map_or(line.len(), |e| start + e + 1);
+                &line[start..end]
+            } else {
+                placeholder
+            };
+            let question = marker
+                .trim_start_matches(placeholder)
+                .trim_start_matches(':')
+                .trim_end_matches(']')
+                .trim();
+            let question = if question.is_empty() {
+                format!("What should replace \"{}\"?", marker)
+            } else {
+                question.to_string()
+            };
+
+            let mut question = Question::new(
+                Some(ClarifyCategory::FunctionalScope),
+                question,
+                format!("Unresolved placeholder \"{}\"", marker),
+            )
+            .at_line(line_number);
+            question.marker = Some(marker.to_string());
+            question.column = Some(start + 1);
+            questions.push(question);
+        }
+    }
+
+    questions
+}
+
+/// An answered question, ready to be recorded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedAnswer {
+    /// The question
+    pub question: String,
+
+    /// The answer text
+    pub answer: String,
+
+    /// Placeholder in the spec to replace with the answer
+    pub marker: Option<String>,
+
+    /// 1-based line of the placeholder
+    pub line: Option<usize>,
+
+    /// 1-based byte column of the placeholder on its line
+    pub column: Option<usize>,
+}
+
+impl ResolvedAnswer {
+    /// An answer to one of the report's questions
+    pub fn new(question: &Question, answer: String) -> Self {
+        Self {
+            question: question.question.clone(),
+            answer,
+            marker: question.marker.clone(),
+            line: question.line,
+            column: question.column,
+        }
+    }
+}
+
+/// Record answers in the spec
+///
+/// Answers are appended to `### Session <date>` under `## Clarifications`
+/// (created before `## Amendment History`, or at the end, if missing) as
+/// `- Q: ... → A: ...` bullets, and placeholders the questions came from
+/// are replaced with the answer on the line they were found on; the same
+/// text elsewhere (an earlier line, a code example) is left alone.
+pub fn integrate_answers(
+    spec: &str,
+    answers: &[ResolvedAnswer],
+    date: NaiveDate,
+) -> Result<String> {
+    if answers.is_empty() {
+        anyhow::bail!("No answers to record");
+    }
+
+    let mut spec = spec.to_string();
+    for answer in answers {
+        if let (Some(marker), Some(line)) = (&answer.marker, answer.line) {
+            spec = replace_placeholder(&spec, marker, line, answer.column, &answer.answer);
+        }
+    }
+
+    let bullets = answers
+        .iter()
+        .map(|a| format!("- Q: {} → A: {}", a.question, a.answer))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let session = format!("Session {}", date);
+
+    let lines: Vec<&str> = spec.split_inclusive('\n').collect();
+    let all = headings(&lines);
+    let whole = 0..lines.len();
+
+    Ok(
+        match find_block(&all, &lines, 2, CLARIFICATIONS_SECTION, whole.clone()) {
+            Some(section) => match find_block(&all, &lines, 3, &session, section.clone()) {
+                Some(range) => {
+                    let existing = lines[range.clone()].concat();
+                    splice(
+                        &lines,
+                        range,
+                        &format!("{}\n{}", existing.trim_end(), bullets),
+                    )
+                }
+                None => splice(
+                    &lines,
+                    section.end..section.end,
+                    &format!("### {}\n\n{}", session, bullets),
+                ),
+            },
+            None => {
+                let at = find_block(&all, &lines, 2, HISTORY_SECTION, whole)
+                    .map_or(lines.len(), |history| history.start);
+                splice(
+                    &lines,
+                    at..at,
+                    &format!(
+                        "## {}\n\n### {}\n\n{}",
+                        CLARIFICATIONS_SECTION, session, bullets
+                    ),
+                )
+            }
+        },
+    )
+}
+
+/// Replace a placeholder at its recorded position, or its first occurrence
+/// on the line when earlier replacements moved it
+fn replace_placeholder(
+    spec: &str,
+    marker: &str,
+    line: usize,
+    column: Option<usize>,
+    answer: &str,
+) -> String {
+    let mut lines: Vec<String> = spec.split_inclusive('\n').map(str::to_string).collect();
+    let Some(text) = line.checked_sub(1).and_then(|index| lines.get_mut(index)) else {
+        return spec.to_string();
+    };
+    let start = column
+        .and_then(|column| column.checked_sub(1))
+        .filter(|&start| {
+            text.get(start..)
+                .is_some_and(|rest| rest.starts_with(marker))
+        })
+        .or_else(|| text.find(marker));
+    match start {
+        Some(start) => {
+            text.replace_range(start..start + marker.len(), answer);
+            lines.concat()
+        }
+        None => spec.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+    }
+
+    #[test]
+    fn test_coverage_and_prioritized_questions() {
+        let spec = "# Spec\n\n## Requirements\n\n- **FR-001**: Users might export reports [NEEDS CLARIFICATION: which formats?]\n\n\
+            As a manager I want reports. Acceptance: exports open in Excel.\n\
+            Invalid input shows an error. Performance: under 200 ms.\n";
+
+        let report = ClarificationReport::build(spec, &[], DEFAULT_MAX_QUESTIONS);
+
+        let scope = &report.coverage[0];
+        assert_eq!(scope.category, ClarifyCategory::FunctionalScope);
+        assert_eq!(scope.score, 66);
+        assert_eq!(scope.missing, ["Scope boundaries"]);
+        assert_eq!(report.coverage[1].score, 0);
+
+        assert_eq!(report.questions.len(), 5);
+        assert!(report.questions[0].id.starts_with("Q-"));
+        assert_eq!(report.questions[0].question, "which formats?");
+        assert_eq!(report.questions[0].line, Some(5));
+        let categories: Vec<_> = report.questions[1..]
+            .iter()
+            .map(|q| q.category.unwrap())
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                ClarifyCategory::FunctionalScope,
+                ClarifyCategory::DataModel,
+                ClarifyCategory::NonFunctional,
+                ClarifyCategory::EdgeCases
+            ]
+        );
+        assert_eq!(
+            report.questions[1].resolve_answer("c"),
+            "Administration and reporting features"
+        );
+
+        let markdown = report.to_markdown("spec.md");
+        assert!(markdown.contains("| Functional Scope | 66% | Partial | Scope boundaries |"));
+        assert!(markdown.contains(&format!(
+            "### {} (Functional Scope): What is explicitly out of scope",
+            report.questions[1].id
+        )));
+    }
+
+    #[test]
+    fn test_question_ids_are_stable() {
+        let spec = "# Spec\n\nExport as [NEEDS CLARIFICATION: which formats?]\n";
+        let before = ClarificationReport::build(spec, &[], DEFAULT_MAX_QUESTIONS);
+
+        // Answering or adding questions must not renumber the others
+        let extended = format!("{}\nSort by [NEEDS CLARIFICATION: which column?]\n", spec);
+        let custom = ["How should we handle edge cases?".to_string()];
+        let after = ClarificationReport::build(&extended, &custom, 50);
+
+        for question in &before.questions {
+            let same = after.question(&question.id).unwrap();
+            assert_eq!(same.question, question.question);
+        }
+        assert!(after.questions.len() > before.questions.len());
+    }
+
+    #[test]
+    fn test_keywords_match_whole_words() {
+        assert!(contains_keyword("only the author may edit", "author"));
+        assert!(!contains_keyword("only the author may edit", "auth"));
+        assert!(contains_keyword(
+            "users authenticate with sso",
+            "authenticat*"
+        ));
+        assert!(!contains_keyword("reauthenticate", "authenticat*"));
+
+        let report = ClarificationReport::build("The author writes posts.", &[], 50);
+        let security = report
+            .coverage
+            .iter()
+            .flat_map(|c| &c.covered)
+            .any(|topic| topic.to_lowercase().contains("security"));
+        assert!(!security);
+    }
+
+    #[test]
+    fn test_vague_terms_are_terminology_questions() {
+        let report = ClarificationReport::build("Search should be fast.\nWe could cache.", &[], 50);
+
+        let terminology: Vec<&Question> = report
+            .questions
+            .iter()
+            .filter(|q| q.line.is_some())
+            .collect();
+        assert_eq!(terminology.len(), 2);
+        assert!(terminology[0].question.contains("\"fast\""));
+        assert!(terminology[1].question.contains("\"could\""));
+        assert_eq!(terminology[1].line, Some(2));
+        assert!(!report.coverage[4]
+            .covered
+            .contains(&"Precise wording".to_string()));
+    }
+
+    #[test]
+    fn test_integrate_answers() {
+        let spec = "# Spec\n\n- **FR-001**: Export as [NEEDS CLARIFICATION: which formats?]\n\n## Amendment History\n\n- 1.1.0\n";
+        let answers = [
+            ResolvedAnswer {
+                question: "which formats?".to_string(),
+                answer: "CSV and PDF".to_string(),
+                marker: Some("[NEEDS CLARIFICATION: which formats?]".to_string()),
+                line: Some(3),
+                column: Some(25),
+            },
+            ResolvedAnswer {
+                question: "What is out of scope?".to_string(),
+                answer: "Scheduling".to_string(),
+                marker: None,
+                line: None,
+                column: None,
+            },
+        ];
+
+        let updated = integrate_answers(spec, &answers, date()).unwrap();
+        assert!(updated.contains("- **FR-001**: Export as CSV and PDF\n"));
+        assert!(updated.contains(
+            "## Clarifications\n\n### Session 2026-10-18\n\n- Q: which formats? → A: CSV and PDF\n- Q: What is out of scope? → A: Scheduling\n\n## Amendment History"
+        ));
+
+        let again = integrate_answers(&updated, &answers[1..], date()).unwrap();
+        assert!(again.contains(
+            "→ A: Scheduling\n- Q: What is out of scope? → A: Scheduling\n\n## Amendment"
+        ));
+        assert_eq!(again.matches("### Session").count(), 1);
+    }
+
+    #[test]
+    fn test_answers_replace_the_questioned_placeholder() {
+        let spec = "# Spec\n\n```text\nRetention: TBD\n```\n\nOwner: TBD\nRetention: TBD\n";
+        let report = ClarificationReport::build(spec, &[], 50);
+        let retention = report
+            .questions
+            .iter()
+            .find(|q| q.marker.as_deref() == Some("TBD") && q.line == Some(8))
+            .unwrap();
+        assert_eq!(retention.column, Some(12));
+
+        let answers = [ResolvedAnswer::new(retention, "90 days".to_string())];
+        let updated = integrate_answers(spec, &answers, date()).unwrap();
+        assert!(updated.starts_with(
+            "# Spec\n\n```text\nRetention: TBD\n```\n\nOwner: TBD\nRetention: 90 days\n"
+        ));
+    }
+}
@@ -0,0 +1,807 @@
+//! Constitution Compliance
+//!
+//! Splits the constitution into principles, recognizes machine-checkable
+//! rules in their text ("tests before implementation", "maximum 3 projects",
+//! "no new dependencies without justification", "must (not) use X") and
+//! evaluates the plan and tasks against them. This is the "Constitution
+//! Check" gate of spec-kit plans.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::amend::HISTORY_SECTION;
+use super::clarification::contains_keyword;
+use super::findings::Location;
+use super::model::{Artifact, ArtifactSet};
+use super::requirements::strip_list_marker;
+
+/// Maximum evidence lines reported per rule
+const MAX_EVIDENCE: usize = 5;
+
+/// Phrases that introduce a dependency in a plan or task list
+const DEPENDENCY_MARKERS: &[&str] = &[
+    "new dependency",
+    "new dependencies",
+    "add dependency",
+    "add dependencies",
+    "dependencies:",
+    "dependencies**:",
+    "cargo add",
+    "npm install",
+    "yarn add",
+    "pip install",
+    "go get",
+];
+
+/// Phrases that justify a dependency
+const JUSTIFICATION_MARKERS: &[&str] = &[
+    "justif",
+    "because",
+    "rationale",
+    "reason",
+    "required for",
+    "needed for",
+];
+
+/// A machine-checkable rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuleKind {
+    /// Test tasks come before implementation tasks
+    TestsFirst,
+
+    /// The plan uses at most this many projects
+    MaxProjects {
+        /// Maximum number of projects
+        max: usize,
+    },
+
+    /// Every new dependency in the plan or tasks is justified
+    JustifiedDependencies,
+
+    /// The plan or tasks mention a term
+    Requires {
+        /// Required term
+        term: String,
+    },
+
+    /// The plan and tasks never mention a term
+    Forbids {
+        /// Forbidden term
+        term: String,
+    },
+}
+
+impl RuleKind {
+    /// Recognize a rule in a line of principle text
+    pub fn detect(line: &str) -> Option<Self> {
+        let text = strip_list_marker(line.trim())
+            .trim_start_matches("**Rule**:")
+            .trim_start_matches("Rule:")
+            .to_lowercase();
+
+        if text.contains("test")
+            && [
+                "before implementation",
+                "test-first",
+                "tests first",
+                "tdd",
+                "test-driven",
+            ]
+            .iter()
+            .any(|phrase| text.contains(phrase))
+        {
+            return Some(Self::TestsFirst);
+        }
+
+        if ["max", "at most", "no more than", "up to", "limit"]
+            .iter()
+            .any(|phrase| text.contains(phrase))
+        {
+            let words: Vec<&str> = text
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .collect();
+            let max = words.windows(2).find_map(|pair| {
+                pair[1]
+                    .starts_with("project")
+                    .then(|| pair[0].parse().ok())
+                    .flatten()
+            });
+            if let Some(max) = max {
+                return Some(Self::MaxProjects { max });
+            }
+        }
+
+        if text.contains("dependenc") && text.contains("justif") {
+            return Some(Self::JustifiedDependencies);
+        }
+
+        for phrase in ["must not use ", "never use ", "do not use "] {
+            if let Some(term) = term_after(&text, phrase) {
+                return Some(Self::Forbids { term });
+            }
+        }
+        term_after(&text, "must use ").map(|term| Self::Requires { term })
+    }
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TestsFirst => write!(f, "Tests before implementation"),
+            Self::MaxProjects { max } => write!(f, "At most {} projects", max),
+            Self::JustifiedDependencies => write!(f, "New dependencies are justified"),
+            Self::Requires { term } => write!(f, "Uses \"{}\"", term),
+            Self::Forbids { term } => write!(f, "Does not use \"{}\"", term),
+        }
+    }
+}
+
+/// The term following a phrase, up to the next punctuation
+fn term_after(text: &str, phrase: &str) -> Option<String> {
+    let (_, rest) = text.split_once(phrase)?;
+    let term = rest
+        .split(['.', ',', ';', ':', '(', ')'])
+        .next()?
+        .trim()
+        .trim_start_matches("the ")
+        .trim_start_matches("any ")
+        .trim_matches(['*', '`', '"'])
+        .trim();
+    (!term.is_empty()).then(|| term.to_string())
+}
+
+/// A rule found in the constitution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// What the rule checks
+    #[serde(flatten)]
+    pub kind: RuleKind,
+
+    /// 1-based line of the rule in the constitution
+    pub line: usize,
+}
+
+/// A constitution principle and its machine-checkable rules
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Principle {
+    /// Principle heading
+    pub name: String,
+
+    /// 1-based line of the heading
+    pub line: usize,
+
+    /// Rules recognized in the principle text
+    pub rules: Vec<Rule>,
+}
+
+/// Split a constitution into principles
+///
+/// Principles are the `###` headings (spec-kit's `### I. Library-First`
+/// style); constitutions without any use their `##` sections instead.
+pub fn parse_principles(constitution: &Artifact) -> Vec<Principle> {
+    let sections = constitution.sections();
+    let level = if sections.iter().any(|s| s.level == 3) {
+        3
+    } else {
+        2
+    };
+    let lines = constitution.prose_lines();
+
+    sections
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.level == level && !s.heading.eq_ignore_ascii_case(HISTORY_SECTION))
+        .map(|(index, section)| {
+            let end = sections[index + 1..]
+                .iter()
+                .find(|s| s.level <= level)
+                .map_or(usize::MAX, |s| s.line);
+            let rules = lines
+                .iter()
+                .filter(|(line, _)| *line > section.line && *line < end)
+                .filter_map(|(line, text)| {
+                    RuleKind::detect(text).map(|kind| Rule { kind, line: *line })
+                })
+                .collect();
+
+            Principle {
+                name: section.heading.clone(),
+                line: section.line,
+                rules,
+            }
+        })
+        .collect()
+}
+
+/// Outcome of a compliance check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceStatus {
+    /// The plan and tasks satisfy the rule
+    Pass,
+
+    /// The plan or tasks violate the rule
+    Fail,
+
+    /// The artifacts needed to check the rule are missing or silent
+    Unverified,
+
+    /// The principle has no machine-checkable rules
+    Manual,
+}
+
+impl fmt::Display for ComplianceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Unverified => "UNVERIFIED",
+            Self::Manual => "MANUAL",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A line supporting a check result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Evidence {
+    /// Where the evidence is
+    pub location: Location,
+
+    /// The line itself
+    pub text: String,
+}
+
+/// Result of checking one rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleResult {
+    /// The rule
+    pub rule: RuleKind,
+
+    /// Where the rule is stated in the constitution
+    pub location: Location,
+
+    /// Outcome
+    pub status: ComplianceStatus,
+
+    /// Explanation of the outcome
+    pub summary: String,
+
+    /// Supporting lines from the plan or tasks
+    pub evidence: Vec<Evidence>,
+}
+
+/// Result of checking one principle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrincipleResult {
+    /// Principle heading
+    pub principle: String,
+
+    /// Where the principle is defined
+    pub location: Location,
+
+    /// Overall outcome: FAIL if any rule fails, UNVERIFIED if any rule could
+    /// not be checked, MANUAL without rules, PASS otherwise
+    pub status: ComplianceStatus,
+
+    /// Per-rule results
+    pub rules: Vec<RuleResult>,
+}
+
+/// Constitution compliance of a plan and task list
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    /// Per-principle results, in constitution order
+    pub principles: Vec<PrincipleResult>,
+}
+
+impl ComplianceReport {
+    /// Number of principles with a status
+    pub fn count(&self, status: ComplianceStatus) -> usize {
+        self.principles
+            .iter()
+            .filter(|p| p.status == status)
+            .count()
+    }
+
+    /// Whether the gate passes (no principle fails)
+    pub fn passed(&self) -> bool {
+        self.count(ComplianceStatus::Fail) == 0
+    }
+
+    /// Render the report as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut report = String::from("# Constitution Check\n\n");
+
+        if self.principles.is_empty() {
+            report.push_str("No principles found in the constitution.\n");
+            return report;
+        }
+
+        report.push_str(&format!(
+            "**Gate: {}** ({} passed, {} failed, {} unverified, {} manual)\n\n",
+            if self.passed() { "PASS" } else { "FAIL" },
+            self.count(ComplianceStatus::Pass),
+            self.count(ComplianceStatus::Fail),
+            self.count(ComplianceStatus::Unverified),
+            self.count(ComplianceStatus::Manual)
+        ));
+        report.push_str("| Principle | Status | Rules | Location |\n");
+        report.push_str("|-----------|--------|-------|----------|\n");
+        for principle in &self.principles {
+            report.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                principle.principle.replace('|', "\\|"),
+                principle.status,
+                principle.rules.len(),
+                principle.location
+            ));
+        }
+
+        for principle in self.principles.iter().filter(|p| !p.rules.is_empty()) {
+            report.push_str(&format!(
+                "\n## {} ({})\n\n",
+                principle.principle, principle.status
+            ));
+            for rule in &principle.rules {
+                report.push_str(&format!(
+                    "- **{}** {} ({}): {}\n",
+                    rule.status, rule.rule, rule.location, rule.summary
+                ));
+                for evidence in &rule.evidence {
+                    report.push_str(&format!(
+                        "  - {}: `{}`\n",
+                        evidence.location,
+                        evidence.text.replace('`', "'")
+                    ));
+                }
+            }
+        }
+
+        if self.count(ComplianceStatus::Manual) > 0 {
+            report.push_str(
+                "\nPrinciples marked MANUAL have no machine-checkable rules; review them by hand.\n",
+            );
+        }
+
+        report
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Check the plan and tasks against the constitution
+pub fn check_compliance(artifacts: &ArtifactSet) -> ComplianceReport {
+    let Some(constitution) = &artifacts.constitution else {
+        return ComplianceReport::default();
+    };
+
+    let principles = parse_principles(constitution)
+        .into_iter()
+        .map(|principle| {
+            let rules: Vec<RuleResult> = principle
+                .rules
+                .iter()
+                .map(|rule| {
+                    let (status, summary, evidence) = evaluate(&rule.kind, artifacts);
+                    RuleResult {
+                        rule: rule.kind.clone(),
+                        location: constitution.location(rule.line),
+                        status,
+                        summary,
+                        evidence,
+                    }
+                })
+                .collect();
+
+            let status = if rules.is_empty() {
+                ComplianceStatus::Manual
+            } else if rules.iter().any(|r| r.status == ComplianceStatus::Fail) {
+                ComplianceStatus::Fail
+            } else if rules
+                .iter()
+                .any(|r| r.status == ComplianceStatus::Unverified)
+            {
+                ComplianceStatus::Unverified
+            } else {
+                ComplianceStatus::Pass
+            };
+
+            PrincipleResult {
+                principle: principle.name,
+                location: constitution.location(principle.line),
+                status,
+                rules,
+            }
+        })
+        .collect();
+
+    ComplianceReport { principles }
+}
+
+type Evaluation = (ComplianceStatus, String, Vec<Evidence>);
+
+fn evaluate(rule: &RuleKind, artifacts: &ArtifactSet) -> Evaluation {
+    let downstream: Vec<&Artifact> = [&artifacts.plan, &artifacts.tasks]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    match rule {
+        RuleKind::TestsFirst => match &artifacts.tasks {
+            Some(tasks) => check_tests_first(tasks),
+            None => unverified("No task list to check"),
+        },
+        RuleKind::MaxProjects { max } => match &artifacts.plan {
+            Some(plan) => check_max_projects(plan, *max),
+            None => unverified("No plan to check"),
+        },
+        RuleKind::JustifiedDependencies if downstream.is_empty() => {
+            unverified("No plan or task list to check")
+        }
+        RuleKind::JustifiedDependencies => check_dependencies(&downstream),
+        RuleKind::Requires { .. } | RuleKind::Forbids { .. } if downstream.is_empty() => {
+            unverified("No plan or task list to check")
+        }
+        RuleKind::Requires { term } => {
+            let found = mentions(&downstream, term);
+            if found.is_empty() {
+                (
+                    ComplianceStatus::Fail,
+                    format!("\"{}\" is not mentioned in the plan or tasks", term),
+                    Vec::new(),
+                )
+            } else {
+                (
+                    ComplianceStatus::Pass,
+                    format!("\"{}\" is used", term),
+                    found.into_iter().take(1).collect(),
+                )
+            }
+        }
+        RuleKind::Forbids { term } => {
+            let found = mentions(&downstream, term);
+            if found.is_empty() {
+                (
+                    ComplianceStatus::Pass,
+                    format!("\"{}\" is not mentioned", term),
+                    Vec::new(),
+                )
+            } else {
+                (
+                    ComplianceStatus::Fail,
+                    format!("\"{}\" is mentioned {} time(s)", term, found.len()),
+                    found.into_iter().take(MAX_EVIDENCE).collect(),
+                )
+            }
+        }
+    }
+}
+
+fn unverified(summary: &str) -> Evaluation {
+    (
+        ComplianceStatus::Unverified,
+        summary.to_string(),
+        Vec::new(),
+    )
+}
+
+fn evidence(artifact: &Artifact, line: usize, text: &str) -> Evidence {
+    Evidence {
+        location: artifact.location(line),
+        text: text.trim().to_string(),
+    }
+}
+
+/// Lines mentioning a term (case-insensitive, whole words: "go" is not
+/// mentioned by "good")
+fn mentions(artifacts: &[&Artifact], term: &str) -> Vec<Evidence> {
+    artifacts
+        .iter()
+        .flat_map(|artifact| {
+            artifact
+                .prose_lines()
+                .into_iter()
+                .filter(|(_, text)| contains_keyword(&text.to_lowercase(), term))
+                .map(|(line, text)| evidence(artifact, line, text))
+        })
+        .collect()
+}
+
+/// Test tasks must come before implementation tasks
+///
+/// Implementation tasks are non-test tasks that say "implement" or sit under
+/// an implementation or core heading.
+fn check_tests_first(tasks: &Artifact) -> Evaluation {
+    let sections = tasks.sections();
+    let heading_of = |line: usize| {
+        sections
+            .iter()
+            .rev()
+            .find(|s| s.line < line)
+            .map(|s| s.heading.to_lowercase())
+            .unwrap_or_default()
+    };
+
+    let entries = tasks.tasks();
+    let is_test = |text: &str| contains_keyword(&text.to_lowercase(), "test*");
+    let first_test = entries.iter().find(|t| is_test(&t.text));
+    let implementation: Vec<_> = entries
+        .iter()
+        .filter(|t| !is_test(&t.text))
+        .filter(|t| {
+            let heading = heading_of(t.line);
+            t.text.to_lowercase().contains("implement")
+                || heading.contains("implementation")
+                || heading.contains("core")
+        })
+        .collect();
+
+    let task_evidence = |task: &super::traceability::TaskEntry| {
+        evidence(tasks, task.line, &format!("{} {}", task.id, task.text))
+    };
+
+    match (first_test, implementation.first()) {
+        (_, None) => unverified("No implementation tasks found"),
+        (None, Some(_)) => (
+            ComplianceStatus::Fail,
+            "No test tasks found".to_string(),
+            implementation
+                .iter()
+                .take(MAX_EVIDENCE)
+                .map(|t| task_evidence(t))
+                .collect(),
+        ),
+        (Some(test), Some(first)) if first.line < test.line => (
+            ComplianceStatus::Fail,
+            format!(
+                "Implementation tasks come before the first test task {}",
+                test.id
+            ),
+            implementation
+                .iter()
+                .filter(|t| t.line < test.line)
+                .take(MAX_EVIDENCE)
+                .map(|t| task_evidence(t))
+                .chain(std::iter::once(task_evidence(test)))
+                .collect(),
+        ),
+        (Some(test), Some(first)) => (
+            ComplianceStatus::Pass,
+            format!(
+                "Test task {} precedes the first implementation task {}",
+                test.id, first.id
+            ),
+            vec![task_evidence(test), task_evidence(first)],
+        ),
+    }
+}
+
+/// The plan's declared project count (`**Projects**: 2`) must not exceed the limit
+fn check_max_projects(plan: &Artifact, max: usize) -> Evaluation {
+    let declared = plan.prose_lines().into_iter().find_map(|(line, text)| {
+        let (label, value) = text.split_once(':')?;
+        if !label.to_lowercase().contains("project") {
+            return None;
+        }
+        let value = value.trim().trim_start_matches('*').trim();
+        let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+        Some((line, text, digits.parse::<usize>().ok()?))
+    });
+
+    match declared {
+        Some((line, text, count)) if count > max => (
+            ComplianceStatus::Fail,
+            format!("Plan uses {} projects (maximum {})", count, max),
+            vec![evidence(plan, line, text)],
+        ),
+        Some((line, text, count)) => (
+            ComplianceStatus::Pass,
+            format!("Plan uses {} project(s) (maximum {})", count, max),
+            vec![evidence(plan, line, text)],
+        ),
+        None => unverified("Plan does not state a project count (e.g. \"**Projects**: 2\")"),
+    }
+}
+
+/// Lines introducing dependencies must carry a justification on the same or
+/// the next line
+fn check_dependencies(artifacts: &[&Artifact]) -> Evaluation {
+    let mut justified = Vec::new();
+    let mut unjustified = Vec::new();
+
+    for artifact in artifacts {
+        let lines = artifact.prose_lines();
+        for (index, (line, text)) in lines.iter().enumerate() {
+            let lower = text.to_lowercase();
+            let Some(marker) = DEPENDENCY_MARKERS.iter().find(|m| lower.contains(*m)) else {
+                continue;
+            };
+            if marker.ends_with(':') {
+                let value = lower.split_once(marker).map_or("", |(_, v)| v).trim();
+                let value = value.trim_matches(['*', '.', ' ']);
+                if value.is_empty() || ["none", "n/a", "-"].contains(&value) {
+                    continue;
+                }
+            }
+
+            let next = lines
+                .get(index + 1)
+                .map_or(String::new(), |(_, t)| t.to_lowercase());
+            let is_justified = JUSTIFICATION_MARKERS
+                .iter()
+                .any(|m| lower.contains(m) || next.contains(m));
+            let item = evidence(artifact, *line, text);
+            if is_justified {
+                justified.push(item);
+            } else {
+                unjustified.push(item);
+            }
+        }
+    }
+
+    if !unjustified.is_empty() {
+        (
+            ComplianceStatus::Fail,
+            format!(
+                "{} dependency line(s) without a justification",
+                unjustified.len()
+            ),
+            unjustified.into_iter().take(MAX_EVIDENCE).collect(),
+        )
+    } else if justified.is_empty() {
+        (
+            ComplianceStatus::Pass,
+            "No new dependencies introduced".to_string(),
+            Vec::new(),
+        )
+    } else {
+        (
+            ComplianceStatus::Pass,
+            format!("{} dependency line(s), all justified", justified.len()),
+            justified.into_iter().take(MAX_EVIDENCE).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifacts::ArtifactKind;
+
+    const CONSTITUTION: &str = "# Constitution\n\n## Core Principles\n\n\
+        ### I. Test-First\n\nTests MUST be written before implementation.\n\n\
+        ### II. Simplicity\n\n- Maximum 3 projects\n- No new dependencies without justification\n\n\
+        ### III. Storage\n\nWe must not use MongoDB.\n\n\
+        ### IV. Craft\n\nCode is reviewed.\n";
+
+    fn artifacts(plan: &str, tasks: &str) -> ArtifactSet {
+        let mut set = ArtifactSet::default();
+        set.insert(Artifact::new(
+            ArtifactKind::Constitution,
+            "constitution.md",
+            CONSTITUTION,
+        ));
+        set.insert(Artifact::new(ArtifactKind::Plan, "plan.md", plan));
+        set.insert(Artifact::new(ArtifactKind::Tasks, "tasks.md", tasks));
+        set
+    }
+
+    #[test]
+    fn test_detect_rules() {
+        assert_eq!(
+            RuleKind::detect("- Tests MUST be written before implementation"),
+            Some(RuleKind::TestsFirst)
+        );
+        assert_eq!(
+            RuleKind::detect("Rule: max 3 projects"),
+            Some(RuleKind::MaxProjects { max: 3 })
+        );
+        assert_eq!(
+            RuleKind::detect("No new dependencies without justification"),
+            Some(RuleKind::JustifiedDependencies)
+        );
+        assert_eq!(
+            RuleKind::detect("Services MUST use structured logging."),
+            Some(RuleKind::Requires {
+                term: "structured logging".to_string()
+            })
+        );
+        assert_eq!(
+            RuleKind::detect("We must not use MongoDB (licensing)"),
+            Some(RuleKind::Forbids {
+                term: "mongodb".to_string()
+            })
+        );
+        assert_eq!(RuleKind::detect("Code is reviewed."), None);
+    }
+
+    #[test]
+    fn test_compliant_plan_passes() {
+        let report = check_compliance(&artifacts(
+            "# Plan\n\n**Projects**: 2 (api, cli)\n\n**Primary Dependencies**: axum, required for HTTP\n",
+            "## Tests\n\n- [ ] T001 Contract test for login\n\n## Core Implementation\n\n- [ ] T002 Login handler\n",
+        ));
+
+        assert!(report.passed());
+        let statuses: Vec<_> = report.principles.iter().map(|p| p.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                ComplianceStatus::Pass,
+                ComplianceStatus::Pass,
+                ComplianceStatus::Pass,
+                ComplianceStatus::Manual
+            ]
+        );
+        assert!(report.to_markdown().contains("**Gate: PASS**"));
+    }
+
+    #[test]
+    fn test_violations_have_evidence() {
+        let report = check_compliance(&artifacts(
+            "# Plan\n\n**Projects**: 4\n\nStore sessions in MongoDB.\n\nRun `cargo add serde`.\n",
+            "- [ ] T001 Implement login\n- [ ] T002 Test login\n",
+        ));
+
+        assert!(!report.passed());
+        let tests_first = &report.principles[0];
+        assert_eq!(tests_first.status, ComplianceStatus::Fail);
+        assert_eq!(
+            tests_first.rules[0].location.to_string(),
+            "constitution.md:7"
+        );
+        assert_eq!(
+            tests_first.rules[0].evidence[0].location.to_string(),
+            "tasks.md:1"
+        );
+
+        let simplicity = &report.principles[1];
+        assert_eq!(simplicity.rules[0].status, ComplianceStatus::Fail);
+        assert_eq!(
+            simplicity.rules[0].evidence[0].location.to_string(),
+            "plan.md:3"
+        );
+        assert_eq!(simplicity.rules[1].status, ComplianceStatus::Fail);
+        assert_eq!(
+            simplicity.rules[1].evidence[0].location.to_string(),
+            "plan.md:7"
+        );
+
+        let storage = &report.principles[2];
+        assert_eq!(storage.status, ComplianceStatus::Fail);
+        assert_eq!(
+            storage.rules[0].evidence[0].location.to_string(),
+            "plan.md:5"
+        );
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("**Gate: FAIL**"));
+        assert!(markdown.contains("  - plan.md:3: `**Projects**: 4`"));
+    }
+
+    #[test]
+    fn test_terms_match_whole_words() {
+        let mut set = artifacts(
+            "# Plan\n\nA good design using the latest Rust.\n",
+            "## Core Implementation\n\n- [ ] T001 Build the contest page\n- [ ] T002 Testing harness\n",
+        );
+        set.insert(Artifact::new(
+            ArtifactKind::Constitution,
+            "constitution.md",
+            "## Principles\n\n- Services must not use Go\n- Tests MUST be written before implementation\n",
+        ));
+        let report = check_compliance(&set);
+
+        let rules = &report.principles[0].rules;
+        assert_eq!(rules[0].status, ComplianceStatus::Pass);
+        // "contest" is not a test task, so implementation comes first
+        assert_eq!(rules[1].status, ComplianceStatus::Fail);
+        assert_eq!(rules[1].evidence[0].text, "T001 Build the contest page");
+    }
+}
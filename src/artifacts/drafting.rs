@@ -0,0 +1,300 @@
+//! Artifact Drafting
+//!
+//! Prompts, templates and structural validation for plan and task drafts
+//! generated by the client's model through MCP sampling.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+use super::markdown::MarkdownDocument;
+use super::model::{ArtifactKind, ArtifactSet};
+use super::requirements::RequirementKind;
+use super::templates::TemplateKind;
+use super::traceability::parse_tasks;
+
+/// Default number of generation attempts before giving up
+pub const DEFAULT_DRAFT_ATTEMPTS: u32 = 3;
+
+/// Upper bound on generation attempts a caller may request
+pub const MAX_DRAFT_ATTEMPTS: u32 = 5;
+
+/// Token budget requested for a draft
+pub const DRAFT_MAX_TOKENS: u32 = 8192;
+
+/// Template text that must not survive into a draft
+const TEMPLATE_PLACEHOLDERS: &[&str] = &[
+    "[FEATURE]",
+    "[FEATURE NAME]",
+    "[###-feature-name]",
+    "[DATE]",
+    "ACTION REQUIRED",
+];
+
+/// An artifact that can be drafted through sampling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DraftKind {
+    /// Technical plan (`plan.md`)
+    Plan,
+
+    /// Task list (`tasks.md`)
+    Tasks,
+}
+
+impl DraftKind {
+    /// Artifact kind the draft becomes
+    pub fn artifact_kind(&self) -> ArtifactKind {
+        match self {
+            DraftKind::Plan => ArtifactKind::Plan,
+            DraftKind::Tasks => ArtifactKind::Tasks,
+        }
+    }
+
+    /// Template the draft follows
+    pub fn template_kind(&self) -> TemplateKind {
+        match self {
+            DraftKind::Plan => TemplateKind::Plan,
+            DraftKind::Tasks => TemplateKind::Tasks,
+        }
+    }
+
+    /// Artifacts given to the model as context, in prompt order
+    fn context_kinds(&self) -> &'static [ArtifactKind] {
+        match self {
+            DraftKind::Plan => &[ArtifactKind::Constitution, ArtifactKind::Specification],
+            DraftKind::Tasks => &[
+                ArtifactKind::Constitution,
+                ArtifactKind::Specification,
+                ArtifactKind::Plan,
+            ],
+        }
+    }
+}
+
+impl fmt::Display for DraftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DraftKind::Plan => write!(f, "implementation plan"),
+            DraftKind::Tasks => write!(f, "task list"),
+        }
+    }
+}
+
+/// System prompt for drafting an artifact
+pub fn system_prompt(kind: DraftKind) -> String {
+    format!(
+        "You are a spec-driven development assistant following GitHub Spec-Kit. \
+        Write the {} as a complete markdown document that follows the template's \
+        structure. Replace every bracketed placeholder, keep requirement IDs \
+        (FR-001, NFR-001, ...) exactly as written in the specification, and reply \
+        with the document only.",
+        kind
+    )
+}
+
+/// User prompt with the template, the existing artifacts and optional guidance
+pub fn draft_prompt(
+    kind: DraftKind,
+    template: &str,
+    artifacts: &ArtifactSet,
+    guidance: Option<&str>,
+) -> String {
+    let mut prompt = format!("Draft the {} for this feature.\n", kind);
+
+    if let Some(guidance) = guidance.filter(|g| !g.trim().is_empty()) {
+        prompt.push_str(&format!("\n{}\n", guidance.trim()));
+    }
+
+    for artifact_kind in kind.context_kinds() {
+        if let Some(artifact) = artifacts.get(*artifact_kind) {
+            prompt.push_str(&format!(
+                "\n<{0}>\n{1}\n</{0}>\n",
+                artifact.label,
+                artifact.content.trim()
+            ));
+        }
+    }
+
+    prompt.push_str(&format!("\n<template>\n{}\n</template>\n", template.trim()));
+    prompt
+}
+
+/// Feedback prompt asking the model to fix a rejected draft
+pub fn retry_prompt(problems: &[String]) -> String {
+    format!(
+        "The draft does not have the expected structure:\n{}\n\n\
+        Reply with the corrected document only.",
+        problems
+            .iter()
+            .map(|problem| format!("- {}", problem))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
+/// Strip a surrounding code fence that models often wrap documents in
+pub fn clean_draft(text: &str) -> String {
+    let trimmed = text.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return format!("{}\n", trimmed);
+    };
+    let Some((_language, body)) = rest.split_once('\n') else {
+        return format!("{}\n", trimmed);
+    };
+    match body.trim_end().strip_suffix("```") {
+        Some(body) => format!("{}\n", body.trim()),
+        None => format!("{}\n", trimmed),
+    }
+}
+
+/// Check a draft against the expected structure, returning the problems found
+///
+/// Plans need an H1 title and the template's core sections; task lists need
+/// sequential `T001`-style task IDs that cover every functional requirement
+/// of the specification. Neither may keep template placeholders.
+pub fn validate_draft(kind: DraftKind, draft: &str, artifacts: &ArtifactSet) -> Vec<String> {
+    let mut problems = Vec::new();
+    let document = MarkdownDocument::parse(draft);
+
+    let first_line = document
+        .prose_lines()
+        .into_iter()
+        .find(|(_, text)| !text.trim().is_empty())
+        .map(|(line, _)| line);
+    if !document
+        .sections()
+        .first()
+        .is_some_and(|section| section.level == 1 && Some(section.line) == first_line)
+    {
+        problems.push("The document must start with a `# ` title".to_string());
+    }
+
+    for placeholder in TEMPLATE_PLACEHOLDERS {
+        if draft.contains(placeholder) {
+            problems.push(format!(
+                "Template placeholder `{}` was not filled in",
+                placeholder
+            ));
+        }
+    }
+
+    match kind {
+        DraftKind::Plan => validate_plan(&document, &mut problems),
+        DraftKind::Tasks => validate_tasks(draft, artifacts, &mut problems),
+    }
+
+    problems
+}
+
+fn validate_plan(document: &MarkdownDocument, problems: &mut Vec<String>) {
+    let headings: Vec<String> = document
+        .sections()
+        .iter()
+        .filter(|section| section.level == 2)
+        .map(|section| section.heading.to_lowercase())
+        .collect();
+
+    for section in TemplateKind::Plan.required_sections() {
+        let name = section.to_lowercase();
+        if !headings.iter().any(|heading| heading.contains(&name)) {
+            problems.push(format!("Missing `## {}` section", section));
+        }
+    }
+}
+
+fn validate_tasks(draft: &str, artifacts: &ArtifactSet, problems: &mut Vec<String>) {
+    let tasks: Vec<_> = parse_tasks(draft)
+        .into_iter()
+        .filter(|task| task.id.starts_with('T'))
+        .collect();
+    if tasks.is_empty() {
+        problems.push("No tasks found; list them as `- [ ] T001 Description`".to_string());
+        return;
+    }
+
+    let mut seen = HashSet::new();
+    for (index, task) in tasks.iter().enumerate() {
+        let expected = format!("T{:03}", index + 1);
+        if !seen.insert(task.id.clone()) {
+            problems.push(format!("Task ID {} is used more than once", task.id));
+        } else if task.id != expected {
+            problems.push(format!(
+                "Task IDs must be sequential: found {} where {} was expected",
+                task.id, expected
+            ));
+            break;
+        }
+    }
+
+    let Some(spec) = &artifacts.specification else {
+        return;
+    };
+    let referenced: HashSet<_> = tasks
+        .iter()
+        .flat_map(|task| task.requirements.iter().copied())
+        .collect();
+    let uncovered: Vec<String> = spec
+        .requirements()
+        .into_iter()
+        .map(|requirement| requirement.id)
+        .filter(|id| id.kind == RequirementKind::Functional && !referenced.contains(id))
+        .map(|id| id.to_string())
+        .collect();
+    if !uncovered.is_empty() {
+        problems.push(format!(
+            "No task references {}; add the requirement IDs each task implements",
+            uncovered.join(", ")
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifacts::{Artifact, PLAN_TEMPLATE};
+
+    fn with_spec(spec: &str) -> ArtifactSet {
+        let mut set = ArtifactSet::default();
+        set.insert(Artifact::new(ArtifactKind::Specification, "spec.md", spec));
+        set
+    }
+
+    #[test]
+    fn test_validate_plan_draft() {
+        let set = ArtifactSet::default();
+        let problems = validate_draft(DraftKind::Plan, PLAN_TEMPLATE, &set);
+        assert!(problems.iter().any(|p| p.contains("`[FEATURE]`")));
+
+        let draft = "# Plan: Export\n\n## Summary\n\nx\n\n## Technical Context\n\nx\n\n## Project Structure\n\nx\n";
+        let problems = validate_draft(DraftKind::Plan, draft, &set);
+        assert_eq!(problems, vec!["Missing `## Constitution Check` section"]);
+    }
+
+    #[test]
+    fn test_validate_tasks_draft() {
+        let set = with_spec("## Requirements\n\n- **FR-001**: Export\n- **FR-002**: Import\n");
+
+        let draft = "# Tasks\n\n- [ ] T001 Export CSV (FR-001)\n- [ ] T003 Import (FR-002)\n";
+        let problems = validate_draft(DraftKind::Tasks, draft, &set);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("found T003 where T002 was expected"));
+
+        let draft = "# Tasks\n\n- [ ] T001 Export CSV (FR-001)\n- [ ] T002 Docs\n";
+        let problems = validate_draft(DraftKind::Tasks, draft, &set);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("No task references FR-002"));
+
+        let draft = "# Tasks\n\n- [ ] T001 Export (FR-001)\n- [ ] T002 Import (FR-002)\n";
+        assert!(validate_draft(DraftKind::Tasks, draft, &set).is_empty());
+    }
+
+    #[test]
+    fn test_clean_draft_strips_fence() {
+        assert_eq!(
+            clean_draft("```markdown\n# Plan\n\nBody\n```\n"),
+            "# Plan\n\nBody\n"
+        );
+        assert_eq!(clean_draft("# Plan\n"), "# Plan\n");
+    }
+}
@@ -0,0 +1,343 @@
+//! Analysis Findings
+//!
+//! Severity-graded findings produced by artifact analysis, and the report
+//! that renders them as markdown and JSON.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Finding severity, ordered from most to least severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Severity {
+    /// Blocks implementation (constitution violation, missing core artifact)
+    Critical,
+
+    /// Should be fixed before implementation
+    High,
+
+    /// Worth fixing, does not block
+    Medium,
+
+    /// Style or wording improvement
+    Low,
+}
+
+impl Severity {
+    /// All severities, most severe first
+    pub const ALL: [Severity; 4] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+    ];
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Critical => "CRITICAL",
+            Self::High => "HIGH",
+            Self::Medium => "MEDIUM",
+            Self::Low => "LOW",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Finding category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingCategory {
+    /// Duplicate or near-duplicate requirements
+    Duplication,
+
+    /// Vague adjectives or unresolved placeholders
+    Ambiguity,
+
+    /// Same concept spelled differently across artifacts
+    Terminology,
+
+    /// Conflict with a constitution principle
+    Constitution,
+
+    /// Tasks or requirements missing actionable detail
+    Underspecification,
+
+    /// Requirements without tasks, tasks without requirements
+    Coverage,
+}
+
+impl FindingCategory {
+    /// Prefix for finding IDs in this category
+    pub fn id_prefix(&self) -> char {
+        match self {
+            Self::Duplication => 'D',
+            Self::Ambiguity => 'A',
+            Self::Terminology => 'T',
+            Self::Constitution => 'C',
+            Self::Underspecification => 'U',
+            Self::Coverage => 'G',
+        }
+    }
+}
+
+impl fmt::Display for FindingCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Duplication => "Duplication",
+            Self::Ambiguity => "Ambiguity",
+            Self::Terminology => "Terminology",
+            Self::Constitution => "Constitution",
+            Self::Underspecification => "Underspecification",
+            Self::Coverage => "Coverage",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Where a finding applies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    /// Artifact file name
+    pub file: String,
+
+    /// 1-based line number, if the finding applies to a specific line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+impl Location {
+    /// Location of a whole file
+    pub fn file(file: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            line: None,
+        }
+    }
+
+    /// Location of a line within a file
+    pub fn line(file: impl Into<String>, line: usize) -> Self {
+        Self {
+            file: file.into(),
+            line: Some(line),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}", self.file, line),
+            None => f.write_str(&self.file),
+        }
+    }
+}
+
+/// A single analysis finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Finding ID (e.g. `A1`), assigned when added to a report
+    pub id: String,
+
+    /// Category
+    pub category: FindingCategory,
+
+    /// Severity
+    pub severity: Severity,
+
+    /// Where the finding applies
+    pub location: Location,
+
+    /// What is wrong
+    pub summary: String,
+
+    /// How to fix it
+    pub recommendation: String,
+}
+
+impl Finding {
+    /// Create a finding (the ID is assigned by [`AnalysisReport::new`])
+    pub fn new(
+        category: FindingCategory,
+        severity: Severity,
+        location: Location,
+        summary: impl Into<String>,
+        recommendation: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: String::new(),
+            category,
+            severity,
+            location,
+            summary: summary.into(),
+            recommendation: recommendation.into(),
+        }
+    }
+}
+
+/// Summary metrics for an analysis run
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AnalysisMetrics {
+    /// Number of artifacts analyzed
+    pub artifacts: usize,
+
+    /// Number of requirements with IDs
+    pub requirements: usize,
+
+    /// Number of tasks
+    pub tasks: usize,
+
+    /// Percentage of requirements covered by at least one task
+    pub coverage_percent: usize,
+}
+
+/// The result of analyzing a project's artifacts
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AnalysisReport {
+    /// Findings, most severe first
+    pub findings: Vec<Finding>,
+
+    /// Summary metrics
+    pub metrics: AnalysisMetrics,
+}
+
+impl AnalysisReport {
+    /// Create a report, sorting findings by severity and assigning IDs
+    pub fn new(mut findings: Vec<Finding>, metrics: AnalysisMetrics) -> Self {
+        findings.sort_by_key(|f| (f.severity, f.category));
+
+        let mut counters = std::collections::HashMap::new();
+        for finding in &mut findings {
+            let counter = counters.entry(finding.category).or_insert(0);
+            *counter += 1;
+            finding.id = format!("{}{}", finding.category.id_prefix(), counter);
+        }
+
+        Self { findings, metrics }
+    }
+
+    /// Number of findings with a given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    }
+
+    /// Whether any finding blocks implementation
+    pub fn has_blocking_issues(&self) -> bool {
+        self.count(Severity::Critical) > 0
+    }
+
+    /// Render the report as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut report = String::from("# Specification Analysis Report\n\n");
+
+        if self.findings.is_empty() {
+            report.push_str("✓ No issues found.\n");
+        } else {
+            report.push_str("| ID | Category | Severity | Location | Summary | Recommendation |\n");
+            report.push_str("|----|----------|----------|----------|---------|----------------|\n");
+            for finding in &self.findings {
+                report.push_str(&format!(
+                    "| {} | {} | {} | {} | {} | {} |\n",
+                    finding.id,
+                    finding.category,
+                    finding.severity,
+                    finding.location,
+                    finding.summary.replace('|', "\\|"),
+                    finding.recommendation.replace('|', "\\|")
+                ));
+            }
+        }
+
+        report.push_str("\n## Metrics\n\n");
+        report.push_str(&format!(
+            "- Artifacts analyzed: {}\n",
+            self.metrics.artifacts
+        ));
+        report.push_str(&format!("- Requirements: {}\n", self.metrics.requirements));
+        report.push_str(&format!("- Tasks: {}\n", self.metrics.tasks));
+        report.push_str(&format!(
+            "- Requirement coverage: {}%\n",
+            self.metrics.coverage_percent
+        ));
+        for severity in Severity::ALL {
+            report.push_str(&format!(
+                "- {} issues: {}\n",
+                severity,
+                self.count(severity)
+            ));
+        }
+
+        report.push_str("\n## Next Actions\n\n");
+        if self.has_blocking_issues() {
+            report.push_str("- Resolve CRITICAL issues before running speckit_implement\n");
+        }
+        if self.count(Severity::High) > 0 {
+            report.push_str("- Address HIGH issues; proceeding without them is risky\n");
+        }
+        if self.count(Severity::Critical) + self.count(Severity::High) == 0 {
+            report.push_str("- No blocking issues; proceed to implementation\n");
+        }
+        if self.count(Severity::Medium) + self.count(Severity::Low) > 0 {
+            report.push_str("- MEDIUM and LOW issues can be fixed as improvements\n");
+        }
+
+        report
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(category: FindingCategory, severity: Severity) -> Finding {
+        Finding::new(
+            category,
+            severity,
+            Location::line("spec.md", 3),
+            "summary",
+            "recommendation",
+        )
+    }
+
+    #[test]
+    fn test_report_sorts_and_assigns_ids() {
+        let report = AnalysisReport::new(
+            vec![
+                finding(FindingCategory::Ambiguity, Severity::Medium),
+                finding(FindingCategory::Constitution, Severity::Critical),
+                finding(FindingCategory::Ambiguity, Severity::High),
+            ],
+            AnalysisMetrics::default(),
+        );
+
+        let ids: Vec<&str> = report.findings.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, vec!["C1", "A1", "A2"]);
+        assert!(report.has_blocking_issues());
+    }
+
+    #[test]
+    fn test_report_rendering() {
+        let report = AnalysisReport::new(
+            vec![finding(FindingCategory::Coverage, Severity::High)],
+            AnalysisMetrics::default(),
+        );
+
+        let markdown = report.to_markdown();
+        assert!(
+            markdown.contains("| G1 | Coverage | HIGH | spec.md:3 | summary | recommendation |")
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["findings"][0]["severity"], "HIGH");
+        assert_eq!(json["findings"][0]["location"]["line"], 3);
+    }
+}
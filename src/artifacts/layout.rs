@@ -0,0 +1,725 @@
+//! Project Layout Resolution
+//!
+//! Resolves where spec-kit artifacts live. Real spec-kit projects keep the
+//! constitution in `.specify/memory/constitution.md` and each feature in
+//! `specs/NNN-feature-name/`; older projects use flat `speckit.*` files in the
+//! project directory, which remain supported as a fallback.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use super::model::ArtifactKind;
+use crate::speckit::{GitRepo, SpecKitError};
+use crate::utils::PathPolicy;
+
+/// Spec-kit configuration directory
+pub const SPECIFY_DIR: &str = ".specify";
+
+/// Directory holding one subdirectory per feature
+pub const SPECS_DIR: &str = "specs";
+
+/// Environment variable spec-kit uses to select the active feature
+pub const FEATURE_ENV: &str = "SPECIFY_FEATURE";
+
+/// File under `.specify/` recording the active feature
+pub const ACTIVE_FEATURE_FILE: &str = "active-feature";
+
+/// Maximum number of words in a generated feature slug
+const MAX_SLUG_WORDS: usize = 3;
+
+/// How many directory levels below a workspace root are searched for projects
+const DISCOVERY_DEPTH: usize = 2;
+
+/// Directories never searched for projects
+const DISCOVERY_SKIP: &[&str] = &["node_modules", "target", "vendor"];
+
+/// Words dropped from feature descriptions when building slugs
+const SLUG_STOP_WORDS: &[&str] = &[
+    "a",
+    "an",
+    "the",
+    "to",
+    "for",
+    "of",
+    "in",
+    "on",
+    "and",
+    "or",
+    "with",
+    "add",
+    "create",
+    "implement",
+    "support",
+    "new",
+    "i",
+    "want",
+    "we",
+    "need",
+];
+
+/// How a project stores its artifacts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    /// `.specify/` and `specs/NNN-feature/` directories
+    SpecKit,
+
+    /// Flat `speckit.*` files in the project directory
+    Flat,
+}
+
+/// Where a spec-kit layout keeps its artifacts
+///
+/// The constitution is relative to the project root, the other artifacts to
+/// the active feature directory. Flat projects always use `speckit.*` files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArtifactPaths {
+    /// Constitution, relative to the project root
+    pub constitution: PathBuf,
+
+    /// Specification, relative to the feature directory
+    pub spec: PathBuf,
+
+    /// Technical plan, relative to the feature directory
+    pub plan: PathBuf,
+
+    /// Task list, relative to the feature directory
+    pub tasks: PathBuf,
+}
+
+impl Default for ArtifactPaths {
+    fn default() -> Self {
+        Self {
+            constitution: PathBuf::from(SPECIFY_DIR).join("memory/constitution.md"),
+            spec: PathBuf::from("spec.md"),
+            plan: PathBuf::from("plan.md"),
+            tasks: PathBuf::from("tasks.md"),
+        }
+    }
+}
+
+impl ArtifactPaths {
+    /// Specification, plan and task list, relative to the feature directory
+    pub fn feature_files(&self) -> [&Path; 3] {
+        [&self.spec, &self.plan, &self.tasks]
+    }
+}
+
+/// A numbered feature directory such as `specs/001-user-auth`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feature {
+    /// Feature number (`1` for `001-user-auth`)
+    pub number: u32,
+
+    /// Directory name (`001-user-auth`)
+    pub name: String,
+
+    /// Path to the feature directory
+    pub path: PathBuf,
+}
+
+impl Feature {
+    /// Parse a feature from its directory path
+    pub fn from_path(path: impl Into<PathBuf>) -> Option<Self> {
+        let path = path.into();
+        let name = path.file_name()?.to_str()?.to_string();
+        let (number, slug) = name.split_once('-')?;
+        if number.len() < 3 || slug.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(Self {
+            number: number.parse().ok()?,
+            name,
+            path,
+        })
+    }
+
+    /// Path to a file inside the feature directory
+    pub fn file(&self, name: impl AsRef<Path>) -> PathBuf {
+        self.path.join(name)
+    }
+}
+
+/// Resolved locations of a project's artifacts
+#[derive(Debug, Clone)]
+pub struct ProjectLayout {
+    root: PathBuf,
+    kind: LayoutKind,
+    feature: Option<Feature>,
+    artifacts: ArtifactPaths,
+}
+
+impl ProjectLayout {
+    /// Resolve the layout for a directory
+    ///
+    /// Walks up from `start` to the nearest `.specify/`, stopping at the
+    /// enclosing git repository root; a bare `specs/` directory only counts
+    /// in `start` itself. The active feature is taken from
+    /// `SPECIFY_FEATURE` when set, then from the current git branch when it
+    /// names a feature, then from `.specify/active-feature`, and otherwise is
+    /// the highest-numbered feature.
+    pub fn resolve(start: &Path) -> Self {
+        let hint = std::env::var(FEATURE_ENV).ok();
+        Self::resolve_with_feature(start, hint.as_deref())
+    }
+
+    /// Resolve the layout for a project path checked against the workspace
+    /// policy
+    ///
+    /// A project root found outside the allowed roots is ignored, so the
+    /// layout never leaves the sandbox: the checked directory is then used
+    /// as a flat project. Artifact locations come from the policy's
+    /// configured [`ArtifactPaths`].
+    pub fn resolve_in(project_path: &Path, paths: &PathPolicy) -> Result<Self> {
+        let hint = std::env::var(FEATURE_ENV).ok();
+        Self::resolve_with_feature_in(project_path, hint.as_deref(), paths)
+    }
+
+    /// [`resolve_in`](Self::resolve_in), selecting the named feature if it
+    /// exists
+    pub fn resolve_with_feature_in(
+        project_path: &Path,
+        feature: Option<&str>,
+        paths: &PathPolicy,
+    ) -> Result<Self> {
+        let start = paths.check(project_path)?;
+        let layout = Self::resolve_with_feature(&start, feature);
+        if paths.check(&layout.root).is_err() {
+            tracing::debug!(
+                root = %layout.root.display(),
+                "Ignoring project root outside the workspace"
+            );
+            return Ok(Self::flat(start));
+        }
+        Ok(layout.with_artifact_paths(paths.artifact_paths().clone()))
+    }
+
+    /// Resolve the layout, selecting the named feature if it exists
+    pub fn resolve_with_feature(start: &Path, feature: Option<&str>) -> Self {
+        let start = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
+
+        let Some(root) = find_speckit_root(&start) else {
+            return Self::flat(start);
+        };
+
+        let mut layout = Self {
+            root,
+            kind: LayoutKind::SpecKit,
+            feature: None,
+            artifacts: ArtifactPaths::default(),
+        };
+        let recorded = std::fs::read_to_string(layout.active_feature_file()).ok();
+        layout.feature = feature
+            .and_then(|name| layout.find_feature(name))
+            .or_else(|| layout.branch_feature())
+            .or_else(|| recorded.and_then(|name| layout.find_feature(name.trim())))
+            .or_else(|| layout.features().into_iter().last());
+        layout
+    }
+
+    /// A flat layout rooted at a directory
+    pub fn flat(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            kind: LayoutKind::Flat,
+            feature: None,
+            artifacts: ArtifactPaths::default(),
+        }
+    }
+
+    /// Use these artifact locations instead of spec-kit's defaults
+    pub fn with_artifact_paths(mut self, artifacts: ArtifactPaths) -> Self {
+        self.artifacts = artifacts;
+        self
+    }
+
+    /// Where this project keeps its artifacts
+    pub fn artifact_paths(&self) -> &ArtifactPaths {
+        &self.artifacts
+    }
+
+    /// Project root directory
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Layout kind
+    pub fn kind(&self) -> LayoutKind {
+        self.kind
+    }
+
+    /// Active feature, if any
+    pub fn feature(&self) -> Option<&Feature> {
+        self.feature.as_ref()
+    }
+
+    /// Select the active feature by directory name
+    pub fn select_feature(mut self, name: &str) -> Result<Self> {
+        let feature = self
+            .feature_named(name)
+            .ok_or_else(|| SpecKitError::InvalidPath(format!("Feature not found: {}", name)))?;
+        self.feature = Some(feature);
+        Ok(self)
+    }
+
+    /// Find a feature by directory name (`002-billing`) or number (`2`, `002`)
+    pub fn find_feature(&self, query: &str) -> Option<Feature> {
+        let number = query.parse::<u32>().ok();
+        self.features()
+            .into_iter()
+            .find(|f| f.name == query || Some(f.number) == number)
+    }
+
+    /// Find a feature by exact directory name
+    fn feature_named(&self, name: &str) -> Option<Feature> {
+        self.features().into_iter().find(|f| f.name == name)
+    }
+
+    /// Feature named by the current git branch, which takes precedence over
+    /// the recorded active feature
+    pub fn branch_feature(&self) -> Option<Feature> {
+        let branch = GitRepo::discover(&self.root)?.current_branch()?;
+        self.feature_named(&branch)
+    }
+
+    /// File recording the active feature
+    pub fn active_feature_file(&self) -> PathBuf {
+        self.root.join(SPECIFY_DIR).join(ACTIVE_FEATURE_FILE)
+    }
+
+    /// Make a feature active for this and later resolutions
+    ///
+    /// The record is written atomically through the workspace policy.
+    pub async fn set_active_feature(&mut self, feature: Feature, paths: &PathPolicy) -> Result<()> {
+        paths
+            .write(&self.active_feature_file(), format!("{}\n", feature.name))
+            .await?;
+
+        self.kind = LayoutKind::SpecKit;
+        self.feature = Some(feature);
+        Ok(())
+    }
+
+    /// Create the next numbered feature directory and make it active
+    ///
+    /// The slug comes from `short_name` when given, otherwise from the first
+    /// meaningful words of `description`.
+    pub async fn create_feature(
+        &mut self,
+        description: &str,
+        short_name: Option<&str>,
+        paths: &PathPolicy,
+    ) -> Result<Feature> {
+        let slug = feature_slug(short_name.unwrap_or(description));
+        if slug.is_empty() {
+            return Err(SpecKitError::InvalidPath(
+                "Feature description must contain letters or digits".to_string(),
+            )
+            .into());
+        }
+
+        let number = self.features().last().map(|f| f.number + 1).unwrap_or(1);
+        let path = paths.check(&self.specs_dir().join(format!("{:03}-{}", number, slug)))?;
+        tokio::fs::create_dir_all(&path).await?;
+
+        let feature = Feature::from_path(path)
+            .ok_or_else(|| SpecKitError::InvalidPath("Invalid feature directory".to_string()))?;
+        self.set_active_feature(feature.clone(), paths).await?;
+        Ok(feature)
+    }
+
+    /// Directory containing feature directories
+    pub fn specs_dir(&self) -> PathBuf {
+        self.root.join(SPECS_DIR)
+    }
+
+    /// All feature directories, ordered by number
+    pub fn features(&self) -> Vec<Feature> {
+        let Ok(entries) = std::fs::read_dir(self.specs_dir()) else {
+            return Vec::new();
+        };
+
+        let mut features: Vec<Feature> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| Feature::from_path(entry.path()))
+            .collect();
+        features.sort_by(|a, b| (a.number, &a.name).cmp(&(b.number, &b.name)));
+        features
+    }
+
+    /// Flat-layout path of an artifact
+    pub fn flat_path(&self, kind: ArtifactKind) -> PathBuf {
+        self.root.join(kind.file_name())
+    }
+
+    /// Preferred path of an artifact, used when writing it
+    pub fn artifact_path(&self, kind: ArtifactKind) -> PathBuf {
+        if self.kind == LayoutKind::Flat {
+            return self.flat_path(kind);
+        }
+
+        match (kind, &self.feature) {
+            (ArtifactKind::Constitution, _) => self.root.join(&self.artifacts.constitution),
+            (ArtifactKind::Specification, Some(feature)) => feature.path.join(&self.artifacts.spec),
+            (ArtifactKind::Plan, Some(feature)) => feature.path.join(&self.artifacts.plan),
+            (ArtifactKind::Tasks, Some(feature)) => feature.path.join(&self.artifacts.tasks),
+            (_, None) => self.flat_path(kind),
+        }
+    }
+
+    /// Existing path of an artifact, falling back to the flat layout
+    pub fn find(&self, kind: ArtifactKind) -> Option<PathBuf> {
+        [self.artifact_path(kind), self.flat_path(kind)]
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+
+    /// Path to read an artifact from: the existing file, or where it would be written
+    pub fn read_path(&self, kind: ArtifactKind) -> PathBuf {
+        self.find(kind).unwrap_or_else(|| self.artifact_path(kind))
+    }
+
+    /// Path for a generated report or auxiliary file
+    ///
+    /// Lives in the active feature directory (`feature_file`) when there is
+    /// one, and in the project root (`flat_file`) otherwise.
+    pub fn output_path(&self, feature_file: &str, flat_file: &str) -> PathBuf {
+        match &self.feature {
+            Some(feature) if self.kind == LayoutKind::SpecKit => feature.file(feature_file),
+            _ => self.root.join(flat_file),
+        }
+    }
+
+    /// Path relative to the project root, for display
+    pub fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// Find the spec-kit project containing `start`: `start` itself when it has
+/// `.specify/` or `specs/`, otherwise the nearest ancestor with `.specify/`
+/// up to the enclosing git repository root
+fn find_speckit_root(start: &Path) -> Option<PathBuf> {
+    if start.join(SPECS_DIR).is_dir() {
+        return Some(start.to_path_buf());
+    }
+    for dir in start.ancestors() {
+        if dir.join(SPECIFY_DIR).is_dir() {
+            return Some(dir.to_path_buf());
+        }
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+    None
+}
+
+/// Find spec-kit projects in a workspace root: directories with `.specify/`
+/// or flat `speckit.*` files, searched up to two levels deep, skipping hidden
+/// and build directories. A project's subdirectories are not searched.
+pub fn discover_projects(root: &Path) -> Vec<PathBuf> {
+    let mut projects = Vec::new();
+    discover_in(root, DISCOVERY_DEPTH, &mut projects);
+    projects
+}
+
+fn discover_in(dir: &Path, depth: usize, projects: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut subdirs = Vec::new();
+    let mut is_project = false;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+        if (is_dir && name == SPECIFY_DIR) || (!is_dir && name.starts_with("speckit.")) {
+            is_project = true;
+        } else if is_dir && !name.starts_with('.') && !DISCOVERY_SKIP.contains(&name.as_str()) {
+            subdirs.push(entry.path());
+        }
+    }
+
+    if is_project {
+        projects.push(dir.to_path_buf());
+        return;
+    }
+    if depth == 0 {
+        return;
+    }
+    subdirs.sort();
+    for subdir in subdirs {
+        discover_in(&subdir, depth - 1, projects);
+    }
+}
+
+/// Build a feature slug from a description (`"Add user authentication"` → `user-authentication`)
+pub fn feature_slug(description: &str) -> String {
+    let lower = description.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let meaningful: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|w| !SLUG_STOP_WORDS.contains(w))
+        .collect();
+    let chosen = if meaningful.is_empty() {
+        &words
+    } else {
+        &meaningful
+    };
+
+    chosen
+        .iter()
+        .take(MAX_SLUG_WORDS)
+        .copied()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_feature_from_path() {
+        let feature = Feature::from_path("specs/002-billing").unwrap();
+        assert_eq!(feature.number, 2);
+        assert_eq!(feature.name, "002-billing");
+        assert!(Feature::from_path("specs/notes").is_none());
+        assert!(Feature::from_path("specs/01-short").is_none());
+    }
+
+    #[test]
+    fn test_flat_layout_fallback() {
+        let dir = tempdir().unwrap();
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+
+        assert_eq!(layout.kind(), LayoutKind::Flat);
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Specification),
+            layout.root().join("speckit.specify")
+        );
+        assert_eq!(
+            layout.output_path("analysis.md", "speckit.analyze"),
+            layout.root().join("speckit.analyze")
+        );
+    }
+
+    #[test]
+    fn test_speckit_layout() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".specify/memory")).unwrap();
+        fs::create_dir_all(dir.path().join("specs/001-user-auth")).unwrap();
+        fs::create_dir_all(dir.path().join("specs/002-billing/contracts")).unwrap();
+
+        // Resolution from a nested directory finds the project root
+        let layout = ProjectLayout::resolve_with_feature(
+            &dir.path().join("specs/002-billing/contracts"),
+            None,
+        );
+        let root = layout.root().to_path_buf();
+
+        assert_eq!(layout.kind(), LayoutKind::SpecKit);
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Constitution),
+            root.join(".specify/memory/constitution.md")
+        );
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Plan),
+            root.join("specs/002-billing/plan.md")
+        );
+
+        let layout = layout.select_feature("001-user-auth").unwrap();
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Specification),
+            root.join("specs/001-user-auth/spec.md")
+        );
+        assert!(layout.clone().select_feature("999-missing").is_err());
+
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), Some("001-user-auth"));
+        assert_eq!(layout.feature().unwrap().number, 1);
+    }
+
+    #[test]
+    fn test_resolution_stays_in_project() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        let project = base.join("workspace/project");
+        fs::create_dir_all(base.join("specs/001-elsewhere")).unwrap();
+        fs::create_dir_all(project.join("docs")).unwrap();
+
+        // A bare specs/ only counts in the start directory
+        let layout = ProjectLayout::resolve_with_feature(&project, None);
+        assert_eq!(layout.kind(), LayoutKind::Flat);
+        assert_eq!(layout.root(), project);
+
+        // .specify/ is not searched past the git repository root
+        fs::create_dir_all(base.join(".specify")).unwrap();
+        fs::create_dir_all(base.join("workspace/.git")).unwrap();
+        let layout = ProjectLayout::resolve_with_feature(&project.join("docs"), None);
+        assert_eq!(layout.kind(), LayoutKind::Flat);
+
+        // ...nor above the workspace roots
+        fs::remove_dir(base.join("workspace/.git")).unwrap();
+        let paths = PathPolicy::new([base.join("workspace")]);
+        let layout = ProjectLayout::resolve_with_feature_in(&project, None, &paths).unwrap();
+        assert_eq!(layout.kind(), LayoutKind::Flat);
+        assert_eq!(layout.root(), project);
+        assert!(ProjectLayout::resolve_in(&base, &paths).is_err());
+    }
+
+    #[test]
+    fn test_feature_slug() {
+        assert_eq!(
+            feature_slug("Add user authentication"),
+            "user-authentication"
+        );
+        assert_eq!(
+            feature_slug("Implement OAuth2 login for the admin dashboard!"),
+            "oauth2-login-admin"
+        );
+        assert_eq!(feature_slug("The new"), "the-new");
+        assert_eq!(feature_slug("!!!"), "");
+    }
+
+    #[tokio::test]
+    async fn test_create_and_switch_features() {
+        let dir = tempdir().unwrap();
+        let paths = PathPolicy::new([dir.path().to_path_buf()]);
+        let mut layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.kind(), LayoutKind::Flat);
+
+        let first = layout
+            .create_feature("User authentication", None, &paths)
+            .await
+            .unwrap();
+        let second = layout
+            .create_feature("Billing and invoices", Some("billing"), &paths)
+            .await
+            .unwrap();
+        assert_eq!(first.name, "001-user-authentication");
+        assert_eq!(second.name, "002-billing");
+        assert_eq!(layout.kind(), LayoutKind::SpecKit);
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+
+        let mut layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        let first = layout.find_feature("1").unwrap();
+        layout.set_active_feature(first, &paths).await.unwrap();
+
+        // The recorded feature wins over the highest-numbered one...
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "001-user-authentication");
+
+        // ...but an explicit feature (SPECIFY_FEATURE) wins over the record
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), Some("002-billing"));
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+
+        // Features cannot be created outside the workspace
+        let outside = PathPolicy::new(Vec::new());
+        let mut layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert!(layout
+            .create_feature("Search", None, &outside)
+            .await
+            .is_err());
+        assert!(!dir.path().join("specs/003-search").exists());
+    }
+
+    #[tokio::test]
+    async fn test_feature_from_git_branch() {
+        let dir = tempdir().unwrap();
+        crate::speckit::git::init_test_repo(dir.path());
+        let paths = PathPolicy::new([dir.path().to_path_buf()]);
+        let mut layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        layout.create_feature("Login", None, &paths).await.unwrap();
+        layout
+            .create_feature("Billing", None, &paths)
+            .await
+            .unwrap();
+
+        // Not a feature branch: the recorded feature applies
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+
+        let repo = GitRepo::discover(dir.path()).unwrap();
+        repo.switch_branch("001-login").await.unwrap();
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "001-login");
+    }
+
+    #[test]
+    fn test_find_prefers_speckit_then_flat() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("specs/001-search")).unwrap();
+        fs::write(dir.path().join("speckit.plan"), "legacy plan").unwrap();
+
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(
+            layout.find(ArtifactKind::Plan).unwrap(),
+            layout.root().join("speckit.plan")
+        );
+        assert!(layout.find(ArtifactKind::Tasks).is_none());
+
+        fs::write(dir.path().join("specs/001-search/plan.md"), "plan").unwrap();
+        assert_eq!(
+            layout.find(ArtifactKind::Plan).unwrap(),
+            layout.root().join("specs/001-search/plan.md")
+        );
+    }
+
+    #[test]
+    fn test_configured_artifact_paths() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("specs/001-search")).unwrap();
+        let paths =
+            PathPolicy::new([dir.path().to_path_buf()]).with_artifact_paths(ArtifactPaths {
+                constitution: PathBuf::from("docs/constitution.md"),
+                spec: PathBuf::from("requirements.md"),
+                ..ArtifactPaths::default()
+            });
+
+        let layout = ProjectLayout::resolve_in(dir.path(), &paths).unwrap();
+        let feature = layout.root().join("specs/001-search");
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Constitution),
+            layout.root().join("docs/constitution.md")
+        );
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Specification),
+            feature.join("requirements.md")
+        );
+        assert_eq!(
+            layout.artifact_path(ArtifactKind::Plan),
+            feature.join("plan.md")
+        );
+    }
+
+    #[test]
+    fn test_discover_projects() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("api/.specify/memory")).unwrap();
+        std::fs::create_dir_all(root.join("api/nested/.specify")).unwrap();
+        std::fs::create_dir_all(root.join("tools/cli")).unwrap();
+        std::fs::write(root.join("tools/cli/speckit.specify"), "# Spec").unwrap();
+        std::fs::create_dir_all(root.join("node_modules/pkg/.specify")).unwrap();
+        std::fs::create_dir_all(root.join("a/b/c/.specify")).unwrap();
+
+        assert_eq!(
+            discover_projects(root),
+            vec![root.join("api"), root.join("tools/cli")]
+        );
+        assert!(discover_projects(&root.join("missing")).is_empty());
+    }
+}
@@ -0,0 +1,1276 @@
+//! Artifact Linting
+//!
+//! `cargo clippy`-style checks on the structure of spec-kit artifacts. Every
+//! rule has an ID, a default severity and a short explanation. Rules are
+//! allowed, warned or denied in the `[lint]` configuration, and can be
+//! allowed inline with `<!-- speckit-lint: allow(rule, ...) -->`: the
+//! comment covers the section it appears in, or the whole file when it comes
+//! before the first heading.
+//!
+//! Some rules come with a safe, mechanical fix (renumbering a duplicate task,
+//! normalizing a checkbox, ...). [`fix_artifact`] applies the fixes of the
+//! rules that are not allowed and returns the fixed content; the caller
+//! decides whether to write it.
+
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::amend::{find_block, heading_matches, headings, splice, HISTORY_SECTION};
+use super::findings::Location;
+use super::markdown::MarkdownDocument;
+use super::model::{Artifact, ArtifactKind, ArtifactSet};
+use super::requirements::{split_definition, RequirementKind, RequirementNumbering};
+use crate::config::LintConfig;
+
+/// Prefix of inline lint directives in HTML comments
+pub const LINT_DIRECTIVE: &str = "speckit-lint:";
+
+/// Mandatory specification sections, each with the headings accepted in its
+/// place (spec-kit's upstream names)
+pub const SPEC_MANDATORY_SECTIONS: &[(&str, &[&str])] = &[
+    ("Requirements", &["Functional Requirements"]),
+    (
+        "User Stories",
+        &["User Scenarios & Testing", "User Scenarios"],
+    ),
+];
+
+/// Supporting file that holds the plan's data model
+pub const DATA_MODEL_FILE: &str = "data-model.md";
+
+/// Specification section that holds user stories
+pub const USER_STORIES_SECTION: &str = "User Stories";
+
+/// Unresolved clarification marker
+const CLARIFICATION_MARKER: &str = "[NEEDS CLARIFICATION";
+
+/// Rounds of line fixes; a line gets at most one fix per round
+const MAX_FIX_PASSES: usize = 4;
+
+/// A lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// The specification lacks a mandatory section
+    MissingSection,
+
+    /// A `[NEEDS CLARIFICATION]` marker is left in an artifact
+    NeedsClarification,
+
+    /// An acceptance criterion is not in Given/When/Then form
+    AcceptanceFormat,
+
+    /// A task does not name the file it changes
+    TaskFilePath,
+
+    /// The plan does not describe a data model
+    PlanDataModel,
+
+    /// Two tasks share an ID
+    DuplicateTaskId,
+
+    /// A checkbox is not written `- [ ]` or `- [x]`
+    CheckboxSyntax,
+
+    /// A user story is not in "As a ..., I want ..., so that ..." form
+    UserStoryFormat,
+
+    /// A requirement has no `FR-`/`NFR-` ID
+    RequirementId,
+}
+
+impl LintRule {
+    /// All rules
+    pub const ALL: [LintRule; 9] = [
+        LintRule::MissingSection,
+        LintRule::NeedsClarification,
+        LintRule::AcceptanceFormat,
+        LintRule::TaskFilePath,
+        LintRule::PlanDataModel,
+        LintRule::DuplicateTaskId,
+        LintRule::CheckboxSyntax,
+        LintRule::UserStoryFormat,
+        LintRule::RequirementId,
+    ];
+
+    /// Rule ID, as used in configuration and inline directives
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::MissingSection => "missing_section",
+            Self::NeedsClarification => "needs_clarification",
+            Self::AcceptanceFormat => "acceptance_format",
+            Self::TaskFilePath => "task_file_path",
+            Self::PlanDataModel => "plan_data_model",
+            Self::DuplicateTaskId => "duplicate_task_id",
+            Self::CheckboxSyntax => "checkbox_syntax",
+            Self::UserStoryFormat => "user_story_format",
+            Self::RequirementId => "requirement_id",
+        }
+    }
+
+    /// Look up a rule by ID
+    pub fn parse(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.id() == id.trim())
+    }
+
+    /// Severity when the configuration does not set one
+    pub fn default_severity(&self) -> LintSeverity {
+        match self {
+            Self::MissingSection | Self::NeedsClarification | Self::DuplicateTaskId => {
+                LintSeverity::Error
+            }
+            Self::AcceptanceFormat
+            | Self::TaskFilePath
+            | Self::PlanDataModel
+            | Self::CheckboxSyntax
+            | Self::UserStoryFormat
+            | Self::RequirementId => LintSeverity::Warning,
+        }
+    }
+
+    /// Whether the rule's diagnostics can be fixed mechanically
+    pub fn is_fixable(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingSection
+                | Self::DuplicateTaskId
+                | Self::CheckboxSyntax
+                | Self::UserStoryFormat
+                | Self::RequirementId
+        )
+    }
+
+    /// Why the rule exists
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            Self::MissingSection => {
+                "Specifications need the mandatory Requirements and User Stories sections; later phases plan against them"
+            }
+            Self::NeedsClarification => {
+                "[NEEDS CLARIFICATION] markers are open questions; resolve them (speckit_clarify) before planning"
+            }
+            Self::AcceptanceFormat => {
+                "Acceptance criteria should read \"Given <context>, When <action>, Then <outcome>\" so they map directly to tests"
+            }
+            Self::TaskFilePath => {
+                "Tasks should name the file they change (e.g. src/models/user.rs) so they can be executed without guessing"
+            }
+            Self::PlanDataModel => {
+                "Plans should describe the data model (a Data Model section or data-model.md) before tasks are generated"
+            }
+            Self::DuplicateTaskId => {
+                "Task IDs must be unique so dependencies and progress tracking refer to one task"
+            }
+            Self::CheckboxSyntax => {
+                "Checkboxes should read `- [ ]` or `- [x]` so progress is counted correctly"
+            }
+            Self::UserStoryFormat => {
+                "User stories should read \"As a <role>, I want <goal>, so that <benefit>\""
+            }
+            Self::RequirementId => {
+                "Requirements need an ID (FR-001, NFR-001) so plans and tasks can trace them"
+            }
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// Diagnostic severity, ordered from most to least severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// Must be fixed
+    Error,
+
+    /// Should be fixed
+    Warning,
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A single lint diagnostic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintDiagnostic {
+    /// Rule that produced the diagnostic
+    pub rule: LintRule,
+
+    /// Effective severity
+    pub severity: LintSeverity,
+
+    /// Where the diagnostic applies
+    pub location: Location,
+
+    /// What is wrong
+    pub message: String,
+
+    /// Whether [`fix_artifact`] can fix it
+    pub fixable: bool,
+}
+
+/// A fix applied (or, for a dry run, to be applied) to an artifact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintFix {
+    /// Rule whose diagnostic was fixed
+    pub rule: LintRule,
+
+    /// Where the fix applies
+    pub location: Location,
+
+    /// What changed
+    pub description: String,
+}
+
+/// An artifact's content after its safe fixes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedArtifact {
+    /// Fixed content
+    pub content: String,
+
+    /// Fixes applied, by line
+    pub fixes: Vec<LintFix>,
+}
+
+/// The result of linting a project's artifacts
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LintReport {
+    /// Diagnostics, errors first, then by file and line
+    pub diagnostics: Vec<LintDiagnostic>,
+
+    /// Number of artifacts linted
+    pub artifacts: usize,
+}
+
+impl LintReport {
+    /// Number of diagnostics with a given severity
+    pub fn count(&self, severity: LintSeverity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+
+    /// Whether any diagnostic is an error
+    pub fn has_errors(&self) -> bool {
+        self.count(LintSeverity::Error) > 0
+    }
+
+    /// Number of diagnostics that can be fixed mechanically
+    pub fn fixable(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.fixable).count()
+    }
+
+    /// Rules that produced diagnostics, in rule order
+    pub fn rules(&self) -> Vec<LintRule> {
+        LintRule::ALL
+            .into_iter()
+            .filter(|rule| self.diagnostics.iter().any(|d| d.rule == *rule))
+            .collect()
+    }
+
+    /// Render the report as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut report = String::from("# Artifact Lint Report\n\n");
+
+        if self.diagnostics.is_empty() {
+            report.push_str("✓ No issues found.\n");
+            return report;
+        }
+
+        report.push_str("| Severity | Rule | Location | Message |\n");
+        report.push_str("|----------|------|----------|---------|\n");
+        for diagnostic in &self.diagnostics {
+            report.push_str(&format!(
+                "| {} | `{}` | {} | {} |\n",
+                diagnostic.severity,
+                diagnostic.rule,
+                diagnostic.location,
+                diagnostic.message.replace('|', "\\|")
+            ));
+        }
+
+        report.push_str("\n## Rules\n\n");
+        for rule in self.rules() {
+            report.push_str(&format!("- `{}`: {}\n", rule, rule.explanation()));
+        }
+        report.push_str(&format!(
+            "\nAllow a rule for one section with `<!-- {} allow(rule) -->`, or project-wide with `[lint] allow = [\"rule\"]`.\n",
+            LINT_DIRECTIVE
+        ));
+        if self.fixable() > 0 {
+            report.push_str(&format!(
+                "{} of these can be fixed automatically with `fix: true` (add `dry_run: true` to preview).\n",
+                self.fixable()
+            ));
+        }
+
+        report
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Lint every artifact in a set
+pub fn lint_artifacts(artifacts: &ArtifactSet, config: &LintConfig) -> LintReport {
+    let mut diagnostics: Vec<LintDiagnostic> = artifacts
+        .present()
+        .into_iter()
+        .flat_map(|artifact| {
+            check_artifact(artifact, config)
+                .into_iter()
+                .map(|(severity, found)| LintDiagnostic {
+                    rule: found.rule,
+                    severity,
+                    location: match found.line {
+                        Some(line) => artifact.location(line),
+                        None => Location::file(&artifact.label),
+                    },
+                    message: found.message,
+                    fixable: found.rule.is_fixable() && found.fix.is_some(),
+                })
+        })
+        .collect();
+
+    diagnostics.sort_by(|a, b| {
+        (a.severity, &a.location.file, a.location.line).cmp(&(
+            b.severity,
+            &b.location.file,
+            b.location.line,
+        ))
+    });
+
+    LintReport {
+        diagnostics,
+        artifacts: artifacts.present().len(),
+    }
+}
+
+/// Apply the safe fixes for an artifact's enabled, non-allowed rules
+///
+/// Line fixes are applied first, one per line per round, re-checking the
+/// artifact between rounds; missing sections are added once no line fix is
+/// left, before the amendment history if there is one.
+pub fn fix_artifact(artifact: &Artifact, config: &LintConfig) -> FixedArtifact {
+    let mut current = artifact.clone();
+    let mut fixes = Vec::new();
+
+    for _ in 0..MAX_FIX_PASSES {
+        let mut lines = BTreeMap::new();
+        let mut sections = Vec::new();
+
+        for (_, found) in check_artifact(&current, config) {
+            let Some(fix) = found.fix.filter(|_| found.rule.is_fixable()) else {
+                continue;
+            };
+            match (fix.edit, found.line) {
+                (Edit::ReplaceLine(text), Some(line)) => {
+                    if let Entry::Vacant(entry) = lines.entry(line) {
+                        entry.insert(text);
+                        fixes.push(LintFix {
+                            rule: found.rule,
+                            location: current.location(line),
+                            description: fix.description,
+                        });
+                    }
+                }
+                (Edit::AddSection(block), _) => sections.push((found.rule, block, fix.description)),
+                (Edit::ReplaceLine(_), None) => {}
+            }
+        }
+
+        if !lines.is_empty() {
+            current.content = replace_lines(&current.content, &lines);
+            continue;
+        }
+        if !sections.is_empty() {
+            let blocks: Vec<&str> = sections
+                .iter()
+                .map(|(_, block, _)| block.as_str())
+                .collect();
+            current.content = add_sections(&current.content, &blocks);
+            for (rule, _, description) in sections {
+                fixes.push(LintFix {
+                    rule,
+                    location: Location::file(&current.label),
+                    description,
+                });
+            }
+        }
+        break;
+    }
+
+    fixes.sort_by_key(|fix| fix.location.line.unwrap_or(usize::MAX));
+    FixedArtifact {
+        content: current.content,
+        fixes,
+    }
+}
+
+/// A raw diagnostic, before configuration and inline allows are applied
+struct Found {
+    rule: LintRule,
+
+    /// 1-based line, or `None` for the whole file
+    line: Option<usize>,
+
+    message: String,
+
+    fix: Option<Fix>,
+}
+
+impl Found {
+    fn new(rule: LintRule, line: Option<usize>, message: String) -> Self {
+        Self {
+            rule,
+            line,
+            message,
+            fix: None,
+        }
+    }
+
+    fn with_fix(mut self, edit: Edit, description: String) -> Self {
+        self.fix = Some(Fix { edit, description });
+        self
+    }
+}
+
+/// A safe, mechanical fix for a diagnostic
+struct Fix {
+    edit: Edit,
+    description: String,
+}
+
+enum Edit {
+    /// Replace the diagnostic's line (without its line ending)
+    ReplaceLine(String),
+
+    /// Add a `##` section block
+    AddSection(String),
+}
+
+/// Diagnostics of one artifact that are enabled and not allowed inline,
+/// with their effective severity
+fn check_artifact(artifact: &Artifact, config: &LintConfig) -> Vec<(LintSeverity, Found)> {
+    let document = artifact.document();
+    let allowed = inline_allows(&document);
+
+    let mut found = check_clarifications(&document);
+    found.extend(check_checkboxes(&document));
+    match artifact.kind {
+        ArtifactKind::Specification => {
+            found.extend(check_sections(&document));
+            found.extend(check_acceptance(&document));
+            found.extend(check_user_stories(&document));
+            found.extend(check_requirement_ids(&document));
+        }
+        ArtifactKind::Plan => found.extend(check_data_model(artifact, &document)),
+        ArtifactKind::Tasks => {
+            found.extend(check_task_paths(artifact));
+            found.extend(check_duplicate_tasks(artifact, &document));
+        }
+        ArtifactKind::Constitution => {}
+    }
+
+    found
+        .into_iter()
+        .filter_map(|found| {
+            let severity = config.severity(found.rule)?;
+            (!is_allowed(&allowed, found.rule, found.line)).then_some((severity, found))
+        })
+        .collect()
+}
+
+/// Replace 1-based lines, keeping their line endings
+fn replace_lines(content: &str, lines: &BTreeMap<usize, String>) -> String {
+    content
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(i, line)| match lines.get(&(i + 1)) {
+            Some(text) => {
+                let ending = &line[line.trim_end_matches(['\r', '\n']).len()..];
+                format!("{}{}", text, ending)
+            }
+            None => line.to_string(),
+        })
+        .collect()
+}
+
+/// Add section blocks before the amendment history, or at the end
+fn add_sections(content: &str, blocks: &[&str]) -> String {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let at = find_block(
+        &headings(&lines),
+        &lines,
+        2,
+        HISTORY_SECTION,
+        0..lines.len(),
+    )
+    .map_or(lines.len(), |block| block.start);
+    splice(&lines, at..at, &blocks.join("\n\n"))
+}
+
+/// Rules allowed by inline directives, with the lines they cover (`None`
+/// for the whole file)
+fn inline_allows(document: &MarkdownDocument) -> Vec<(LintRule, Option<RangeInclusive<usize>>)> {
+    let mut allowed = Vec::new();
+
+    for block in document.html_blocks() {
+        let Some((_, directive)) = block.text.split_once(LINT_DIRECTIVE) else {
+            continue;
+        };
+        let Some(list) = directive
+            .trim_start()
+            .strip_prefix("allow(")
+            .and_then(|rest| rest.split_once(')'))
+            .map(|(list, _)| list)
+        else {
+            tracing::warn!(line = block.line, "Ignoring malformed lint directive");
+            continue;
+        };
+
+        let scope = document.section_at(block.line).map(|s| s.lines());
+        for id in list.split(',') {
+            match LintRule::parse(id) {
+                Some(rule) => allowed.push((rule, scope.clone())),
+                None => tracing::warn!(rule = id.trim(), "Ignoring unknown lint rule"),
+            }
+        }
+    }
+
+    allowed
+}
+
+fn is_allowed(
+    allowed: &[(LintRule, Option<RangeInclusive<usize>>)],
+    rule: LintRule,
+    line: Option<usize>,
+) -> bool {
+    allowed.iter().any(|(allowed, scope)| {
+        *allowed == rule
+            && match (scope, line) {
+                (None, _) => true,
+                (Some(scope), Some(line)) => scope.contains(&line),
+                (Some(_), None) => false,
+            }
+    })
+}
+
+/// Mandatory specification sections that are missing
+fn check_sections(document: &MarkdownDocument) -> Vec<Found> {
+    SPEC_MANDATORY_SECTIONS
+        .iter()
+        .filter(|(name, aliases)| {
+            !document.sections().iter().any(|section| {
+                section.level >= 2
+                    && std::iter::once(name)
+                        .chain(aliases.iter())
+                        .any(|candidate| heading_matches(&section.heading, candidate))
+            })
+        })
+        .map(|(name, _)| {
+            Found::new(
+                LintRule::MissingSection,
+                None,
+                format!("Missing mandatory section `## {}`", name),
+            )
+            .with_fix(
+                Edit::AddSection(format!(
+                    "## {}\n\n<!-- Write the {} here -->",
+                    name,
+                    name.to_lowercase()
+                )),
+                format!("Added `## {}` section", name),
+            )
+        })
+        .collect()
+}
+
+/// `[NEEDS CLARIFICATION]` markers outside code and comments
+fn check_clarifications(document: &MarkdownDocument) -> Vec<Found> {
+    document
+        .prose_lines()
+        .into_iter()
+        .filter_map(|(line, text)| {
+            let start = text.find(CLARIFICATION_MARKER)?;
+            let end = text[start..]
+                .find(']')
+                .map_or(text.len(), |e| start + e + 1);
+            Some(Found::new(
+                LintRule::NeedsClarification,
+                Some(line),
+                format!("Unresolved clarification: {}", &text[start..end]),
+            ))
+        })
+        .collect()
+}
+
+/// Acceptance criteria that are not in Given/When/Then form
+///
+/// Acceptance criteria are the top-level list items under a heading or a
+/// label line (e.g. `**Acceptance Scenarios**:`) that mentions acceptance.
+fn check_acceptance(document: &MarkdownDocument) -> Vec<Found> {
+    document
+        .list_items()
+        .iter()
+        .filter(|item| item.depth == 0 && is_acceptance_item(document, item.line))
+        .filter(|item| !is_given_when_then(&item.text))
+        .map(|item| {
+            Found::new(
+                LintRule::AcceptanceFormat,
+                Some(item.line),
+                format!(
+                    "Acceptance criterion is not in Given/When/Then form: \"{}\"",
+                    item.text
+                ),
+            )
+        })
+        .collect()
+}
+
+fn is_acceptance_item(document: &MarkdownDocument, line: usize) -> bool {
+    let mentions = |text: &str| text.to_lowercase().contains("acceptance");
+    let in_item = |line: usize| {
+        document
+            .list_items()
+            .iter()
+            .any(|item| (item.line..=item.end_line).contains(&line))
+    };
+    let heading = document.section_at(line);
+
+    // The label closest above the list, stopping at the section heading
+    let floor = heading.map_or(0, |s| s.line);
+    let label = (floor + 1..line)
+        .rev()
+        .map(|l| (l, document.line(l).trim()))
+        .find(|(l, text)| !text.is_empty() && !in_item(*l));
+
+    match label {
+        Some((_, text)) => mentions(text),
+        None => heading.is_some_and(|s| mentions(&s.heading)),
+    }
+}
+
+fn is_given_when_then(text: &str) -> bool {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let position = |word: &str| words.iter().position(|w| w == word);
+
+    matches!(
+        (position("given"), position("when"), position("then")),
+        (Some(given), Some(when), Some(then)) if given < when && when < then
+    )
+}
+
+/// Tasks that name no file path
+fn check_task_paths(tasks: &Artifact) -> Vec<Found> {
+    tasks
+        .tasks()
+        .into_iter()
+        .filter(|task| task.id.starts_with('T') && !mentions_path(&task.text))
+        .map(|task| {
+            Found::new(
+                LintRule::TaskFilePath,
+                Some(task.line),
+                format!("Task {} does not name a file path", task.id),
+            )
+        })
+        .collect()
+}
+
+/// Whether text contains a path (`src/lib.rs`, `docs/`) or a file name
+/// with an extension (`README.md`)
+fn mentions_path(text: &str) -> bool {
+    text.split_whitespace()
+        .map(|token| token.trim_matches(|c: char| "`'\"()[],;:".contains(c)))
+        .map(|token| token.trim_end_matches('.'))
+        .any(|token| {
+            if token.contains('/') && token.chars().any(char::is_alphanumeric) {
+                return true;
+            }
+            token.rsplit_once('.').is_some_and(|(stem, extension)| {
+                !stem.is_empty()
+                    && extension.len() >= 2
+                    && extension.len() <= 5
+                    && extension.chars().all(|c| c.is_ascii_alphanumeric())
+                    && extension.chars().any(|c| c.is_ascii_alphabetic())
+            })
+        })
+}
+
+/// A plan without a data model section, `data-model.md` reference or file
+fn check_data_model(plan: &Artifact, document: &MarkdownDocument) -> Vec<Found> {
+    let has_section = document
+        .sections()
+        .iter()
+        .any(|s| s.heading.to_lowercase().contains("data model"));
+    let references_file = document
+        .prose_lines()
+        .iter()
+        .any(|(_, text)| text.contains(DATA_MODEL_FILE));
+    if has_section || references_file || plan.path.with_file_name(DATA_MODEL_FILE).is_file() {
+        return Vec::new();
+    }
+
+    vec![Found::new(
+        LintRule::PlanDataModel,
+        None,
+        format!(
+            "Plan has no `## Data Model` section and no {}",
+            DATA_MODEL_FILE
+        ),
+    )]
+}
+
+/// Task IDs used more than once; later uses are renumbered past the highest ID
+fn check_duplicate_tasks(tasks: &Artifact, document: &MarkdownDocument) -> Vec<Found> {
+    let tasks: Vec<_> = tasks
+        .tasks()
+        .into_iter()
+        .filter(|task| task.id.starts_with('T'))
+        .collect();
+    let mut next = tasks
+        .iter()
+        .filter_map(|task| task.id[1..].parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+
+    let mut first_use: HashMap<&str, usize> = HashMap::new();
+    let mut found = Vec::new();
+    for task in &tasks {
+        let Some(first) = first_use.get(task.id.as_str()) else {
+            first_use.insert(&task.id, task.line);
+            continue;
+        };
+
+        next += 1;
+        let id = format!("T{:0width$}", next, width = (task.id.len() - 1).max(3));
+        found.push(
+            Found::new(
+                LintRule::DuplicateTaskId,
+                Some(task.line),
+                format!("Task ID {} is already used on line {}", task.id, first),
+            )
+            .with_fix(
+                Edit::ReplaceLine(document.line(task.line).replacen(&task.id, &id, 1)),
+                format!("Renumbered duplicate task {} to {}", task.id, id),
+            ),
+        );
+    }
+
+    found
+}
+
+/// Checkboxes written other than `- [ ]` or `- [x]`
+fn check_checkboxes(document: &MarkdownDocument) -> Vec<Found> {
+    document
+        .list_items()
+        .iter()
+        .filter_map(|item| {
+            let line = document.line(item.line);
+            let fixed = normalize_checkbox(line).filter(|fixed| fixed != line)?;
+            Some(
+                Found::new(
+                    LintRule::CheckboxSyntax,
+                    Some(item.line),
+                    format!("Malformed checkbox: \"{}\"", line.trim()),
+                )
+                .with_fix(
+                    Edit::ReplaceLine(fixed.clone()),
+                    format!("Rewrote checkbox as \"{}\"", fixed.trim()),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// A list line with a checkbox-like prefix (`* [X]`, `- []`, `+ [✓]`, ...)
+/// rewritten with a `-` bullet and a `[ ]` / `[x]` checkbox
+fn normalize_checkbox(line: &str) -> Option<String> {
+    let body = line.trim_start();
+    let indent = &line[..line.len() - body.len()];
+
+    let (marker, rest) = if body.starts_with(['-', '*', '+']) {
+        ("-", &body[1..])
+    } else {
+        let digits = body.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || !body[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        body.split_at(digits + 1)
+    };
+
+    let (inner, text) = rest.trim_start().strip_prefix('[')?.split_once(']')?;
+    let mark = match inner.trim() {
+        "" => ' ',
+        "x" | "X" | "✓" | "✔" => 'x',
+        _ => return None,
+    };
+
+    Some(
+        format!("{}{} [{}] {}", indent, marker, mark, text.trim_start())
+            .trim_end()
+            .to_string(),
+    )
+}
+
+/// User stories that are not in "As a ..., I want ..., so that ..." form
+///
+/// Stories are the top-level list items and paragraphs of the User Stories
+/// section.
+fn check_user_stories(document: &MarkdownDocument) -> Vec<Found> {
+    let in_item = |line: usize| {
+        document
+            .list_items()
+            .iter()
+            .any(|item| (item.line..=item.end_line).contains(&line))
+    };
+
+    document
+        .prose_lines()
+        .into_iter()
+        .filter(|(line, _)| {
+            document.section_at(*line).is_some_and(|section| {
+                section.line != *line && heading_matches(&section.heading, USER_STORIES_SECTION)
+            })
+        })
+        .filter_map(|(line, text)| {
+            let story = match document.item_at(line) {
+                Some(item) if item.depth == 0 && item.checked.is_none() => item.text.as_str(),
+                Some(_) => return None,
+                None if in_item(line) => return None,
+                None => {
+                    let previous = document.line(line - 1).trim();
+                    if !(previous.is_empty() || previous.starts_with('#')) {
+                        return None;
+                    }
+                    text.trim()
+                }
+            };
+            if story.is_empty()
+                || story.starts_with(['[', '|', '*'])
+                || story.ends_with(':')
+                || is_story_format(story)
+            {
+                return None;
+            }
+
+            let found = Found::new(
+                LintRule::UserStoryFormat,
+                Some(line),
+                format!(
+                    "User story is not in \"As a ..., I want ..., so that ...\" form: \"{}\"",
+                    story
+                ),
+            );
+            let raw = document.line(line);
+            match (rewrite_story(story), raw.find(story)) {
+                (Some(rewritten), Some(start)) => Some(found.with_fix(
+                    Edit::ReplaceLine(format!("{}{}", &raw[..start], rewritten)),
+                    format!("Rewrote user story as \"{}\"", rewritten),
+                )),
+                _ => Some(found),
+            }
+        })
+        .collect()
+}
+
+fn is_story_format(story: &str) -> bool {
+    let story = story.to_ascii_lowercase();
+    (story.starts_with("as a ") || story.starts_with("as an "))
+        && story.contains(", i want ")
+        && story.contains(", so that ")
+}
+
+/// Rewrite a story that names a role, a goal and a benefit in the template
+/// form, e.g. "As an admin I need to export reports in order to audit them"
+/// becomes "As an admin, I want to export reports, so that I can audit them"
+fn rewrite_story(story: &str) -> Option<String> {
+    let body = story.trim().trim_end_matches('.');
+    let period = if story.trim_end().ends_with('.') {
+        "."
+    } else {
+        ""
+    };
+    let lower = body.to_ascii_lowercase();
+
+    let first = |from: usize, patterns: &[(&'static str, &'static str)]| {
+        patterns
+            .iter()
+            .filter_map(|(pattern, lead)| {
+                lower[from..]
+                    .find(pattern)
+                    .map(|at| (from + at, from + at + pattern.len(), *lead))
+            })
+            .min_by_key(|(at, _, _)| *at)
+    };
+
+    let article = ["as an ", "as a "]
+        .into_iter()
+        .find(|prefix| lower.starts_with(prefix))?;
+    let (goal_at, goal_start, _) = first(
+        article.len(),
+        &[
+            (" i want ", ""),
+            (" i need ", ""),
+            (" i would like ", ""),
+            (" i'd like ", ""),
+        ],
+    )?;
+    let (benefit_at, benefit_start, lead) = first(
+        goal_start,
+        &[
+            (" so that ", ""),
+            (" so i can ", "I can "),
+            (" in order to ", "I can "),
+        ],
+    )?;
+
+    let part = |range: std::ops::Range<usize>| body[range].trim().trim_end_matches(',').trim();
+    let (role, goal, benefit) = (
+        part(article.len()..goal_at),
+        part(goal_start..benefit_at),
+        part(benefit_start..body.len()),
+    );
+    if role.is_empty() || goal.is_empty() || benefit.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "As {} {}, I want {}, so that {}{}{}",
+        article[3..].trim(),
+        role,
+        goal,
+        lead,
+        benefit,
+        period
+    ))
+}
+
+/// Requirement list items without an ID, which get the next free one
+fn check_requirement_ids(document: &MarkdownDocument) -> Vec<Found> {
+    let mut numbering = RequirementNumbering::from_existing(document.source());
+
+    document
+        .list_items()
+        .iter()
+        .filter(|item| item.depth == 0 && item.checked.is_none() && !item.text.is_empty())
+        .filter_map(|item| {
+            let kind = requirement_kind(&document.section_at(item.line)?.heading)?;
+            let line = document.line(item.line);
+            if split_definition(line).is_some() {
+                return None;
+            }
+
+            let start = line.find(item.text.as_str())?;
+            let id = numbering.assign(kind, &item.text);
+            Some(
+                Found::new(
+                    LintRule::RequirementId,
+                    Some(item.line),
+                    format!("Requirement has no ID: \"{}\"", item.text),
+                )
+                .with_fix(
+                    Edit::ReplaceLine(format!("{}**{}**: {}", &line[..start], id, &line[start..])),
+                    format!("Assigned {}", id),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Kind of requirements listed under a heading, if it is a requirements one
+fn requirement_kind(heading: &str) -> Option<RequirementKind> {
+    let matches = |names: &[&str]| names.iter().any(|name| heading_matches(heading, name));
+    if matches(&["Non-Functional Requirements", "Nonfunctional Requirements"]) {
+        Some(RequirementKind::NonFunctional)
+    } else if matches(&["Requirements", "Functional Requirements"]) {
+        Some(RequirementKind::Functional)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "# Feature Specification: Export\n\n\
+        ## User Scenarios & Testing *(mandatory)*\n\n\
+        **Acceptance Scenarios**:\n\n\
+        1. **Given** a report, **When** the user exports it, **Then** a CSV is downloaded\n\
+        2. The export is fast\n\n\
+        ## Edge Cases\n\n\
+        - Empty reports [NEEDS CLARIFICATION: show an error?]\n\n\
+        ```text\n[NEEDS CLARIFICATION: example only]\n```\n";
+
+    fn set(kind: ArtifactKind, name: &str, content: &str) -> ArtifactSet {
+        let mut set = ArtifactSet::default();
+        set.insert(Artifact::new(
+            kind,
+            format!("/nonexistent/{}", name),
+            content,
+        ));
+        set
+    }
+
+    fn rules(report: &LintReport) -> Vec<(LintRule, Option<usize>)> {
+        report
+            .diagnostics
+            .iter()
+            .map(|d| (d.rule, d.location.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_spec_rules() {
+        let report = lint_artifacts(
+            &set(ArtifactKind::Specification, "spec.md", SPEC),
+            &LintConfig::default(),
+        );
+
+        assert_eq!(
+            rules(&report),
+            [
+                (LintRule::MissingSection, None),
+                (LintRule::NeedsClarification, Some(12)),
+                (LintRule::AcceptanceFormat, Some(8)),
+            ]
+        );
+        assert_eq!(
+            report.diagnostics[0].message,
+            "Missing mandatory section `## Requirements`"
+        );
+        assert!(report.has_errors());
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| error | `missing_section` | spec.md | "));
+        assert!(markdown.contains("| warning | `acceptance_format` | spec.md:8 | "));
+        assert!(markdown.contains(LintRule::AcceptanceFormat.explanation()));
+    }
+
+    #[test]
+    fn test_config_levels_and_inline_allows() {
+        let spec = format!(
+            "<!-- speckit-lint: allow(missing_section) -->\n{}\n\
+             ## Notes\n\n<!-- speckit-lint: allow(needs_clarification, bogus) -->\n\
+             - Retention [NEEDS CLARIFICATION: how long?]\n",
+            SPEC
+        );
+        let config = LintConfig {
+            allow: vec![LintRule::PlanDataModel],
+            deny: vec![LintRule::AcceptanceFormat],
+            ..LintConfig::default()
+        };
+
+        let report = lint_artifacts(&set(ArtifactKind::Specification, "spec.md", &spec), &config);
+        assert_eq!(
+            rules(&report),
+            [
+                (LintRule::AcceptanceFormat, Some(9)),
+                (LintRule::NeedsClarification, Some(13)),
+            ]
+        );
+        assert_eq!(report.diagnostics[0].severity, LintSeverity::Error);
+    }
+
+    #[test]
+    fn test_task_and_plan_rules() {
+        let mut artifacts = set(
+            ArtifactKind::Tasks,
+            "tasks.md",
+            "# Tasks\n\n\
+             - [ ] T001 Create model in src/models/user.rs\n\
+             - [ ] T002 Update README.md\n\
+             - [ ] T003 Wire everything up, e.g. the endpoints\n",
+        );
+        artifacts.insert(Artifact::new(
+            ArtifactKind::Plan,
+            "/nonexistent/plan.md",
+            "# Plan\n\n## Summary\n\nExport.\n",
+        ));
+
+        let report = lint_artifacts(&artifacts, &LintConfig::default());
+        assert_eq!(
+            rules(&report),
+            [
+                (LintRule::PlanDataModel, None),
+                (LintRule::TaskFilePath, Some(5)),
+            ]
+        );
+
+        artifacts.insert(Artifact::new(
+            ArtifactKind::Plan,
+            "/nonexistent/plan.md",
+            "# Plan\n\n## Data Model\n\nUser(id, name)\n",
+        ));
+        let report = lint_artifacts(&artifacts, &LintConfig::default());
+        assert_eq!(rules(&report), [(LintRule::TaskFilePath, Some(5))]);
+    }
+
+    fn fixes(fixed: &FixedArtifact) -> Vec<(LintRule, Option<usize>, &str)> {
+        fixed
+            .fixes
+            .iter()
+            .map(|f| (f.rule, f.location.line, f.description.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fix_specification() {
+        let spec = Artifact::new(
+            ArtifactKind::Specification,
+            "/nonexistent/spec.md",
+            "# Spec\n\n\
+             ## Functional Requirements\n\n\
+             - **FR-001**: Export reports\n\
+             - Import reports\n\n\
+             ## Non-Functional Requirements\n\n\
+             - Exports finish within 2s\n\n\
+             ## User Stories\n\n\
+             - As a manager I need to export reports in order to share them.\n\
+             - As a user, I want exports, so that I can archive them\n\
+             - Exports should be fast\n",
+        );
+        let mut artifacts = ArtifactSet::default();
+        artifacts.insert(spec.clone());
+        let report = lint_artifacts(&artifacts, &LintConfig::default());
+        assert_eq!(
+            rules(&report),
+            [
+                (LintRule::RequirementId, Some(6)),
+                (LintRule::RequirementId, Some(10)),
+                (LintRule::UserStoryFormat, Some(14)),
+                (LintRule::UserStoryFormat, Some(16)),
+            ]
+        );
+        assert_eq!(report.fixable(), 3);
+        assert!(report.to_markdown().contains("3 of these can be fixed"));
+
+        let fixed = fix_artifact(&spec, &LintConfig::default());
+        assert_eq!(
+            fixes(&fixed),
+            [
+                (LintRule::RequirementId, Some(6), "Assigned FR-002"),
+                (LintRule::RequirementId, Some(10), "Assigned NFR-001"),
+                (
+                    LintRule::UserStoryFormat,
+                    Some(14),
+                    "Rewrote user story as \"As a manager, I want to export reports, so that I can share them.\""
+                ),
+            ]
+        );
+        assert!(fixed.content.contains("\n- **FR-002**: Import reports\n"));
+        assert!(fixed
+            .content
+            .contains("\n- **NFR-001**: Exports finish within 2s\n"));
+        assert!(fixed
+            .content
+            .contains("\n- As a manager, I want to export reports, so that I can share them.\n"));
+        assert!(fixed.content.ends_with("- Exports should be fast\n"));
+    }
+
+    #[test]
+    fn test_fix_missing_section_before_history() {
+        let spec = Artifact::new(
+            ArtifactKind::Specification,
+            "/nonexistent/spec.md",
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export\n\n\
+             ## Amendment History\n\n- 1.0.1: created\n",
+        );
+
+        let fixed = fix_artifact(&spec, &LintConfig::default());
+        assert_eq!(
+            fixes(&fixed),
+            [(
+                LintRule::MissingSection,
+                None,
+                "Added `## User Stories` section"
+            )]
+        );
+        assert_eq!(
+            fixed.content,
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export\n\n\
+             ## User Stories\n\n<!-- Write the user stories here -->\n\n\
+             ## Amendment History\n\n- 1.0.1: created\n"
+        );
+
+        // The added stub raises no new diagnostics
+        let spec = Artifact::new(spec.kind, spec.path, fixed.content);
+        assert!(check_artifact(&spec, &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_fix_tasks() {
+        let tasks = Artifact::new(
+            ArtifactKind::Tasks,
+            "/nonexistent/tasks.md",
+            "# Tasks\r\n\r\n\
+             * [X] T001 Create src/model.rs\r\n\
+             - [ ] T003 Add src/api.rs\r\n\
+             - [] T003 Add src/cli.rs\r\n  + [ ] T002 Wire src/main.rs\r\n",
+        );
+
+        let fixed = fix_artifact(&tasks, &LintConfig::default());
+        assert_eq!(
+            fixes(&fixed),
+            [
+                (
+                    LintRule::CheckboxSyntax,
+                    Some(3),
+                    "Rewrote checkbox as \"- [x] T001 Create src/model.rs\""
+                ),
+                (
+                    LintRule::CheckboxSyntax,
+                    Some(5),
+                    "Rewrote checkbox as \"- [ ] T003 Add src/cli.rs\""
+                ),
+                (
+                    LintRule::DuplicateTaskId,
+                    Some(5),
+                    "Renumbered duplicate task T003 to T004"
+                ),
+                (
+                    LintRule::CheckboxSyntax,
+                    Some(6),
+                    "Rewrote checkbox as \"- [ ] T002 Wire src/main.rs\""
+                ),
+            ]
+        );
+        assert_eq!(
+            fixed.content,
+            "# Tasks\r\n\r\n\
+             - [x] T001 Create src/model.rs\r\n\
+             - [ ] T003 Add src/api.rs\r\n\
+             - [ ] T004 Add src/cli.rs\r\n  - [ ] T002 Wire src/main.rs\r\n"
+        );
+
+        // Allowed rules are not fixed
+        let config = LintConfig {
+            allow: vec![LintRule::CheckboxSyntax],
+            ..LintConfig::default()
+        };
+        let fixed = fix_artifact(&tasks, &config);
+        assert!(fixed.fixes.is_empty());
+        assert_eq!(fixed.content, tasks.content);
+    }
+}
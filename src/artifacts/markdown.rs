@@ -0,0 +1,564 @@
+//! Markdown Document Tree
+//!
+//! Parses artifact markdown with pulldown-cmark into the structure the
+//! analyses work on: front matter, sections by heading, list items with
+//! their checkbox state, tables and code blocks, each with 1-based source
+//! line numbers. Everything that reads artifacts goes through this tree so
+//! headings, checkboxes and requirement-like text inside code blocks, HTML
+//! comments or front matter are never mistaken for document content.
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use std::ops::{Range, RangeInclusive};
+
+/// A markdown section heading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Heading text (without `#` markers)
+    pub heading: String,
+
+    /// Heading level (1 for `#`, 2 for `##`, ...)
+    pub level: usize,
+
+    /// 1-based line number of the heading
+    pub line: usize,
+
+    /// 1-based last line of the section, before the next heading of the
+    /// same or a higher level
+    pub end_line: usize,
+}
+
+impl Section {
+    /// Lines of the section, heading included
+    pub fn lines(&self) -> RangeInclusive<usize> {
+        self.line..=self.end_line
+    }
+}
+
+/// A list item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListItem {
+    /// 1-based line number of the item's marker
+    pub line: usize,
+
+    /// 1-based last line of the item, nested lists included
+    pub end_line: usize,
+
+    /// Nesting depth (0 for top-level items)
+    pub depth: usize,
+
+    /// Whether the item belongs to a numbered list
+    pub ordered: bool,
+
+    /// Checkbox state of `- [ ]` / `- [x]` items
+    pub checked: Option<bool>,
+
+    /// First line of the item without its list marker and checkbox
+    pub text: String,
+}
+
+/// A table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// 1-based line number of the header row
+    pub line: usize,
+
+    /// 1-based last line of the table
+    pub end_line: usize,
+
+    /// Header cells
+    pub header: Vec<String>,
+
+    /// Body rows
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Index of a column by header (case-insensitive)
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.header
+            .iter()
+            .position(|cell| cell.trim().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A fenced or indented code block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// 1-based line number of the opening fence (or first indented line)
+    pub line: usize,
+
+    /// 1-based line number of the closing fence (or last indented line)
+    pub end_line: usize,
+
+    /// Info string language, e.g. `rust` for `` ```rust ``
+    pub lang: Option<String>,
+
+    /// Code content
+    pub text: String,
+}
+
+/// A block of raw HTML, such as an `<!-- ... -->` comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlBlock {
+    /// 1-based first line
+    pub line: usize,
+
+    /// 1-based last line
+    pub end_line: usize,
+
+    /// Raw HTML
+    pub text: String,
+}
+
+/// YAML front matter between `---` fences at the top of a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatter {
+    /// 1-based line number of the closing fence
+    pub end_line: usize,
+
+    /// Raw YAML text
+    pub text: String,
+
+    /// Top-level `key: value` fields, in order
+    pub fields: Vec<(String, String)>,
+}
+
+impl FrontMatter {
+    /// Value of a top-level field
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A parsed markdown document
+#[derive(Debug, Clone)]
+pub struct MarkdownDocument<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+    front_matter: Option<FrontMatter>,
+    sections: Vec<Section>,
+    items: Vec<ListItem>,
+    tables: Vec<Table>,
+    code_blocks: Vec<CodeBlock>,
+    html_blocks: Vec<HtmlBlock>,
+}
+
+impl<'a> MarkdownDocument<'a> {
+    /// Parse a document
+    pub fn parse(source: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+
+        let mut document = Self {
+            source,
+            line_starts,
+            front_matter: None,
+            sections: Vec::new(),
+            items: Vec::new(),
+            tables: Vec::new(),
+            code_blocks: Vec::new(),
+            html_blocks: Vec::new(),
+        };
+        document.build();
+        document
+    }
+
+    fn build(&mut self) {
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+
+        // Open list item indices and whether each enclosing list is ordered
+        let mut open_items: Vec<usize> = Vec::new();
+        let mut lists: Vec<bool> = Vec::new();
+        let mut table: Option<Table> = None;
+        let mut row: Vec<String> = Vec::new();
+        let mut cell: Option<String> = None;
+        let mut code: Option<CodeBlock> = None;
+        let mut metadata: Option<String> = None;
+
+        for (event, range) in Parser::new_ext(self.source, options).into_offset_iter() {
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    self.sections.push(Section {
+                        heading: heading_text(&self.source[range.clone()]),
+                        level: level as usize,
+                        line: self.line_of(range.start),
+                        end_line: 0,
+                    });
+                }
+                Event::Start(Tag::List(start)) => lists.push(start.is_some()),
+                Event::End(TagEnd::List(_)) => {
+                    lists.pop();
+                }
+                Event::Start(Tag::Item) => {
+                    open_items.push(self.items.len());
+                    self.items.push(ListItem {
+                        line: self.line_of(range.start),
+                        end_line: self.end_line_of(&range),
+                        depth: lists.len().saturating_sub(1),
+                        ordered: lists.last().copied().unwrap_or(false),
+                        checked: None,
+                        text: item_text(self.first_line(range.start)),
+                    });
+                }
+                Event::End(TagEnd::Item) => {
+                    open_items.pop();
+                }
+                Event::TaskListMarker(checked) => {
+                    if let Some(item) = open_items.last().map(|i| &mut self.items[*i]) {
+                        item.checked = Some(checked);
+                        item.text = strip_checkbox(&item.text).to_string();
+                    }
+                }
+                Event::Start(Tag::Table(_)) => {
+                    table = Some(Table {
+                        line: self.line_of(range.start),
+                        end_line: self.end_line_of(&range),
+                        header: Vec::new(),
+                        rows: Vec::new(),
+                    });
+                }
+                Event::End(TagEnd::Table) => self.tables.extend(table.take()),
+                Event::End(TagEnd::TableHead) => {
+                    if let Some(table) = table.as_mut() {
+                        table.header = std::mem::take(&mut row);
+                    }
+                }
+                Event::End(TagEnd::TableRow) => {
+                    if let Some(table) = table.as_mut() {
+                        table.rows.push(std::mem::take(&mut row));
+                    }
+                }
+                Event::Start(Tag::TableCell) => cell = Some(String::new()),
+                Event::End(TagEnd::TableCell) => row.extend(cell.take()),
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let lang = match kind {
+                        CodeBlockKind::Fenced(info) => {
+                            info.split_whitespace().next().map(str::to_string)
+                        }
+                        CodeBlockKind::Indented => None,
+                    };
+                    code = Some(CodeBlock {
+                        line: self.line_of(range.start),
+                        end_line: self.end_line_of(&range),
+                        lang,
+                        text: String::new(),
+                    });
+                }
+                Event::End(TagEnd::CodeBlock) => self.code_blocks.extend(code.take()),
+                Event::Start(Tag::HtmlBlock) => {
+                    self.html_blocks.push(HtmlBlock {
+                        line: self.line_of(range.start),
+                        end_line: self.end_line_of(&range),
+                        text: self.source[range].to_string(),
+                    });
+                }
+                Event::Start(Tag::MetadataBlock(_)) => metadata = Some(String::new()),
+                Event::End(TagEnd::MetadataBlock(_)) => {
+                    let text = metadata.take().unwrap_or_default();
+                    self.front_matter = Some(FrontMatter {
+                        end_line: self.end_line_of(&range),
+                        fields: front_matter_fields(&text),
+                        text,
+                    });
+                }
+                Event::Text(text) | Event::Code(text) => {
+                    if let Some(code) = code.as_mut() {
+                        code.text.push_str(&text);
+                    } else if let Some(metadata) = metadata.as_mut() {
+                        metadata.push_str(&text);
+                    } else if let Some(cell) = cell.as_mut() {
+                        cell.push_str(&text);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let last_line = self.source.lines().count();
+        for index in 0..self.sections.len() {
+            let level = self.sections[index].level;
+            self.sections[index].end_line = self.sections[index + 1..]
+                .iter()
+                .find(|s| s.level <= level)
+                .map_or(last_line, |s| s.line - 1);
+        }
+    }
+
+    /// Source text
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// Front matter, if the document starts with a `---` block
+    pub fn front_matter(&self) -> Option<&FrontMatter> {
+        self.front_matter.as_ref()
+    }
+
+    /// Headings in document order
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// List items in document order, nested items included
+    pub fn list_items(&self) -> &[ListItem] {
+        &self.items
+    }
+
+    /// Checkbox items in document order
+    pub fn checkbox_items(&self) -> impl Iterator<Item = &ListItem> {
+        self.items.iter().filter(|item| item.checked.is_some())
+    }
+
+    /// Tables in document order
+    pub fn tables(&self) -> &[Table] {
+        &self.tables
+    }
+
+    /// Code blocks in document order
+    pub fn code_blocks(&self) -> &[CodeBlock] {
+        &self.code_blocks
+    }
+
+    /// HTML blocks (including comments) in document order
+    pub fn html_blocks(&self) -> &[HtmlBlock] {
+        &self.html_blocks
+    }
+
+    /// The innermost section containing a line
+    pub fn section_at(&self, line: usize) -> Option<&Section> {
+        self.sections
+            .iter()
+            .filter(|s| s.lines().contains(&line))
+            .max_by_key(|s| s.line)
+    }
+
+    /// The list item starting on a line
+    pub fn item_at(&self, line: usize) -> Option<&ListItem> {
+        self.items.iter().find(|item| item.line == line)
+    }
+
+    /// Text of a 1-based line, without its line ending
+    pub fn line(&self, line: usize) -> &'a str {
+        let Some(start) = line.checked_sub(1).and_then(|i| self.line_starts.get(i)) else {
+            return "";
+        };
+        self.source[*start..].lines().next().unwrap_or_default()
+    }
+
+    /// Byte offset where a 1-based line starts (the source length past the
+    /// last line)
+    pub fn line_offset(&self, line: usize) -> usize {
+        line.checked_sub(1)
+            .and_then(|i| self.line_starts.get(i))
+            .map_or(self.source.len(), |start| (*start).min(self.source.len()))
+    }
+
+    /// Lines outside code blocks, HTML blocks and front matter, with 1-based
+    /// line numbers
+    pub fn prose_lines(&self) -> Vec<(usize, &'a str)> {
+        let front_matter_end = self.front_matter.as_ref().map_or(0, |f| f.end_line);
+        self.source
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(line, _)| {
+                *line > front_matter_end
+                    && !self
+                        .code_blocks
+                        .iter()
+                        .any(|c| (c.line..=c.end_line).contains(line))
+                    && !self
+                        .html_blocks
+                        .iter()
+                        .any(|h| (h.line..=h.end_line).contains(line))
+            })
+            .collect()
+    }
+
+    /// 1-based line containing a byte offset
+    fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= offset)
+    }
+
+    /// 1-based last line of a source range
+    fn end_line_of(&self, range: &Range<usize>) -> usize {
+        let end = self.source[..range.end]
+            .trim_end_matches(['\n', '\r'])
+            .len();
+        self.line_of(end.saturating_sub(1).max(range.start))
+    }
+
+    /// Source from an offset to the end of its line
+    fn first_line(&self, offset: usize) -> &'a str {
+        let rest = &self.source[offset..];
+        rest.lines().next().unwrap_or_default()
+    }
+}
+
+/// Heading text from its source, without `#` markers or setext underline
+fn heading_text(source: &str) -> String {
+    let first = source.lines().next().unwrap_or_default().trim();
+    let Some(rest) = first.strip_prefix('#') else {
+        return first.to_string();
+    };
+    let rest = rest.trim_start_matches('#').trim();
+    // Optional closing sequence: `## Title ##`
+    match rest.trim_end_matches('#') {
+        text if text.len() < rest.len() && (text.is_empty() || text.ends_with(' ')) => {
+            text.trim().to_string()
+        }
+        _ => rest.to_string(),
+    }
+}
+
+/// Item line without its list marker
+fn item_text(line: &str) -> String {
+    let line = line.trim();
+    let marker = if line.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        line.find(['.', ')'])
+            .filter(|end| *end > 0 && line[..*end].chars().all(|c| c.is_ascii_digit()))
+            .map_or(0, |end| end + 1)
+    };
+    line[marker..].trim().to_string()
+}
+
+/// Text after a `[ ]` / `[x]` checkbox
+fn strip_checkbox(text: &str) -> &str {
+    ["[ ]", "[x]", "[X]"]
+        .iter()
+        .find_map(|checkbox| text.strip_prefix(checkbox))
+        .unwrap_or(text)
+        .trim_start()
+}
+
+/// Top-level `key: value` pairs of a YAML block
+fn front_matter_fields(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .filter(|line| !line.starts_with([' ', '\t', '#', '-']))
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let value = value.trim().trim_matches(['"', '\'']);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "---\n\
+title: Export\n\
+status: draft\n\
+---\n\
+\n\
+# Feature: Export\n\
+\n\
+## Requirements *(mandatory)*\n\
+\n\
+- **FR-001**: Users MUST export data\n\
+- [ ] T001 Create model\n  - [x] nested done\n\
+1. first step\n\
+\n\
+```text\n\
+## Not a heading\n\
+- [ ] not a task\n\
+```\n\
+\n\
+<!--\n\
+  Users MUST ignore this\n\
+-->\n\
+\n\
+## Data ##\n\
+\n\
+| Entity | Fields |\n\
+|--------|--------|\n\
+| User | `id`, name |\n";
+
+    #[test]
+    fn test_structure_and_lines() {
+        let doc = MarkdownDocument::parse(DOCUMENT);
+
+        let front_matter = doc.front_matter().unwrap();
+        assert_eq!(front_matter.end_line, 4);
+        assert_eq!(front_matter.get("status"), Some("draft"));
+
+        let headings: Vec<_> = doc
+            .sections()
+            .iter()
+            .map(|s| (s.heading.as_str(), s.level, s.line, s.end_line))
+            .collect();
+        assert_eq!(
+            headings,
+            [
+                ("Feature: Export", 1, 6, 28),
+                ("Requirements *(mandatory)*", 2, 8, 23),
+                ("Data", 2, 24, 28),
+            ]
+        );
+        assert_eq!(
+            doc.section_at(11).unwrap().heading,
+            "Requirements *(mandatory)*"
+        );
+
+        let items: Vec<_> = doc
+            .list_items()
+            .iter()
+            .map(|i| (i.line, i.depth, i.ordered, i.checked, i.text.as_str()))
+            .collect();
+        assert_eq!(
+            items,
+            [
+                (10, 0, false, None, "**FR-001**: Users MUST export data"),
+                (11, 0, false, Some(false), "T001 Create model"),
+                (12, 1, false, Some(true), "nested done"),
+                (13, 0, true, None, "first step"),
+            ]
+        );
+        assert_eq!(doc.checkbox_items().count(), 2);
+
+        let code = &doc.code_blocks()[0];
+        assert_eq!((code.line, code.end_line), (15, 18));
+        assert_eq!(code.lang.as_deref(), Some("text"));
+
+        let table = &doc.tables()[0];
+        assert_eq!(table.line, 26);
+        assert_eq!(table.header, ["Entity", "Fields"]);
+        assert_eq!(
+            table.rows,
+            [vec!["User".to_string(), "id, name".to_string()]]
+        );
+        assert_eq!(table.column("fields"), Some(1));
+
+        let html = &doc.html_blocks()[0];
+        assert_eq!((html.line, html.end_line), (20, 22));
+        assert!(html.text.starts_with("<!--"));
+    }
+
+    #[test]
+    fn test_prose_lines_skip_code_html_and_front_matter() {
+        let doc = MarkdownDocument::parse(DOCUMENT);
+        let prose = doc.prose_lines();
+
+        assert!(prose.iter().all(|(_, text)| !text.contains("title:")));
+        assert!(prose
+            .iter()
+            .all(|(_, text)| !text.contains("Not a heading")));
+        assert!(prose.iter().all(|(_, text)| !text.contains("ignore this")));
+        assert!(prose.contains(&(10, "- **FR-001**: Users MUST export data")));
+    }
+
+    #[test]
+    fn test_heading_text() {
+        assert_eq!(heading_text("## Summary\n"), "Summary");
+        assert_eq!(heading_text("### C# ###"), "C#");
+        assert_eq!(heading_text("Title\n=====\n"), "Title");
+    }
+}
@@ -0,0 +1,36 @@
+//! Spec-Kit Artifact Model
+//!
+//! Locating, parsing and cross-referencing spec-kit artifacts (constitution,
+//! specifications, plans and tasks).
+
+pub mod amend;
+pub mod analysis;
+pub mod checklist;
+pub mod clarification;
+pub mod compliance;
+pub mod drafting;
+pub mod findings;
+pub mod layout;
+pub mod lint;
+pub mod markdown;
+pub mod model;
+pub mod requirements;
+pub mod templates;
+pub mod traceability;
+pub mod writer;
+
+pub use amend::*;
+pub use analysis::*;
+pub use checklist::*;
+pub use clarification::*;
+pub use compliance::*;
+pub use drafting::*;
+pub use findings::*;
+pub use layout::*;
+pub use lint::*;
+pub use markdown::*;
+pub use model::*;
+pub use requirements::*;
+pub use templates::*;
+pub use traceability::*;
+pub use writer::*;
@@ -0,0 +1,284 @@
+//! Artifact Model
+//!
+//! Loads the spec-kit artifacts of a project and exposes their parsed
+//! structure (sections, requirements, plan sections and tasks).
+
+use anyhow::Result;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::findings::Location;
+use super::layout::ProjectLayout;
+use super::markdown::{MarkdownDocument, Section};
+use super::requirements::{extract_requirements, Requirement};
+use super::traceability::{parse_plan_sections, parse_tasks, PlanSection, TaskEntry};
+use crate::utils::PathPolicy;
+
+/// Kind of spec-kit artifact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArtifactKind {
+    /// Project constitution
+    Constitution,
+
+    /// Feature specification
+    Specification,
+
+    /// Technical plan
+    Plan,
+
+    /// Task list
+    Tasks,
+}
+
+impl ArtifactKind {
+    /// All artifact kinds, in workflow order
+    pub const ALL: [ArtifactKind; 4] = [
+        ArtifactKind::Constitution,
+        ArtifactKind::Specification,
+        ArtifactKind::Plan,
+        ArtifactKind::Tasks,
+    ];
+
+    /// File name of this artifact in the flat layout
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Constitution => "speckit.constitution",
+            Self::Specification => "speckit.specify",
+            Self::Plan => "speckit.plan",
+            Self::Tasks => "speckit.tasks",
+        }
+    }
+}
+
+impl fmt::Display for ArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Constitution => "Constitution",
+            Self::Specification => "Specification",
+            Self::Plan => "Plan",
+            Self::Tasks => "Tasks",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A loaded artifact
+#[derive(Debug, Clone)]
+pub struct Artifact {
+    /// Artifact kind
+    pub kind: ArtifactKind,
+
+    /// Path on disk
+    pub path: PathBuf,
+
+    /// Short name used in finding locations
+    pub label: String,
+
+    /// Raw content
+    pub content: String,
+}
+
+impl Artifact {
+    /// Create an artifact from its content
+    pub fn new(kind: ArtifactKind, path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        let path = path.into();
+        let label = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+
+        Self {
+            kind,
+            path,
+            label,
+            content: content.into(),
+        }
+    }
+
+    /// Location of a line within this artifact
+    pub fn location(&self, line: usize) -> Location {
+        Location::line(&self.label, line)
+    }
+
+    /// Parsed markdown structure
+    pub fn document(&self) -> MarkdownDocument<'_> {
+        MarkdownDocument::parse(&self.content)
+    }
+
+    /// Lines outside code blocks, HTML blocks and front matter, with 1-based
+    /// line numbers
+    pub fn prose_lines(&self) -> Vec<(usize, &str)> {
+        self.document().prose_lines()
+    }
+
+    /// Markdown section headings
+    pub fn sections(&self) -> Vec<Section> {
+        self.document().sections().to_vec()
+    }
+
+    /// Requirement definitions
+    pub fn requirements(&self) -> Vec<Requirement> {
+        extract_requirements(&self.content)
+    }
+
+    /// Plan sections that reference requirements
+    pub fn plan_sections(&self) -> Vec<PlanSection> {
+        parse_plan_sections(&self.content)
+    }
+
+    /// Task entries
+    pub fn tasks(&self) -> Vec<TaskEntry> {
+        parse_tasks(&self.content)
+    }
+}
+
+/// The set of artifacts found in a project
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactSet {
+    /// Project constitution
+    pub constitution: Option<Artifact>,
+
+    /// Feature specification
+    pub specification: Option<Artifact>,
+
+    /// Technical plan
+    pub plan: Option<Artifact>,
+
+    /// Task list
+    pub tasks: Option<Artifact>,
+
+    /// Layout the artifacts were loaded from
+    pub layout: Option<ProjectLayout>,
+}
+
+impl ArtifactSet {
+    /// Load every artifact that exists in a project directory
+    pub async fn load(project_path: &Path, paths: &PathPolicy) -> Result<Self> {
+        Self::load_layout(&ProjectLayout::resolve_in(project_path, paths)?, paths).await
+    }
+
+    /// Load every artifact that exists in a resolved project layout
+    ///
+    /// Artifacts outside the workspace roots are rejected.
+    pub async fn load_layout(layout: &ProjectLayout, paths: &PathPolicy) -> Result<Self> {
+        let mut set = Self {
+            layout: Some(layout.clone()),
+            ..Self::default()
+        };
+
+        for kind in ArtifactKind::ALL {
+            let Some(path) = layout.find(kind) else {
+                continue;
+            };
+
+            let content = paths.read_to_string(&path).await?;
+            let mut artifact = Artifact::new(kind, &path, content);
+            artifact.label = layout.relative(&path);
+            set.insert(artifact);
+        }
+
+        Ok(set)
+    }
+
+    /// Display label of an artifact, or of where it is expected when missing
+    pub fn label_for(&self, kind: ArtifactKind) -> String {
+        match (self.get(kind), &self.layout) {
+            (Some(artifact), _) => artifact.label.clone(),
+            (None, Some(layout)) => layout.relative(&layout.artifact_path(kind)),
+            (None, None) => kind.file_name().to_string(),
+        }
+    }
+
+    /// Add or replace an artifact
+    pub fn insert(&mut self, artifact: Artifact) {
+        let slot = match artifact.kind {
+            ArtifactKind::Constitution => &mut self.constitution,
+            ArtifactKind::Specification => &mut self.specification,
+            ArtifactKind::Plan => &mut self.plan,
+            ArtifactKind::Tasks => &mut self.tasks,
+        };
+        *slot = Some(artifact);
+    }
+
+    /// Get an artifact by kind
+    pub fn get(&self, kind: ArtifactKind) -> Option<&Artifact> {
+        match kind {
+            ArtifactKind::Constitution => self.constitution.as_ref(),
+            ArtifactKind::Specification => self.specification.as_ref(),
+            ArtifactKind::Plan => self.plan.as_ref(),
+            ArtifactKind::Tasks => self.tasks.as_ref(),
+        }
+    }
+
+    /// Artifacts that are present, in workflow order
+    pub fn present(&self) -> Vec<&Artifact> {
+        ArtifactKind::ALL
+            .iter()
+            .filter_map(|kind| self.get(*kind))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_skip_code_blocks() {
+        let artifact = Artifact::new(
+            ArtifactKind::Plan,
+            "plan.md",
+            "# Plan\n\n```sh\n# not a heading\n```\n\n## Architecture\n",
+        );
+
+        let sections = artifact.sections();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].heading, "Architecture");
+        assert_eq!(sections[1].level, 2);
+        assert_eq!(sections[1].line, 7);
+    }
+
+    #[tokio::test]
+    async fn test_load_artifact_set() {
+        let dir = tempfile::tempdir().unwrap();
+        tokio::fs::write(dir.path().join("speckit.specify"), "- **FR-001**: Login")
+            .await
+            .unwrap();
+
+        let set = ArtifactSet::load(dir.path(), &PathPolicy::unrestricted())
+            .await
+            .unwrap();
+        assert!(set.constitution.is_none());
+        assert_eq!(set.present().len(), 1);
+        assert_eq!(set.specification.unwrap().requirements().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_load_speckit_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let feature = dir.path().join("specs/001-login");
+        tokio::fs::create_dir_all(dir.path().join(".specify/memory"))
+            .await
+            .unwrap();
+        tokio::fs::create_dir_all(&feature).await.unwrap();
+        tokio::fs::write(dir.path().join(".specify/memory/constitution.md"), "# C")
+            .await
+            .unwrap();
+        tokio::fs::write(feature.join("spec.md"), "- **FR-001**: Login")
+            .await
+            .unwrap();
+
+        let set = ArtifactSet::load(dir.path(), &PathPolicy::unrestricted())
+            .await
+            .unwrap();
+        assert_eq!(set.present().len(), 2);
+        assert_eq!(
+            set.label_for(ArtifactKind::Specification),
+            "specs/001-login/spec.md"
+        );
+        assert_eq!(
+            set.label_for(ArtifactKind::Tasks),
+            "specs/001-login/tasks.md"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! Requirement Identifiers
+//!
+//! Stable requirement IDs (`FR-001`, `NFR-002`, ...) and helpers to find,
+//! extract and assign them in specification text.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use super::markdown::MarkdownDocument;
+
+/// Kind of requirement, which determines the ID prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum RequirementKind {
+    /// Functional requirement (`FR-`)
+    Functional,
+
+    /// Non-functional requirement (`NFR-`)
+    NonFunctional,
+}
+
+impl RequirementKind {
+    /// ID prefix for this kind
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::Functional => "FR",
+            Self::NonFunctional => "NFR",
+        }
+    }
+
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "FR" => Some(Self::Functional),
+            "NFR" => Some(Self::NonFunctional),
+            _ => None,
+        }
+    }
+}
+
+/// A requirement identifier such as `FR-001`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequirementId {
+    /// Requirement kind
+    pub kind: RequirementKind,
+
+    /// Sequence number within the kind
+    pub number: u32,
+}
+
+impl RequirementId {
+    /// Create a new requirement ID
+    pub fn new(kind: RequirementKind, number: u32) -> Self {
+        Self { kind, number }
+    }
+
+    /// Parse an ID such as `FR-001` or `NFR-12`
+    pub fn parse(s: &str) -> Option<Self> {
+        let (prefix, number) = s.split_once('-')?;
+        let kind = RequirementKind::from_prefix(prefix)?;
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some(Self::new(kind, number.parse().ok()?))
+    }
+}
+
+impl fmt::Display for RequirementId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:03}", self.kind.prefix(), self.number)
+    }
+}
+
+impl Serialize for RequirementId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RequirementId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid requirement ID: {}", s)))
+    }
+}
+
+/// A requirement defined in a specification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Requirement {
+    /// Requirement ID
+    pub id: RequirementId,
+
+    /// Requirement text (without the ID)
+    pub text: String,
+
+    /// 1-based line number in the specification
+    pub line: usize,
+}
+
+/// Find every requirement ID referenced in a piece of text, in order of appearance
+pub fn find_requirement_ids(text: &str) -> Vec<RequirementId> {
+    let mut ids = Vec::new();
+    let bytes = text.as_bytes();
+
+    for (start, _) in text.match_indices("FR-") {
+        // Include the `N` of `NFR-` and reject IDs glued to other words
+        let start = if start > 0 && bytes[start - 1] == b'N' {
+            start - 1
+        } else {
+            start
+        };
+        if start > 0 && is_word_byte(bytes[start - 1]) {
+            continue;
+        }
+
+        let digits_start = text[start..]
+            .find('-')
+            .map(|i| start + i + 1)
+            .unwrap_or(start);
+        let digits_end = text[digits_start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map(|i| digits_start + i)
+            .unwrap_or(text.len());
+        if digits_end < text.len() && is_word_byte(bytes[digits_end]) {
+            continue;
+        }
+
+        if let Some(id) = RequirementId::parse(&text[start..digits_end]) {
+            ids.push(id);
+        }
+    }
+
+    ids
+}
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Extract requirement definitions from a specification
+///
+/// A definition is a prose line whose content (after any list marker and
+/// bold markup) starts with a requirement ID, e.g.
+/// `- **FR-001**: Users MUST ...`. Lines in code blocks and HTML comments are
+/// not definitions.
+pub fn extract_requirements(spec: &str) -> Vec<Requirement> {
+    MarkdownDocument::parse(spec)
+        .prose_lines()
+        .into_iter()
+        .filter_map(|(line, text)| {
+            let (id, text) = split_definition(text)?;
+            Some(Requirement {
+                id,
+                text: text.to_string(),
+                line,
+            })
+        })
+        .collect()
+}
+
+/// Split a definition line into its ID and text
+pub(super) fn split_definition(line: &str) -> Option<(RequirementId, &str)> {
+    let body = strip_list_marker(line.trim()).trim_start_matches('*');
+    let end = body
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(body.len());
+    let id = RequirementId::parse(&body[..end])?;
+    let text = body[end..]
+        .trim_start_matches('*')
+        .trim_start_matches(':')
+        .trim();
+    Some((id, text))
+}
+
+/// Remove a leading markdown list marker (`-`, `*`, `+` or `1.`) from a line
+pub fn strip_list_marker(line: &str) -> &str {
+    let trimmed = line.trim_start();
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = trimmed.strip_prefix(marker) {
+            return rest.trim_start();
+        }
+    }
+
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(rest) = trimmed[digits..].strip_prefix(". ") {
+            return rest.trim_start();
+        }
+    }
+
+    trimmed
+}
+
+/// Assigns stable requirement IDs
+///
+/// IDs already present in an existing specification are reused for
+/// requirements with the same text, so re-running `speckit_specify` does not
+/// renumber requirements that plans and tasks already reference.
+#[derive(Debug, Clone, Default)]
+pub struct RequirementNumbering {
+    existing: HashMap<(RequirementKind, String), RequirementId>,
+    next: HashMap<RequirementKind, u32>,
+}
+
+impl RequirementNumbering {
+    /// Create a numbering that starts at 1 for every kind
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a numbering seeded from an existing specification
+    pub fn from_existing(spec: &str) -> Self {
+        let mut numbering = Self::new();
+        for requirement in extract_requirements(spec) {
+            numbering.reserve(requirement.id);
+            numbering.existing.insert(
+                (requirement.id.kind, normalize(&requirement.text)),
+                requirement.id,
+            );
+        }
+        numbering
+    }
+
+    /// Mark an ID as taken so it is never handed out again
+    fn reserve(&mut self, id: RequirementId) {
+        let next = self.next.entry(id.kind).or_insert(1);
+        *next = (*next).max(id.number + 1);
+    }
+
+    /// Get the ID for a requirement: the ID written in the text, an existing
+    /// one when the text matches, or the next free one
+    pub fn assign(&mut self, kind: RequirementKind, text: &str) -> RequirementId {
+        if let Some((id, _)) = split_definition(text) {
+            self.reserve(id);
+            return id;
+        }
+        if let Some(id) = self.existing.get(&(kind, normalize(text))) {
+            return *id;
+        }
+
+        let next = self.next.entry(kind).or_insert(1);
+        let id = RequirementId::new(kind, *next);
+        *next += 1;
+        id
+    }
+
+    /// Format free-form requirement text as an ID'd markdown list
+    ///
+    /// Every non-empty line becomes one requirement. Lines that already carry
+    /// an ID keep it, and markdown headings are passed through unchanged.
+    pub fn format_list(&mut self, kind: RequirementKind, text: &str) -> String {
+        // Explicit IDs are claimed before any ID is handed out, so an earlier
+        // line cannot take one of them
+        let explicit: Vec<RequirementId> = text
+            .lines()
+            .filter_map(|line| split_definition(line.trim()))
+            .map(|(id, _)| id)
+            .collect();
+        for id in &explicit {
+            self.reserve(*id);
+        }
+        self.existing.retain(|_, id| !explicit.contains(id));
+
+        let mut output = String::new();
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            if trimmed.starts_with('#') {
+                output.push_str(&format!("\n{}\n\n", trimmed));
+                continue;
+            }
+
+            let (id, body) = match split_definition(trimmed) {
+                Some((id, body)) => {
+                    self.reserve(id);
+                    (id, body)
+                }
+                None => {
+                    let body = strip_list_marker(trimmed);
+                    (self.assign(kind, body), body)
+                }
+            };
+            output.push_str(&format!("- **{}**: {}\n", id, body));
+        }
+
+        output
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let id = RequirementId::parse("FR-7").unwrap();
+        assert_eq!(id.to_string(), "FR-007");
+        assert_eq!(
+            RequirementId::parse("NFR-012").unwrap(),
+            RequirementId::new(RequirementKind::NonFunctional, 12)
+        );
+        assert!(RequirementId::parse("XR-001").is_none());
+        assert!(RequirementId::parse("FR-").is_none());
+    }
+
+    #[test]
+    fn test_find_requirement_ids() {
+        let ids =
+            find_requirement_ids("Covers FR-001, NFR-002 and (FR-010). Not SFR-003 or FR-004x");
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        assert_eq!(ids, vec!["FR-001", "NFR-002", "FR-010"]);
+    }
+
+    #[test]
+    fn test_extract_requirements() {
+        let spec =
+            "# Spec\n\n- **FR-001**: Users MUST log in\n- NFR-001: Respond in 200ms\nSee FR-001.\n";
+        let requirements = extract_requirements(spec);
+
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(requirements[0].id.to_string(), "FR-001");
+        assert_eq!(requirements[0].text, "Users MUST log in");
+        assert_eq!(requirements[0].line, 3);
+        assert_eq!(requirements[1].id.to_string(), "NFR-001");
+    }
+
+    #[test]
+    fn test_extract_requirements_skips_code_and_comments() {
+        let spec = "## Requirements\n\n\
+            - **FR-001**: Users MUST log in\n\n\
+            ```markdown\n- **FR-002**: Example entries MUST look like this\n```\n\n\
+            <!--\n- **FR-003**: Template guidance MUST be removed\n-->\n";
+        let requirements = extract_requirements(spec);
+
+        assert_eq!(requirements.len(), 1);
+        assert_eq!(requirements[0].id.to_string(), "FR-001");
+        assert_eq!(requirements[0].line, 3);
+    }
+
+    #[test]
+    fn test_numbering_is_stable() {
+        let existing = "- **FR-001**: Users MUST log in\n- **FR-002**: Users MUST log out\n";
+        let mut numbering = RequirementNumbering::from_existing(existing);
+
+        let list = numbering.format_list(
+            RequirementKind::Functional,
+            "- Users must log out\n- Users MUST reset passwords\n",
+        );
+
+        assert_eq!(
+            list,
+            "- **FR-002**: Users must log out\n- **FR-003**: Users MUST reset passwords\n"
+        );
+    }
+
+    #[test]
+    fn test_numbering_reserves_explicit_ids() {
+        let mut numbering = RequirementNumbering::new();
+        let list = numbering.format_list(RequirementKind::Functional, "Export\nFR-001: Import\n");
+        assert_eq!(list, "- **FR-002**: Export\n- **FR-001**: Import\n");
+
+        // An explicit ID also wins over an existing requirement's ID
+        let mut numbering =
+            RequirementNumbering::from_existing("- **FR-001**: Users MUST log in\n");
+        let list = numbering.format_list(
+            RequirementKind::Functional,
+            "Users MUST log in\nFR-001: Users MUST export\n",
+        );
+        assert_eq!(
+            list,
+            "- **FR-002**: Users MUST log in\n- **FR-001**: Users MUST export\n"
+        );
+        assert_eq!(
+            numbering.assign(RequirementKind::Functional, "FR-007: Import"),
+            RequirementId::new(RequirementKind::Functional, 7)
+        );
+        assert_eq!(
+            numbering.assign(RequirementKind::Functional, "Archive"),
+            RequirementId::new(RequirementKind::Functional, 8)
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! Artifact Templates
+//!
+//! Templates for the documents the tools write. Each kind has an embedded
+//! default that mirrors upstream spec-kit's, and a project overrides it by
+//! placing a file of the same name in `.specify/templates/`. Rendering
+//! substitutes placeholders (`[FEATURE NAME]`, `[DATE]`, `$ARGUMENTS`, ...),
+//! fills or drops named sections, and checks that the sections every
+//! document of the kind needs survived.
+
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::amend::{find_block, headings, splice};
+use super::layout::{ProjectLayout, SPECIFY_DIR};
+use crate::utils::PathPolicy;
+
+/// Directory under `.specify/` holding project templates
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// A document kind with a template
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateKind {
+    /// Project constitution
+    Constitution,
+
+    /// Feature specification
+    Spec,
+
+    /// Implementation plan
+    Plan,
+
+    /// Task list
+    Tasks,
+}
+
+impl TemplateKind {
+    /// All template kinds
+    pub const ALL: [TemplateKind; 4] = [
+        TemplateKind::Constitution,
+        TemplateKind::Spec,
+        TemplateKind::Plan,
+        TemplateKind::Tasks,
+    ];
+
+    /// File name in `.specify/templates/`
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            TemplateKind::Constitution => "constitution-template.md",
+            TemplateKind::Spec => "spec-template.md",
+            TemplateKind::Plan => "plan-template.md",
+            TemplateKind::Tasks => "tasks-template.md",
+        }
+    }
+
+    /// Built-in template
+    pub fn embedded(&self) -> &'static str {
+        match self {
+            TemplateKind::Constitution => CONSTITUTION_TEMPLATE,
+            TemplateKind::Spec => SPEC_TEMPLATE,
+            TemplateKind::Plan => PLAN_TEMPLATE,
+            TemplateKind::Tasks => TASKS_TEMPLATE,
+        }
+    }
+
+    /// Sections a rendered document must keep
+    pub fn required_sections(&self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::Constitution => &["Core Principles"],
+            TemplateKind::Spec => &["Requirements"],
+            TemplateKind::Plan => &[
+                "Summary",
+                "Technical Context",
+                "Constitution Check",
+                "Project Structure",
+            ],
+            TemplateKind::Tasks => &[],
+        }
+    }
+}
+
+impl fmt::Display for TemplateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateKind::Constitution => write!(f, "constitution"),
+            TemplateKind::Spec => write!(f, "spec"),
+            TemplateKind::Plan => write!(f, "plan"),
+            TemplateKind::Tasks => write!(f, "tasks"),
+        }
+    }
+}
+
+/// Built-in constitution template
+pub const CONSTITUTION_TEMPLATE: &str = include_str!("templates/constitution-template.md");
+
+/// Built-in specification template
+pub const SPEC_TEMPLATE: &str = include_str!("templates/spec-template.md");
+
+/// Built-in plan template
+pub const PLAN_TEMPLATE: &str = include_str!("templates/plan-template.md");
+
+/// Built-in task template
+pub const TASKS_TEMPLATE: &str = include_str!("templates/tasks-template.md");
+
+/// Where a template came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// Compiled into the server
+    Embedded,
+
+    /// A project override in `.specify/templates/`
+    Project(PathBuf),
+}
+
+impl fmt::Display for TemplateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateSource::Embedded => write!(f, "built-in"),
+            TemplateSource::Project(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// A loaded template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    /// Document kind
+    pub kind: TemplateKind,
+
+    /// Where the text came from
+    pub source: TemplateSource,
+
+    /// Template text
+    pub text: String,
+}
+
+impl Template {
+    /// The built-in template for a kind
+    pub fn embedded(kind: TemplateKind) -> Self {
+        Self {
+            kind,
+            source: TemplateSource::Embedded,
+            text: kind.embedded().to_string(),
+        }
+    }
+
+    /// Path of a project's override for a kind
+    pub fn project_path(root: &Path, kind: TemplateKind) -> PathBuf {
+        root.join(SPECIFY_DIR)
+            .join(TEMPLATES_DIR)
+            .join(kind.file_name())
+    }
+
+    /// Load the project's template for a kind, falling back to the built-in
+    /// one
+    pub async fn load(root: &Path, kind: TemplateKind, paths: &PathPolicy) -> Result<Self> {
+        let path = Self::project_path(root, kind);
+        if !paths.check(&path)?.is_file() {
+            return Ok(Self::embedded(kind));
+        }
+        tracing::debug!(kind = %kind, path = %path.display(), "Using project template");
+        Ok(Self {
+            kind,
+            text: paths.read_to_string(&path).await?,
+            source: TemplateSource::Project(path),
+        })
+    }
+
+    /// Render the template
+    ///
+    /// Placeholders are substituted first, so filled section bodies are
+    /// inserted verbatim. Fails when a required section is missing from the
+    /// result.
+    pub fn render(&self, context: &TemplateContext) -> Result<String> {
+        let mut document = self.text.clone();
+        for (placeholder, value) in &context.values {
+            document = document.replace(placeholder.as_str(), value);
+        }
+
+        for (heading, body) in &context.sections {
+            document = fill_section(&document, heading, body.as_deref());
+        }
+
+        let missing: Vec<&str> = self
+            .kind
+            .required_sections()
+            .iter()
+            .copied()
+            .filter(|section| section_range(&document, section).is_none())
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "The {} template ({}) is missing required section(s): {}",
+                self.kind,
+                self.source,
+                missing
+                    .iter()
+                    .map(|section| format!("`## {}`", section))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        Ok(document)
+    }
+}
+
+/// Values and section contents a template is rendered with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateContext {
+    values: Vec<(String, String)>,
+    sections: Vec<(String, Option<String>)>,
+}
+
+impl TemplateContext {
+    /// An empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Context with the project's standard placeholders: `[PROJECT_NAME]`,
+    /// `[FEATURE NAME]`/`[FEATURE]`, `[###-feature-name]` and the dates
+    ///
+    /// Flat projects use the project directory name for the feature.
+    pub fn for_layout(layout: &ProjectLayout, date: NaiveDate) -> Self {
+        let project = layout
+            .root()
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Project".to_string());
+        let (feature, branch) = match layout.feature() {
+            Some(feature) => (feature_title(&feature.name), feature.name.clone()),
+            None => (feature_title(&project), project.clone()),
+        };
+        let date = date.to_string();
+
+        Self::new()
+            .set("[PROJECT_NAME]", feature_title(&project))
+            .set("[FEATURE NAME]", &feature)
+            .set("[FEATURE]", feature)
+            .set("[###-feature-name]", branch)
+            .set("[DATE]", &date)
+            .set("[RATIFICATION_DATE]", &date)
+            .set("[LAST_AMENDED_DATE]", date)
+    }
+
+    /// Substitute a placeholder
+    pub fn set(mut self, placeholder: impl Into<String>, value: impl Into<String>) -> Self {
+        let placeholder = placeholder.into();
+        self.values.retain(|(p, _)| *p != placeholder);
+        self.values.push((placeholder, value.into()));
+        self
+    }
+
+    /// Replace the body of a `##` (or `###`) section, appending the section
+    /// when the template lacks it
+    pub fn section(mut self, heading: impl Into<String>, body: impl Into<String>) -> Self {
+        self.sections.push((heading.into(), Some(body.into())));
+        self
+    }
+
+    /// Drop a section when the template has it
+    pub fn omit(mut self, heading: impl Into<String>) -> Self {
+        self.sections.push((heading.into(), None));
+        self
+    }
+}
+
+/// Human-readable feature name: `001-user-auth` → `User Auth`
+fn feature_title(name: &str) -> String {
+    let unnumbered = match name.split_once('-') {
+        Some((number, rest)) if number.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => name,
+    };
+    unnumbered
+        .split(['-', '_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Line range of a `##` section, or a `###` section when no `##` matches
+fn section_range(document: &str, heading: &str) -> Option<std::ops::Range<usize>> {
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    let headings = headings(&lines);
+    [2, 3]
+        .into_iter()
+        .find_map(|level| find_block(&headings, &lines, level, heading, 0..lines.len()))
+}
+
+/// Replace, append or (without a body) remove a section
+fn fill_section(document: &str, heading: &str, body: Option<&str>) -> String {
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    match (section_range(document, heading), body) {
+        (Some(range), Some(body)) => {
+            let block = format!("{}\n\n{}", lines[range.start].trim_end(), body.trim_end());
+            splice(&lines, range, &block)
+        }
+        (Some(range), None) => splice(&lines, range, ""),
+        (None, Some(body)) => splice(
+            &lines,
+            lines.len()..lines.len(),
+            &format!("## {}\n\n{}", heading, body.trim_end()),
+        ),
+        (None, None) => document.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    }
+
+    #[test]
+    fn test_render_embedded_spec() {
+        let dir = tempdir().unwrap();
+        let feature = dir.path().join("specs/001-user-auth");
+        std::fs::create_dir_all(&feature).unwrap();
+        let layout = ProjectLayout::resolve(dir.path());
+
+        let rendered = Template::embedded(TemplateKind::Spec)
+            .render(
+                &TemplateContext::for_layout(&layout, date())
+                    .set("$ARGUMENTS", "Users can log in")
+                    .section(
+                        "Requirements",
+                        "### Functional Requirements\n\n- **FR-001**: Users can log in",
+                    )
+                    .omit("Success Criteria")
+                    .omit("User Scenarios & Testing"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            rendered,
+            "# Feature Specification: User Auth\n\n\
+             **Feature Branch**: `001-user-auth`  \n\
+             **Created**: 2025-03-01  \n\
+             **Status**: Draft  \n\
+             **Input**: User description: \"Users can log in\"\n\n\
+             ## Requirements *(mandatory)*\n\n\
+             ### Functional Requirements\n\n\
+             - **FR-001**: Users can log in\n"
+        );
+    }
+
+    #[test]
+    fn test_embedded_templates_follow_upstream() {
+        // Upstream spec-kit's section structure, which lint and analyze expect
+        let sections = |text: &str| -> Vec<String> {
+            text.lines()
+                .filter(|line| line.starts_with("## ") || line.starts_with("### "))
+                .filter(|line| !line.starts_with("### User Story"))
+                .map(str::to_string)
+                .collect()
+        };
+        assert_eq!(
+            sections(SPEC_TEMPLATE),
+            [
+                "## User Scenarios & Testing *(mandatory)*",
+                "### Edge Cases",
+                "## Requirements *(mandatory)*",
+                "### Functional Requirements",
+                "### Key Entities *(include if feature involves data)*",
+                "## Success Criteria *(mandatory)*",
+                "### Measurable Outcomes",
+            ]
+        );
+        assert_eq!(
+            sections(PLAN_TEMPLATE),
+            [
+                "## Summary",
+                "## Technical Context",
+                "## Constitution Check",
+                "## Project Structure",
+                "### Documentation (this feature)",
+                "### Source Code (repository root)",
+                "## Complexity Tracking",
+            ]
+        );
+        for kind in TemplateKind::ALL {
+            for section in kind.required_sections() {
+                assert!(
+                    section_range(kind.embedded(), section).is_some(),
+                    "{} template lacks {}",
+                    kind,
+                    section
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_override() {
+        let dir = tempdir().unwrap();
+        let paths = PathPolicy::unrestricted();
+        let path = Template::project_path(dir.path(), TemplateKind::Spec);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &path,
+            "# Feature Specification: [FEATURE NAME]\n\n\
+             **Input**: User description: \"$ARGUMENTS\"\n\n\
+             ## User Scenarios & Testing *(mandatory)*\n\n[Describe the journey]\n\n\
+             ## Requirements *(mandatory)*\n\n### Functional Requirements\n\n\
+             - **FR-001**: System MUST [specific capability]\n",
+        )
+        .unwrap();
+
+        let template = Template::load(dir.path(), TemplateKind::Spec, &paths)
+            .await
+            .unwrap();
+        assert_eq!(template.source, TemplateSource::Project(path));
+
+        let rendered = template
+            .render(
+                &TemplateContext::new()
+                    .set("[FEATURE NAME]", "Export")
+                    .set("$ARGUMENTS", "Export reports")
+                    .section("Requirements", "- **FR-001**: Users can export CSV")
+                    .section("User Stories", "As a user, I want exports"),
+            )
+            .unwrap();
+        assert!(rendered.starts_with("# Feature Specification: Export\n"));
+        assert!(rendered.contains("User description: \"Export reports\""));
+        assert!(rendered
+            .contains("## Requirements *(mandatory)*\n\n- **FR-001**: Users can export CSV\n"));
+        assert!(!rendered.contains("[specific capability]"));
+        assert!(rendered.ends_with("## User Stories\n\nAs a user, I want exports\n"));
+
+        let missing = Template::load(dir.path(), TemplateKind::Constitution, &paths)
+            .await
+            .unwrap();
+        assert_eq!(missing.source, TemplateSource::Embedded);
+    }
+
+    #[test]
+    fn test_render_requires_sections() {
+        let template = Template {
+            kind: TemplateKind::Constitution,
+            source: TemplateSource::Project(PathBuf::from("constitution-template.md")),
+            text: "# Constitution\n\n## Core Principles\n\n[PRINCIPLES]\n".to_string(),
+        };
+        let err = template
+            .render(&TemplateContext::new().omit("Core Principles"))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required section(s): `## Core Principles`"));
+
+        let rendered = template
+            .render(&TemplateContext::new().section("Core Principles", "### I. Simplicity"))
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "# Constitution\n\n## Core Principles\n\n### I. Simplicity\n"
+        );
+    }
+
+    #[test]
+    fn test_feature_title() {
+        assert_eq!(feature_title("001-user-auth"), "User Auth");
+        assert_eq!(feature_title("my_project"), "My Project");
+    }
+}
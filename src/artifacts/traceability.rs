@@ -0,0 +1,440 @@
+//! Requirement Traceability
+//!
+//! Links specification requirements to the plan sections and tasks that
+//! reference them, and reports gaps in either direction.
+
+use serde::{Deserialize, Serialize};
+
+use super::markdown::MarkdownDocument;
+use super::requirements::{extract_requirements, find_requirement_ids, Requirement, RequirementId};
+
+/// Output format for a traceability report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceFormat {
+    /// Markdown tables
+    #[default]
+    Markdown,
+
+    /// Comma-separated values, one row per requirement
+    Csv,
+
+    /// Pretty-printed JSON
+    Json,
+}
+
+/// A plan section that references requirements
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanSection {
+    /// Section heading (without `#` markers)
+    pub heading: String,
+
+    /// 1-based line number of the heading
+    pub line: usize,
+
+    /// Requirements referenced within the section
+    pub requirements: Vec<RequirementId>,
+}
+
+/// A task from the task list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEntry {
+    /// Task ID (e.g. `T001`), or `L<line>` when the task has no ID
+    pub id: String,
+
+    /// Task description
+    pub text: String,
+
+    /// 1-based line number in the task list
+    pub line: usize,
+
+    /// Requirements referenced by the task
+    pub requirements: Vec<RequirementId>,
+}
+
+/// Trace of a single requirement through plan and tasks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequirementTrace {
+    /// The requirement being traced
+    pub requirement: Requirement,
+
+    /// Headings of plan sections that reference the requirement
+    pub plan_sections: Vec<String>,
+
+    /// IDs of tasks that reference the requirement
+    pub tasks: Vec<String>,
+}
+
+impl RequirementTrace {
+    /// Whether at least one task implements the requirement
+    pub fn is_covered(&self) -> bool {
+        !self.tasks.is_empty()
+    }
+}
+
+/// A reference to a requirement ID that the specification does not define
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DanglingReference {
+    /// The undefined requirement ID
+    pub id: RequirementId,
+
+    /// Where the reference appears (e.g. `plan:12` or `tasks:T004`)
+    pub location: String,
+}
+
+/// Requirement traceability matrix across spec, plan and tasks
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TraceabilityMatrix {
+    /// One entry per requirement, in specification order
+    pub requirements: Vec<RequirementTrace>,
+
+    /// Tasks that do not reference any requirement
+    pub orphan_tasks: Vec<TaskEntry>,
+
+    /// References to requirement IDs missing from the specification
+    pub dangling_references: Vec<DanglingReference>,
+}
+
+impl TraceabilityMatrix {
+    /// Build the matrix from artifact contents
+    pub fn build(spec: &str, plan: Option<&str>, tasks: Option<&str>) -> Self {
+        let sections = plan.map(parse_plan_sections).unwrap_or_default();
+        let task_entries = tasks.map(parse_tasks).unwrap_or_default();
+        let requirements = extract_requirements(spec);
+
+        let is_defined = |id: &RequirementId| requirements.iter().any(|r| r.id == *id);
+
+        let mut dangling_references = Vec::new();
+        for section in &sections {
+            for id in section.requirements.iter().filter(|id| !is_defined(id)) {
+                dangling_references.push(DanglingReference {
+                    id: *id,
+                    location: format!("plan:{}", section.line),
+                });
+            }
+        }
+        for task in &task_entries {
+            for id in task.requirements.iter().filter(|id| !is_defined(id)) {
+                dangling_references.push(DanglingReference {
+                    id: *id,
+                    location: format!("tasks:{}", task.id),
+                });
+            }
+        }
+
+        let traces = requirements
+            .iter()
+            .map(|requirement| RequirementTrace {
+                requirement: requirement.clone(),
+                plan_sections: sections
+                    .iter()
+                    .filter(|s| s.requirements.contains(&requirement.id))
+                    .map(|s| s.heading.clone())
+                    .collect(),
+                tasks: task_entries
+                    .iter()
+                    .filter(|t| t.requirements.contains(&requirement.id))
+                    .map(|t| t.id.clone())
+                    .collect(),
+            })
+            .collect();
+
+        let orphan_tasks = task_entries
+            .into_iter()
+            .filter(|t| t.requirements.is_empty())
+            .collect();
+
+        Self {
+            requirements: traces,
+            orphan_tasks,
+            dangling_references,
+        }
+    }
+
+    /// Requirements that no task covers
+    pub fn uncovered(&self) -> Vec<&RequirementTrace> {
+        self.requirements
+            .iter()
+            .filter(|r| !r.is_covered())
+            .collect()
+    }
+
+    /// Render the matrix in the requested format
+    pub fn render(&self, format: TraceFormat) -> String {
+        match format {
+            TraceFormat::Markdown => self.to_markdown(),
+            TraceFormat::Csv => self.to_csv(),
+            TraceFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Render as markdown
+    pub fn to_markdown(&self) -> String {
+        let mut report = String::from("# Requirement Traceability Matrix\n\n");
+
+        report.push_str("| Requirement | Description | Plan Sections | Tasks | Status |\n");
+        report.push_str("|-------------|-------------|---------------|-------|--------|\n");
+        for trace in &self.requirements {
+            report.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                trace.requirement.id,
+                escape_table_cell(&trace.requirement.text),
+                join_or_dash(&trace.plan_sections),
+                join_or_dash(&trace.tasks),
+                if trace.is_covered() {
+                    "✓ Covered"
+                } else {
+                    "✗ Uncovered"
+                }
+            ));
+        }
+
+        let uncovered = self.uncovered();
+        report.push_str(&format!(
+            "\n## Uncovered Requirements ({})\n\n",
+            uncovered.len()
+        ));
+        if uncovered.is_empty() {
+            report.push_str("✓ Every requirement is covered by at least one task\n");
+        } else {
+            for trace in uncovered {
+                report.push_str(&format!(
+                    "- {} (spec line {}): {}\n",
+                    trace.requirement.id, trace.requirement.line, trace.requirement.text
+                ));
+            }
+        }
+
+        report.push_str(&format!(
+            "\n## Orphan Tasks ({})\n\n",
+            self.orphan_tasks.len()
+        ));
+        if self.orphan_tasks.is_empty() {
+            report.push_str("✓ Every task references a requirement\n");
+        } else {
+            for task in &self.orphan_tasks {
+                report.push_str(&format!(
+                    "- {} (tasks line {}): {}\n",
+                    task.id, task.line, task.text
+                ));
+            }
+        }
+
+        if !self.dangling_references.is_empty() {
+            report.push_str(&format!(
+                "\n## Undefined Requirement References ({})\n\n",
+                self.dangling_references.len()
+            ));
+            for reference in &self.dangling_references {
+                report.push_str(&format!("- {} at {}\n", reference.id, reference.location));
+            }
+        }
+
+        report
+    }
+
+    /// Render as CSV
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("requirement,description,plan_sections,tasks,covered\n");
+        for trace in &self.requirements {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                trace.requirement.id,
+                escape_csv_field(&trace.requirement.text),
+                escape_csv_field(&trace.plan_sections.join("; ")),
+                escape_csv_field(&trace.tasks.join("; ")),
+                trace.is_covered()
+            ));
+        }
+        csv
+    }
+
+    /// Render as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        let uncovered: Vec<_> = self.uncovered().iter().map(|t| t.requirement.id).collect();
+
+        let value = serde_json::json!({
+            "requirements": self.requirements,
+            "uncovered_requirements": uncovered,
+            "orphan_tasks": self.orphan_tasks,
+            "dangling_references": self.dangling_references,
+        });
+
+        serde_json::to_string_pretty(&value).unwrap_or_default()
+    }
+}
+
+/// Split a plan into sections by heading and collect their requirement references
+pub fn parse_plan_sections(plan: &str) -> Vec<PlanSection> {
+    let document = MarkdownDocument::parse(plan);
+    let mut headings = document.sections().iter().peekable();
+    let mut sections: Vec<PlanSection> = Vec::new();
+
+    for (line, text) in document.prose_lines() {
+        if let Some(heading) = headings.next_if(|h| h.line == line) {
+            sections.push(PlanSection {
+                heading: heading.heading.clone(),
+                line,
+                requirements: find_requirement_ids(&heading.heading),
+            });
+            continue;
+        }
+
+        let Some(section) = sections.last_mut() else {
+            continue;
+        };
+        for id in find_requirement_ids(text) {
+            if !section.requirements.contains(&id) {
+                section.requirements.push(id);
+            }
+        }
+    }
+
+    sections.retain(|s| !s.requirements.is_empty());
+    sections
+}
+
+/// Parse task entries from a task list
+///
+/// Tasks are list items, optionally with a checkbox, such as
+/// `- [ ] T001 [P] Create user model (FR-001)`, or prose lines starting with
+/// a task ID.
+pub fn parse_tasks(tasks: &str) -> Vec<TaskEntry> {
+    let document = MarkdownDocument::parse(tasks);
+    document
+        .prose_lines()
+        .into_iter()
+        .filter_map(|(line, text)| {
+            let body = match document.item_at(line) {
+                Some(item) => item.text.as_str(),
+                None if starts_with_task_id(text.trim()) => text.trim(),
+                None => return None,
+            };
+            if body.is_empty() {
+                return None;
+            }
+
+            let (id, text) = if starts_with_task_id(body) {
+                let end = body.find(char::is_whitespace).unwrap_or(body.len());
+                (body[..end].to_string(), body[end..].trim())
+            } else {
+                (format!("L{}", line), body)
+            };
+
+            Some(TaskEntry {
+                id,
+                text: text.to_string(),
+                line,
+                requirements: find_requirement_ids(text),
+            })
+        })
+        .collect()
+}
+
+fn starts_with_task_id(text: &str) -> bool {
+    let Some(rest) = text.strip_prefix('T') else {
+        return false;
+    };
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0
+        && rest[digits..]
+            .chars()
+            .next()
+            .map_or(true, char::is_whitespace)
+}
+
+fn join_or_dash(items: &[String]) -> String {
+    if items.is_empty() {
+        "—".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn escape_csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "# Spec\n\n## Requirements\n\n\
+        - **FR-001**: Users MUST log in\n\
+        - **FR-002**: Users MUST reset passwords\n\
+        - **NFR-001**: Login MUST respond within 200ms\n";
+
+    const PLAN: &str = "# Plan\n\n## Authentication (FR-001, NFR-001)\n\nUse OAuth2.\n\n\
+        ## Storage\n\nSessions satisfy FR-001 and FR-009.\n\n## Deployment\n\nDocker.\n";
+
+    const TASKS: &str = "# Tasks\n\n\
+        - [ ] T001 Set up project skeleton\n\
+        - [ ] T002 [P] Implement login endpoint (FR-001)\n\
+        - [x] T003 Add latency benchmark for NFR-001\n";
+
+    #[test]
+    fn test_parse_plan_sections() {
+        let sections = parse_plan_sections(PLAN);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].heading, "Authentication (FR-001, NFR-001)");
+        assert_eq!(sections[1].heading, "Storage");
+        assert_eq!(sections[1].requirements.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_tasks() {
+        let tasks = parse_tasks(TASKS);
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].id, "T001");
+        assert!(tasks[0].requirements.is_empty());
+        assert_eq!(tasks[1].text, "[P] Implement login endpoint (FR-001)");
+        assert_eq!(tasks[2].requirements[0].to_string(), "NFR-001");
+    }
+
+    #[test]
+    fn test_build_matrix() {
+        let matrix = TraceabilityMatrix::build(SPEC, Some(PLAN), Some(TASKS));
+
+        assert_eq!(matrix.requirements.len(), 3);
+        assert_eq!(matrix.requirements[0].plan_sections.len(), 2);
+        assert_eq!(matrix.requirements[0].tasks, vec!["T002"]);
+
+        let uncovered = matrix.uncovered();
+        assert_eq!(uncovered.len(), 1);
+        assert_eq!(uncovered[0].requirement.id.to_string(), "FR-002");
+
+        assert_eq!(matrix.orphan_tasks.len(), 1);
+        assert_eq!(matrix.orphan_tasks[0].id, "T001");
+
+        assert_eq!(matrix.dangling_references.len(), 1);
+        assert_eq!(matrix.dangling_references[0].id.to_string(), "FR-009");
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let matrix = TraceabilityMatrix::build(SPEC, Some(PLAN), Some(TASKS));
+
+        let markdown = matrix.render(TraceFormat::Markdown);
+        assert!(markdown.contains("| FR-002 | Users MUST reset passwords | — | — | ✗ Uncovered |"));
+        assert!(markdown.contains("## Orphan Tasks (1)"));
+
+        let csv = matrix.render(TraceFormat::Csv);
+        assert!(csv.starts_with("requirement,description,plan_sections,tasks,covered\n"));
+        assert!(csv.contains(
+            "FR-001,Users MUST log in,\"Authentication (FR-001, NFR-001); Storage\",T002,true"
+        ));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&matrix.render(TraceFormat::Json)).unwrap();
+        assert_eq!(json["uncovered_requirements"][0], "FR-002");
+        assert_eq!(json["orphan_tasks"][0]["id"], "T001");
+    }
+}
@@ -0,0 +1,420 @@
+//! Artifact Writer
+//!
+//! Shared write path for generated artifacts. Callers choose whether an
+//! existing file may be replaced or merged, can preview the result as a
+//! unified diff, and get a timestamped backup whenever existing content is
+//! replaced. Backups go to the project's `.specify/backups/`, which ignores
+//! itself so they stay out of commits. Writes are atomic and confined to the
+//! workspace roots.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::layout::SPECIFY_DIR;
+use super::markdown::MarkdownDocument;
+use crate::speckit::SpecKitError;
+use crate::utils::{write_atomic, PathPolicy};
+
+/// Directory under `.specify/` holding backups of replaced artifacts
+pub const BACKUPS_DIR: &str = "backups";
+
+/// How to treat an existing artifact
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteMode {
+    /// Fail if the file already exists
+    CreateOnly,
+
+    /// Replace the file, keeping a backup of the previous content
+    #[default]
+    Overwrite,
+
+    /// Replace `##` sections that also appear in the new content, keep the
+    /// others (including hand-written ones) and append new sections
+    Merge,
+}
+
+/// Options for writing an artifact
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriteOptions {
+    /// How to treat an existing file
+    pub mode: WriteMode,
+
+    /// Compute the result without touching the file
+    pub dry_run: bool,
+}
+
+/// What a write did (or would do, for a dry run)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteAction {
+    /// The file did not exist
+    Created,
+
+    /// Existing content was replaced
+    Overwritten,
+
+    /// New sections were merged into existing content
+    Merged,
+
+    /// The result is identical to the existing content
+    Unchanged,
+}
+
+impl fmt::Display for WriteAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Created => "created",
+            Self::Overwritten => "overwritten",
+            Self::Merged => "merged",
+            Self::Unchanged => "unchanged",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Result of writing an artifact
+#[derive(Debug, Clone)]
+pub struct WriteOutcome {
+    /// Artifact path, as given
+    pub path: PathBuf,
+
+    /// Absolute path the artifact was (or would be) written to, resolved
+    /// against the workspace's base directory
+    pub resolved: PathBuf,
+
+    /// What happened
+    pub action: WriteAction,
+
+    /// Whether this was a dry run (nothing was written)
+    pub dry_run: bool,
+
+    /// Final content of the artifact
+    pub content: String,
+
+    /// Unified diff from the previous content
+    pub diff: String,
+
+    /// Backup of the previous content, if one was made
+    pub backup: Option<PathBuf>,
+}
+
+impl WriteOutcome {
+    /// One-line description of the write
+    pub fn summary(&self) -> String {
+        let mut summary = if self.dry_run {
+            format!(
+                "Dry run: {} would be {} (nothing written)",
+                self.path.display(),
+                self.action
+            )
+        } else {
+            format!("{} {}", self.path.display(), self.action)
+        };
+        if let Some(backup) = &self.backup {
+            summary.push_str(&format!(" (backup: {})", backup.display()));
+        }
+        summary
+    }
+}
+
+/// Write an artifact according to the options
+pub async fn write_artifact(
+    paths: &PathPolicy,
+    path: &Path,
+    content: &str,
+    options: WriteOptions,
+) -> Result<WriteOutcome> {
+    let resolved = paths.check(path)?;
+    let existing = match tokio::fs::read_to_string(&resolved).await {
+        Ok(existing) => Some(existing),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(SpecKitError::IoError(e).into()),
+    };
+
+    let (action, content) = match (&existing, options.mode) {
+        (None, _) => (WriteAction::Created, content.to_string()),
+        (Some(_), WriteMode::CreateOnly) => {
+            return Err(SpecKitError::FileError(format!(
+                "{} already exists; use mode \"overwrite\" or \"merge\" to update it",
+                path.display()
+            ))
+            .into());
+        }
+        (Some(old), _) if old == content => (WriteAction::Unchanged, content.to_string()),
+        (Some(_), WriteMode::Overwrite) => (WriteAction::Overwritten, content.to_string()),
+        (Some(old), WriteMode::Merge) => {
+            let merged = merge_sections(old, content);
+            if &merged == old {
+                (WriteAction::Unchanged, merged)
+            } else {
+                (WriteAction::Merged, merged)
+            }
+        }
+    };
+
+    let old = existing.as_deref().unwrap_or("");
+    let diff = unified_diff(old, &content, &path.display().to_string());
+
+    let mut backup = None;
+    if !options.dry_run && action != WriteAction::Unchanged {
+        if let Some(old) = &existing {
+            let backup_path = paths.check(&backup_path(&resolved))?;
+            let ignore = backups_dir(&resolved).join(".gitignore");
+            if !ignore.exists() {
+                write_atomic(&ignore, "*\n").await?;
+            }
+            write_atomic(&backup_path, old).await?;
+            backup = Some(backup_path);
+        }
+        write_atomic(&resolved, &content).await?;
+    }
+
+    tracing::info!(
+        path = %path.display(),
+        action = %action,
+        dry_run = options.dry_run,
+        "Artifact write"
+    );
+
+    Ok(WriteOutcome {
+        path: path.to_path_buf(),
+        resolved,
+        action,
+        dry_run: options.dry_run,
+        content,
+        diff,
+        backup,
+    })
+}
+
+/// Unified diff between two versions of a file
+pub fn unified_diff(old: &str, new: &str, name: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", name), &format!("b/{}", name))
+        .to_string()
+}
+
+/// Directory holding backups of an artifact: `.specify/backups/` in the
+/// nearest directory with `.specify/`, or next to the file when there is none
+fn backups_dir(path: &Path) -> PathBuf {
+    let dir = path.parent().unwrap_or(path);
+    let project = dir
+        .ancestors()
+        .find(|dir| dir.join(SPECIFY_DIR).is_dir())
+        .unwrap_or(dir);
+    project.join(SPECIFY_DIR).join(BACKUPS_DIR)
+}
+
+/// Timestamped backup path mirroring the artifact's place in the project
+/// (`.specify/backups/specs/001-login/spec.md.20261018-153000.bak`)
+fn backup_path(path: &Path) -> PathBuf {
+    let root = backups_dir(path);
+    let project = root.parent().and_then(Path::parent).unwrap_or(&root);
+    let relative = path.strip_prefix(project).unwrap_or(path);
+    let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+
+    let base = root.join(relative).display().to_string();
+    let mut candidate = PathBuf::from(format!("{}.{}.bak", base, stamp));
+    let mut n = 1;
+    while candidate.exists() {
+        candidate = PathBuf::from(format!("{}.{}-{}.bak", base, stamp, n));
+        n += 1;
+    }
+    candidate
+}
+
+/// A markdown document split at `##` headings
+struct SectionedDocument<'a> {
+    /// Text before the first `##` heading
+    preamble: &'a str,
+
+    /// `(heading, text including the heading line)` pairs
+    sections: Vec<(String, &'a str)>,
+}
+
+impl<'a> SectionedDocument<'a> {
+    fn parse(content: &'a str) -> Self {
+        let document = MarkdownDocument::parse(content);
+        let starts: Vec<(usize, String)> = document
+            .sections()
+            .iter()
+            .filter(|section| section.level == 2)
+            .map(|section| {
+                (
+                    document.line_offset(section.line),
+                    section.heading.to_lowercase(),
+                )
+            })
+            .collect();
+
+        let preamble_end = starts
+            .first()
+            .map(|(start, _)| *start)
+            .unwrap_or(content.len());
+        let sections = starts
+            .iter()
+            .enumerate()
+            .map(|(i, (start, heading))| {
+                let end = starts.get(i + 1).map(|(s, _)| *s).unwrap_or(content.len());
+                (heading.clone(), &content[*start..end])
+            })
+            .collect();
+
+        Self {
+            preamble: &content[..preamble_end],
+            sections,
+        }
+    }
+}
+
+/// Merge new sections into existing content
+///
+/// Sections are matched by heading (case-insensitive). Existing sections
+/// keep their position; new sections are appended in their original order.
+pub fn merge_sections(existing: &str, new: &str) -> String {
+    let old = SectionedDocument::parse(existing);
+    let new = SectionedDocument::parse(new);
+
+    let mut merged = if old.preamble.trim().is_empty() {
+        new.preamble.to_string()
+    } else {
+        old.preamble.to_string()
+    };
+
+    let mut push = |text: &str| {
+        if !merged.is_empty() && !merged.ends_with('\n') {
+            merged.push('\n');
+        }
+        if !merged.is_empty() && !merged.ends_with("\n\n") {
+            merged.push('\n');
+        }
+        merged.push_str(text);
+    };
+
+    for (heading, text) in &old.sections {
+        match new.sections.iter().find(|(h, _)| h == heading) {
+            Some((_, replacement)) => push(replacement),
+            None => push(text),
+        }
+    }
+    for (heading, text) in &new.sections {
+        if !old.sections.iter().any(|(h, _)| h == heading) {
+            push(text);
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_merge_sections() {
+        let existing = "# Spec\n\n## Requirements\n\n- old\n\n## Notes\n\nHand-written\n";
+        let new = "# Spec\n\n## Requirements\n\n- new\n\n## Risks\n\n- none\n";
+
+        let merged = merge_sections(existing, new);
+        assert_eq!(
+            merged,
+            "# Spec\n\n## Requirements\n\n- new\n\n## Notes\n\nHand-written\n\n## Risks\n\n- none\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_modes() {
+        let dir = tempdir().unwrap();
+        let paths = PathPolicy::unrestricted();
+        let path = dir.path().join("spec.md");
+
+        let created = write_artifact(&paths, &path, "## A\n\none\n", WriteOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(created.action, WriteAction::Created);
+        assert!(created.backup.is_none());
+
+        let create_only = WriteOptions {
+            mode: WriteMode::CreateOnly,
+            ..WriteOptions::default()
+        };
+        let error = write_artifact(&paths, &path, "## A\n\ntwo\n", create_only)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+
+        let overwritten = write_artifact(&paths, &path, "## A\n\ntwo\n", WriteOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(overwritten.action, WriteAction::Overwritten);
+        let backup = overwritten.backup.unwrap();
+        assert!(backup.starts_with(dir.path().canonicalize().unwrap().join(".specify/backups")));
+        assert_eq!(std::fs::read_to_string(backup).unwrap(), "## A\n\none\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "## A\n\ntwo\n");
+
+        let unchanged = write_artifact(&paths, &path, "## A\n\ntwo\n", WriteOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(unchanged.action, WriteAction::Unchanged);
+        assert!(unchanged.backup.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_backups_stay_out_of_feature_dirs() {
+        let dir = tempdir().unwrap();
+        let paths = PathPolicy::new([dir.path().to_path_buf()]);
+        let feature = dir.path().join("specs/001-login");
+        std::fs::create_dir_all(dir.path().join(".specify/memory")).unwrap();
+        std::fs::create_dir_all(&feature).unwrap();
+        let path = feature.join("spec.md");
+        std::fs::write(&path, "old").unwrap();
+
+        let outcome = write_artifact(&paths, &path, "new", WriteOptions::default())
+            .await
+            .unwrap();
+
+        let backups = dir.path().canonicalize().unwrap().join(".specify/backups");
+        let backup = outcome.backup.unwrap();
+        assert!(backup.starts_with(backups.join("specs/001-login")));
+        assert!(backup.to_string_lossy().ends_with(".bak"));
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "old");
+        assert_eq!(std::fs::read_dir(&feature).unwrap().count(), 1);
+        assert_eq!(
+            std::fs::read_to_string(backups.join(".gitignore")).unwrap(),
+            "*\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_returns_diff() {
+        let dir = tempdir().unwrap();
+        let paths = PathPolicy::unrestricted();
+        let path = dir.path().join("spec.md");
+        std::fs::write(&path, "## A\n\none\n").unwrap();
+
+        let outcome = write_artifact(
+            &paths,
+            &path,
+            "## A\n\ntwo\n",
+            WriteOptions {
+                mode: WriteMode::Overwrite,
+                dry_run: true,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(outcome.dry_run);
+        assert!(outcome.diff.contains("-one"));
+        assert!(outcome.diff.contains("+two"));
+        assert!(outcome.summary().contains("nothing written"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "## A\n\none\n");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -1,5 +1,624 @@
 //! Configuration Management
 //!
-//! Placeholder for future configuration features.
+//! Layered server configuration. Later layers override earlier ones:
+//!
+//! 1. Built-in defaults
+//! 2. User file (`~/.config/spec-kit-mcp/config.toml` on Linux)
+//! 3. Project file (`.speckit-mcp.toml` in the project directory), which
+//!    may not set the keys in [`PROJECT_PROTECTED_KEYS`]; the project
+//!    directory is `paths.project_dir` from the other layers, or the working
+//!    directory
+//! 4. `SPEC_KIT_*` environment variables (`__` separates sections, e.g.
+//!    `SPEC_KIT_CLI__TIMEOUT_SECONDS=60`)
+//! 5. Command-line arguments
+
+use anyhow::{Context, Result};
+use config::{Environment, File, FileFormat};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::artifacts::{ArtifactPaths, LintRule, LintSeverity};
+use crate::speckit::{DEFAULT_PROBE_TTL_SECONDS, SPEC_KIT_SOURCE};
+use crate::utils::PathPolicy;
+
+/// Project-level configuration file name
+pub const PROJECT_CONFIG_FILE: &str = ".speckit-mcp.toml";
+
+/// Settings the project file may not set, as (section, key)
+///
+/// They choose what the server executes and which directories it may touch,
+/// and a project file comes with a possibly untrusted checkout. They are
+/// ignored (with a warning) in the project layer and only honoured from the
+/// user file, the environment or the command line.
+pub const PROJECT_PROTECTED_KEYS: &[(&str, &str)] = &[
+    ("cli", "path"),
+    ("cli", "source"),
+    ("cli", "env"),
+    ("cli", "env_allow"),
+    ("paths", "project_dir"),
+    ("paths", "allowed_roots"),
+];
+
+/// Prefix of configuration environment variables
+pub const ENV_PREFIX: &str = "SPEC_KIT";
+
+/// Effective server configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Log level (trace, debug, info, warn, error)
+    pub log_level: String,
+
+    /// Spec-kit CLI invocation
+    pub cli: CliConfig,
+
+    /// Project directory, workspace roots and artifact locations
+    pub paths: PathsConfig,
+
+    /// Tool selection
+    pub tools: ToolsConfig,
+
+    /// MCP transport
+    pub transport: TransportConfig,
+
+    /// Artifact lint rule levels
+    pub lint: LintConfig,
+
+    /// Layers that contributed to this configuration, lowest precedence first
+    #[serde(skip)]
+    pub sources: Vec<String>,
+
+    /// Protected settings found in the project file and ignored, as
+    /// `section.key`
+    #[serde(skip)]
+    pub ignored: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            log_level: "info".to_string(),
+            cli: CliConfig::default(),
+            paths: PathsConfig::default(),
+            tools: ToolsConfig::default(),
+            transport: TransportConfig::default(),
+            lint: LintConfig::default(),
+            sources: vec!["defaults".to_string()],
+            ignored: Vec::new(),
+        }
+    }
+}
+
+/// How the spec-kit CLI is invoked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CliConfig {
+    /// Launcher command (`uvx` by default)
+    pub path: String,
+
+    /// Package source passed to `uvx --from`
+    pub source: String,
+
+    /// Timeout for spec-kit commands in seconds
+    pub timeout_seconds: u64,
+
+    /// How long a successful CLI probe is cached on disk, in seconds; `0`
+    /// disables the cache
+    pub probe_ttl_seconds: u64,
+
+    /// Extra environment variables passed through to spec-kit commands, on
+    /// top of the built-in allow-list; a trailing `*` matches a prefix
+    pub env_allow: Vec<String>,
+
+    /// Environment variables set for every spec-kit command, as
+    /// `KEY=VALUE` entries (a list rather than a table because configuration
+    /// keys are case-insensitive)
+    pub env: Vec<String>,
+}
+
+impl Default for CliConfig {
+    fn default() -> Self {
+        Self {
+            path: "uvx".to_string(),
+            source: SPEC_KIT_SOURCE.to_string(),
+            timeout_seconds: 300,
+            probe_ttl_seconds: DEFAULT_PROBE_TTL_SECONDS,
+            env_allow: Vec::new(),
+            env: Vec::new(),
+        }
+    }
+}
+
+/// Project directory, workspace roots and artifact locations
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PathsConfig {
+    /// Directory that relative tool paths (and the default `project_path`)
+    /// resolve against; the server's working directory when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_dir: Option<PathBuf>,
+
+    /// Directories tools may read and write; the project directory when
+    /// empty. Narrowed to the spec-kit projects under the client's roots
+    /// (or the roots themselves) that lie inside them, when it provides any.
+    pub allowed_roots: Vec<PathBuf>,
+
+    /// Where spec-kit projects keep their artifacts (see
+    /// [`crate::artifacts::ProjectLayout`])
+    pub artifacts: ArtifactPaths,
+}
+
+impl PathsConfig {
+    /// Workspace path policy for these settings
+    ///
+    /// Relative roots resolve against the current directory, so this should
+    /// be called after switching to `project_dir`.
+    pub fn path_policy(&self) -> PathPolicy {
+        let policy = if self.allowed_roots.is_empty() {
+            PathPolicy::current_dir()
+        } else {
+            PathPolicy::new(self.allowed_roots.iter().cloned())
+        };
+        policy.with_artifact_paths(self.artifacts.clone())
+    }
+}
+
+/// Which tools the server exposes
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolsConfig {
+    /// Tools to expose; all tools when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<Vec<String>>,
+
+    /// Tools to hide, applied after `enabled`
+    pub disabled: Vec<String>,
+}
+
+impl ToolsConfig {
+    /// Whether a tool should be registered
+    pub fn is_enabled(&self, name: &str) -> bool {
+        let listed = self
+            .enabled
+            .as_ref()
+            .map_or(true, |enabled| enabled.iter().any(|tool| tool == name));
+        listed && !self.disabled.iter().any(|tool| tool == name)
+    }
+}
+
+/// Artifact lint rule levels for `speckit_lint`
+///
+/// A rule listed in `deny` is an error, in `warn` a warning and in `allow`
+/// is not reported, in that order of precedence; unlisted rules keep their
+/// default severity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LintConfig {
+    /// Rules that are not reported
+    pub allow: Vec<LintRule>,
+
+    /// Rules reported as warnings
+    pub warn: Vec<LintRule>,
+
+    /// Rules reported as errors
+    pub deny: Vec<LintRule>,
+}
+
+impl LintConfig {
+    /// Effective severity of a rule, or `None` when it is allowed
+    pub fn severity(&self, rule: LintRule) -> Option<LintSeverity> {
+        if self.deny.contains(&rule) {
+            Some(LintSeverity::Error)
+        } else if self.warn.contains(&rule) {
+            Some(LintSeverity::Warning)
+        } else if self.allow.contains(&rule) {
+            None
+        } else {
+            Some(rule.default_severity())
+        }
+    }
+}
+
+/// Transport kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransportKind {
+    /// Newline-delimited JSON-RPC over stdin/stdout
+    #[default]
+    Stdio,
+}
+
+impl fmt::Display for TransportKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdio => f.write_str("stdio"),
+        }
+    }
+}
+
+/// MCP transport settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransportConfig {
+    /// Transport kind
+    pub kind: TransportKind,
+}
+
+/// Settings given on the command line, applied last
+#[derive(Debug, Clone, Default)]
+pub struct CliOverrides {
+    /// `--log-level`
+    pub log_level: Option<String>,
+
+    /// `--cli-path`
+    pub cli_path: Option<String>,
+
+    /// `--timeout`
+    pub timeout_seconds: Option<u64>,
+
+    /// `--config`, replacing the user file
+    pub config_file: Option<PathBuf>,
+
+    /// `--project-dir`
+    pub project_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Load the configuration from all layers
+    ///
+    /// `working_dir` is the project directory unless another layer sets
+    /// `paths.project_dir`, and relative project directories resolve
+    /// against it.
+    pub fn load(working_dir: &Path, overrides: &CliOverrides) -> Result<Self> {
+        let user_file = overrides.config_file.clone().or_else(user_config_file);
+        Self::load_from(user_file.as_deref(), working_dir, None, overrides)
+    }
+
+    /// Load the configuration from explicit layers
+    ///
+    /// The project directory is resolved from the user file, the
+    /// environment and the overrides first, then its project file is layered
+    /// in. `env` replaces the process environment when given (used in tests).
+    pub fn load_from(
+        user_file: Option<&Path>,
+        working_dir: &Path,
+        env: Option<config::Map<String, String>>,
+        overrides: &CliOverrides,
+    ) -> Result<Self> {
+        let config = Self::load_layers(user_file, None, env.clone(), overrides)?;
+        let project_dir = match &config.paths.project_dir {
+            Some(project_dir) => working_dir.join(project_dir),
+            None => working_dir.to_path_buf(),
+        };
+
+        let project_file = project_dir.join(PROJECT_CONFIG_FILE);
+        if !project_file.is_file() {
+            return Ok(config);
+        }
+        Self::load_layers(user_file, Some(&project_file), env, overrides)
+    }
+
+    /// Build the configuration from the user file, an optional project file,
+    /// the environment and the overrides
+    fn load_layers(
+        user_file: Option<&Path>,
+        project_file: Option<&Path>,
+        env: Option<config::Map<String, String>>,
+        overrides: &CliOverrides,
+    ) -> Result<Self> {
+        let mut sources = vec!["defaults".to_string()];
+        let mut ignored = Vec::new();
+        let mut builder = config::Config::builder();
+
+        if let Some(path) = user_file.filter(|path| path.is_file()) {
+            builder = builder.add_source(File::from(path).format(FileFormat::Toml));
+            sources.push(path.display().to_string());
+        }
+
+        if let Some(project_file) = project_file {
+            let text = std::fs::read_to_string(project_file)
+                .with_context(|| format!("Failed to read {}", project_file.display()))?;
+            let (text, protected) = without_protected_keys(&text)
+                .with_context(|| format!("Invalid configuration in {}", project_file.display()))?;
+            builder = builder.add_source(File::from_str(&text, FileFormat::Toml));
+            sources.push(project_file.display().to_string());
+            ignored = protected;
+        }
+
+        let environment = Environment::with_prefix(ENV_PREFIX)
+            .prefix_separator("_")
+            .separator("__")
+            .try_parsing(true)
+            .list_separator(",")
+            .with_list_parse_key("cli.env_allow")
+            .with_list_parse_key("cli.env")
+            .with_list_parse_key("tools.enabled")
+            .with_list_parse_key("tools.disabled")
+            .with_list_parse_key("paths.allowed_roots")
+            .with_list_parse_key("lint.allow")
+            .with_list_parse_key("lint.warn")
+            .with_list_parse_key("lint.deny")
+            .source(env);
+        builder = builder.add_source(environment);
+
+        let mut config: Self = builder
+            .build()
+            .context("Failed to load configuration")?
+            .try_deserialize()
+            .context("Invalid configuration")?;
+        sources.push(format!("{}_* environment", ENV_PREFIX));
+
+        if config.apply_overrides(overrides) {
+            sources.push("command line".to_string());
+        }
+        config.sources = sources;
+        config.ignored = ignored;
+
+        Ok(config)
+    }
+
+    /// Apply command-line settings, returning whether any were set
+    fn apply_overrides(&mut self, overrides: &CliOverrides) -> bool {
+        let mut applied = false;
+        if let Some(level) = &overrides.log_level {
+            self.log_level = level.clone();
+            applied = true;
+        }
+        if let Some(path) = &overrides.cli_path {
+            self.cli.path = path.clone();
+            applied = true;
+        }
+        if let Some(timeout) = overrides.timeout_seconds {
+            self.cli.timeout_seconds = timeout;
+            applied = true;
+        }
+        if let Some(project_dir) = &overrides.project_dir {
+            self.paths.project_dir = Some(project_dir.clone());
+            applied = true;
+        }
+        applied
+    }
+
+    /// Render the configuration as TOML
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Remove the protected keys from a project file, returning the remaining
+/// TOML and the removed keys
+fn without_protected_keys(text: &str) -> Result<(String, Vec<String>)> {
+    let mut table: toml::Table = text.parse()?;
+    let mut removed = Vec::new();
+
+    for (section, values) in table.iter_mut() {
+        let toml::Value::Table(values) = values else {
+            continue;
+        };
+        values.retain(|key, _| {
+            let protected = PROJECT_PROTECTED_KEYS
+                .iter()
+                .any(|(s, k)| section.eq_ignore_ascii_case(s) && key.eq_ignore_ascii_case(k));
+            if protected {
+                removed.push(format!("{}.{}", section, key));
+            }
+            !protected
+        });
+    }
+
+    Ok((toml::to_string(&table)?, removed))
+}
+
+/// User-level configuration file (`<config dir>/spec-kit-mcp/config.toml`)
+pub fn user_config_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("spec-kit-mcp").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn env(vars: &[(&str, &str)]) -> Option<config::Map<String, String>> {
+        Some(
+            vars.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_defaults() {
+        let dir = tempdir().unwrap();
+        let config =
+            Config::load_from(None, dir.path(), env(&[]), &CliOverrides::default()).unwrap();
+
+        assert_eq!(config.log_level, "info");
+        assert_eq!(config.cli.path, "uvx");
+        assert_eq!(config.cli.timeout_seconds, 300);
+        assert_eq!(config.transport.kind, TransportKind::Stdio);
+        assert!(config.tools.is_enabled("speckit_plan"));
+    }
+
+    #[test]
+    fn test_layer_precedence() {
+        let dir = tempdir().unwrap();
+        let user_file = dir.path().join("user.toml");
+        std::fs::write(
+            &user_file,
+            "log_level = \"debug\"\n[cli]\ntimeout_seconds = 10\npath = \"/opt/uvx\"\n\
+             env = [\"GH_HOST=github.example.com\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(PROJECT_CONFIG_FILE),
+            "[cli]\ntimeout_seconds = 20\n\
+             [tools]\ndisabled = [\"speckit_implement\"]\n\
+             [lint]\nallow = [\"task_file_path\"]\n",
+        )
+        .unwrap();
+
+        let config = Config::load_from(
+            Some(&user_file),
+            dir.path(),
+            env(&[
+                ("SPEC_KIT_LOG_LEVEL", "warn"),
+                ("SPEC_KIT_CLI__TIMEOUT_SECONDS", "30"),
+                ("SPEC_KIT_TOOLS__ENABLED", "speckit_plan,speckit_implement"),
+                ("SPEC_KIT_CLI__ENV_ALLOW", "CORP_*,JAVA_HOME"),
+                ("SPEC_KIT_LINT__DENY", "acceptance_format,plan_data_model"),
+                ("OTHER_VAR", "ignored"),
+            ]),
+            &CliOverrides {
+                timeout_seconds: Some(40),
+                ..CliOverrides::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(config.log_level, "warn");
+        assert_eq!(config.cli.path, "/opt/uvx");
+        assert_eq!(config.cli.timeout_seconds, 40);
+        assert_eq!(config.cli.env_allow, ["CORP_*", "JAVA_HOME"]);
+        assert_eq!(config.cli.env, ["GH_HOST=github.example.com"]);
+        assert_eq!(config.lint.severity(LintRule::TaskFilePath), None);
+        assert_eq!(
+            config.lint.severity(LintRule::PlanDataModel),
+            Some(LintSeverity::Error)
+        );
+        assert_eq!(
+            config.lint.severity(LintRule::NeedsClarification),
+            Some(LintSeverity::Error)
+        );
+        assert!(config.tools.is_enabled("speckit_plan"));
+        assert!(!config.tools.is_enabled("speckit_implement"));
+        assert!(!config.tools.is_enabled("speckit_tasks"));
+        assert_eq!(config.sources.len(), 5);
+    }
+
+    #[test]
+    fn test_project_file_cannot_set_protected_keys() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(PROJECT_CONFIG_FILE),
+            "[cli]\npath = \"./evil.sh\"\nSource = \"git+https://evil.example/x\"\n\
+             env = [\"PATH=.\"]\nenv_allow = [\"*\"]\ntimeout_seconds = 20\n\
+             [paths]\nallowed_roots = [\"/\"]\nproject_dir = \"/\"\n",
+        )
+        .unwrap();
+
+        let config =
+            Config::load_from(None, dir.path(), env(&[]), &CliOverrides::default()).unwrap();
+        assert_eq!(
+            config.cli,
+            CliConfig {
+                timeout_seconds: 20,
+                ..CliConfig::default()
+            }
+        );
+        assert_eq!(config.paths, PathsConfig::default());
+        assert_eq!(
+            config.ignored,
+            [
+                "cli.Source",
+                "cli.env",
+                "cli.env_allow",
+                "cli.path",
+                "paths.allowed_roots",
+                "paths.project_dir"
+            ]
+        );
+
+        // The environment may still set them
+        let config = Config::load_from(
+            None,
+            dir.path(),
+            env(&[("SPEC_KIT_CLI__PATH", "/opt/uvx")]),
+            &CliOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.cli.path, "/opt/uvx");
+    }
+
+    #[test]
+    fn test_project_file_of_configured_project_dir() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("project");
+        std::fs::create_dir(&project).unwrap();
+        std::fs::write(
+            dir.path().join(PROJECT_CONFIG_FILE),
+            "[cli]\ntimeout_seconds = 10\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.join(PROJECT_CONFIG_FILE),
+            "[cli]\ntimeout_seconds = 20\n\
+             [paths.artifacts]\nconstitution = \"docs/constitution.md\"\nspec = \"requirements.md\"\n",
+        )
+        .unwrap();
+
+        let config = Config::load_from(
+            None,
+            dir.path(),
+            env(&[("SPEC_KIT_PATHS__PROJECT_DIR", "project")]),
+            &CliOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.cli.timeout_seconds, 20);
+        assert_eq!(
+            config.paths.artifacts,
+            ArtifactPaths {
+                constitution: PathBuf::from("docs/constitution.md"),
+                spec: PathBuf::from("requirements.md"),
+                ..ArtifactPaths::default()
+            }
+        );
+        assert!(config
+            .sources
+            .contains(&project.join(PROJECT_CONFIG_FILE).display().to_string()));
+
+        // The command line picks the project too
+        let config = Config::load_from(
+            None,
+            dir.path(),
+            env(&[]),
+            &CliOverrides {
+                project_dir: Some(project.clone()),
+                ..CliOverrides::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(config.cli.timeout_seconds, 20);
+        assert_eq!(config.paths.project_dir, Some(project));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(PROJECT_CONFIG_FILE),
+            "[transport]\nkind = \"carrier-pigeon\"\n",
+        )
+        .unwrap();
+
+        let result = Config::load_from(None, dir.path(), env(&[]), &CliOverrides::default());
+        assert!(result.is_err());
+
+        let result = Config::load_from(
+            None,
+            tempdir().unwrap().path(),
+            env(&[("SPEC_KIT_LINT__ALLOW", "no_such_rule")]),
+            &CliOverrides::default(),
+        );
+        assert!(result.is_err());
+    }
 
-// Configuration will be added in future versions
+    #[test]
+    fn test_to_toml() {
+        let toml = Config::default().to_toml();
+        assert!(toml.contains("log_level = \"info\""));
+        assert!(toml.contains("[cli]"));
+        assert!(toml.contains("kind = \"stdio\""));
+    }
+}
@@ -5,7 +5,7 @@
 //!
 //! # Features
 //!
-//! - **9 Spec-Kit Tools**: Complete workflow from constitution to implementation
+//! - **15 Spec-Kit Tools**: Complete workflow from constitution to implementation
 //! - **MCP Protocol**: Full JSON-RPC 2.0 implementation
 //! - **Async/Await**: Built on Tokio for high performance
 //! - **Type Safety**: Comprehensive type system with validation
@@ -56,6 +56,13 @@
 //! 7. `speckit_clarify` - Clarify ambiguous requirements
 //! 8. `speckit_analyze` - Analyze cross-artifact consistency
 //! 9. `speckit_checklist` - Generate validation checklist
+//! 10. `speckit_check` - Validate required tool installations
+//! 11. `speckit_trace` - Trace requirement IDs through plan and tasks
+//! 12. `speckit_feature_create` - Create the next numbered feature directory
+//! 13. `speckit_feature_list` - List features and their artifact completeness
+//! 14. `speckit_feature_switch` - Set the active feature
+//! 15. `speckit_compliance` - Check plan and tasks against constitution principles
+//! 16. `speckit_lint` - Lint artifacts against configurable rules and apply safe fixes
 //!
 //! # Example Usage
 //!
@@ -78,6 +85,7 @@
 //! }
 //! ```
 
+pub mod artifacts;
 pub mod config;
 pub mod mcp;
 pub mod speckit;
@@ -85,6 +93,7 @@ pub mod tools;
 pub mod utils;
 
 // Re-export main types
+pub use config::Config;
 pub use mcp::{McpServer, ProtocolHandler, StdioTransport};
 pub use speckit::{SpecKitCli, SpecKitError};
-pub use tools::{create_registry, Tool, ToolRegistry};
+pub use tools::{create_registry, create_registry_with_config, Tool, ToolRegistry};
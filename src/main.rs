@@ -1,29 +1,119 @@
 //! Spec-Kit MCP Server Binary
 //!
-//! Main entry point for the MCP server.
+//! Main entry point for the MCP server. Without a subcommand the binary
+//! serves MCP over stdio; `tools list` and `call` run tools directly for
+//! scripting and debugging.
 
-use anyhow::Result;
-use clap::Parser;
-use spec_kit_mcp::{create_registry, McpServer, SpecKitCli};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{Map, Value};
+use spec_kit_mcp::config::{CliOverrides, Config, PROJECT_CONFIG_FILE};
+use spec_kit_mcp::mcp::types::{ContentBlock, ToolResult};
+use spec_kit_mcp::{create_registry_with_config, McpServer, SpecKitCli, ToolRegistry};
+use std::io::Write;
+use std::path::PathBuf;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+/// Exit status when a tool fails or returns an error result
+const EXIT_TOOL_ERROR: i32 = 1;
+
+/// Exit status when a tool cannot be run (unknown tool, bad arguments)
+const EXIT_USAGE: i32 = 2;
+
 /// Spec-Kit MCP Server
 #[derive(Parser, Debug)]
 #[command(name = "spec-kit-mcp")]
 #[command(about = "MCP server for GitHub Spec-Kit", long_about = None)]
 #[command(version)]
 struct Args {
-    /// Log level (trace, debug, info, warn, error)
-    #[arg(short, long, default_value = "info")]
-    log_level: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Log level (trace, debug, info, warn, error) [default: info]
+    #[arg(short, long, global = true)]
+    log_level: Option<String>,
 
-    /// Path to spec-kit CLI (defaults to 'specify' in PATH)
-    #[arg(long)]
+    /// Launcher for the spec-kit CLI [default: uvx]
+    #[arg(long, global = true)]
     cli_path: Option<String>,
 
-    /// Timeout for spec-kit commands in seconds
-    #[arg(long, default_value = "300")]
-    timeout: u64,
+    /// Timeout for spec-kit commands in seconds [default: 300]
+    #[arg(long, global = true)]
+    timeout: Option<u64>,
+
+    /// Configuration file to use instead of the user-level config.toml
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Project directory tools work in; its .speckit-mcp.toml is loaded
+    /// [default: current directory]
+    #[arg(long, global = true)]
+    project_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Serve MCP over stdio (the default)
+    Serve,
+
+    /// Inspect the available tools
+    Tools {
+        #[command(subcommand)]
+        command: ToolsCommand,
+    },
+
+    /// Call a tool and print its result
+    Call {
+        /// Tool name, e.g. speckit_analyze
+        tool: String,
+
+        /// Tool argument; values that parse as JSON (numbers, booleans,
+        /// arrays, objects) are passed as JSON, anything else as a string
+        #[arg(long = "arg", value_name = "KEY=VALUE")]
+        args: Vec<String>,
+
+        /// Tool arguments as a JSON object, inline or from `@file` (`@-` for
+        /// stdin); `--arg` values override its keys
+        #[arg(long, value_name = "JSON|@FILE")]
+        json: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        output: OutputFormat,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ToolsCommand {
+    /// List the enabled tools
+    List {
+        /// Print the full tool definitions as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// How `call` prints the tool result
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Text content blocks
+    Text,
+
+    /// The tool result as JSON
+    Json,
+}
+
+impl Args {
+    /// Settings that override every configuration layer
+    fn overrides(&self) -> CliOverrides {
+        CliOverrides {
+            log_level: self.log_level.clone(),
+            cli_path: self.cli_path.clone(),
+            timeout_seconds: self.timeout,
+            config_file: self.config.clone(),
+            project_dir: self.project_dir.clone(),
+        }
+    }
 }
 
 #[tokio::main]
@@ -31,39 +121,68 @@ async fn main() -> Result<()> {
     // Parse arguments
     let args = Args::parse();
 
+    // Load layered configuration, including the configured project's file
+    let config = Config::load(&std::env::current_dir()?, &args.overrides())?;
+
     // Initialize logging
-    init_logging(&args.log_level)?;
+    init_logging(&config.log_level)?;
+    for key in &config.ignored {
+        tracing::warn!(
+            key = %key,
+            file = PROJECT_CONFIG_FILE,
+            "Ignoring setting the project file may not set; set it in the user config file, a SPEC_KIT_* variable or on the command line"
+        );
+    }
 
-    tracing::info!(
-        version = env!("CARGO_PKG_VERSION"),
-        "Starting spec-kit-mcp server"
-    );
+    if let Some(project_dir) = &config.paths.project_dir {
+        std::env::set_current_dir(project_dir)?;
+        tracing::info!(project_dir = %project_dir.display(), "Using configured project directory");
+    }
 
     // Create spec-kit CLI interface
-    let mut cli = SpecKitCli::new().with_timeout(args.timeout);
-
-    if let Some(cli_path) = args.cli_path {
-        cli = cli.with_cli_path(cli_path);
-    }
+    let cli = SpecKitCli::from_config(&config.cli).with_path_policy(config.paths.path_policy());
 
-    // Check if spec-kit is installed
-    if !cli.is_installed().await {
-        eprintln!("Error: spec-kit CLI not found!");
-        eprintln!("Please install it with: uv tool install specify-cli");
-        eprintln!("Or: pip install specify-cli");
-        std::process::exit(1);
+    match args.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(cli, &config).await,
+        Command::Tools {
+            command: ToolsCommand::List { json },
+        } => {
+            let registry = create_registry_with_config(cli, &config);
+            list_tools(&registry, json)
+        }
+        Command::Call {
+            tool,
+            args,
+            json,
+            output,
+        } => {
+            let registry = create_registry_with_config(cli, &config);
+            let code = call_tool(&registry, &tool, &args, json.as_deref(), output).await;
+            std::process::exit(code);
+        }
     }
+}
 
-    tracing::info!("Spec-kit CLI found and validated");
+/// Serve MCP over stdio
+async fn serve(cli: SpecKitCli, config: &Config) -> Result<()> {
+    tracing::info!(
+        version = env!("CARGO_PKG_VERSION"),
+        sources = ?config.sources,
+        "Starting spec-kit-mcp server"
+    );
 
     // Create tool registry
-    let registry = create_registry(cli);
+    let registry = create_registry_with_config(cli, config);
     tracing::info!(tool_count = registry.len(), "Tool registry initialized");
 
+    // Probe the CLI in the background so startup never waits on uvx; tools
+    // that need it are hidden from tools/list while it is missing
+    registry.refresh_cli();
+
     // Create and run server
     let mut server = McpServer::new(registry);
 
-    tracing::info!("MCP server ready, listening on stdio");
+    tracing::info!(transport = %config.transport.kind, "MCP server ready");
 
     // Run server
     server.run().await?;
@@ -71,6 +190,144 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Print the enabled tools, sorted by name
+fn list_tools(registry: &ToolRegistry, json: bool) -> Result<()> {
+    let mut tools = registry.list_tools();
+    tools.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if json {
+        print_stdout(&serde_json::to_string_pretty(&tools)?)
+    } else {
+        let width = tools.iter().map(|t| t.name.len()).max().unwrap_or(0);
+        let lines: Vec<String> = tools
+            .iter()
+            .map(|tool| format!("{:width$}  {}", tool.name, tool.description, width = width))
+            .collect();
+        print_stdout(&lines.join("\n"))
+    }
+}
+
+/// Run a tool and print its result, returning the exit status
+async fn call_tool(
+    registry: &ToolRegistry,
+    tool: &str,
+    args: &[String],
+    json: Option<&str>,
+    output: OutputFormat,
+) -> i32 {
+    if !registry.has_tool(tool) {
+        eprintln!("Error: Tool not found: {}", tool);
+        return EXIT_USAGE;
+    }
+
+    let schema = registry
+        .get(tool)
+        .map(|tool| tool.definition().input_schema)
+        .unwrap_or_default();
+    let arguments = match tool_arguments(args, json, &schema) {
+        Ok(arguments) => arguments,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            return EXIT_USAGE;
+        }
+    };
+
+    let result = match registry.call(tool, arguments).await {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            return EXIT_TOOL_ERROR;
+        }
+    };
+
+    let printed = match output {
+        OutputFormat::Text => print_stdout(&result_text(&result)),
+        OutputFormat::Json => serde_json::to_string_pretty(&result)
+            .map_err(Into::into)
+            .and_then(|json| print_stdout(&json)),
+    };
+    if let Err(e) = printed {
+        eprintln!("Error: {:#}", e);
+        return EXIT_TOOL_ERROR;
+    }
+
+    if result.is_error == Some(true) {
+        EXIT_TOOL_ERROR
+    } else {
+        0
+    }
+}
+
+/// Build the tool arguments from `--json` and `--arg` options
+///
+/// `--arg` values of `string` properties in the tool's input schema are kept
+/// as written; other values are parsed as JSON when they can be, so
+/// `max_questions=3` is a number and `domains=["api"]` an array.
+fn tool_arguments(args: &[String], json: Option<&str>, schema: &Value) -> Result<Value> {
+    let mut arguments = match json {
+        Some(json) => {
+            let text = match json.strip_prefix('@') {
+                Some("-") => std::io::read_to_string(std::io::stdin())
+                    .context("Failed to read arguments from stdin")?,
+                Some(path) => std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read arguments from {}", path))?,
+                None => json.to_string(),
+            };
+            match serde_json::from_str(&text).context("Invalid --json arguments")? {
+                Value::Object(map) => map,
+                _ => bail!("--json arguments must be a JSON object"),
+            }
+        }
+        None => Map::new(),
+    };
+
+    for arg in args {
+        let Some((key, value)) = arg.split_once('=') else {
+            bail!("Invalid --arg '{}': expected KEY=VALUE", arg);
+        };
+        let value = if is_string_property(schema, key) {
+            Value::String(value.to_string())
+        } else {
+            serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()))
+        };
+        arguments.insert(key.to_string(), value);
+    }
+
+    Ok(Value::Object(arguments))
+}
+
+/// Whether an input schema declares a property as a string
+fn is_string_property(schema: &Value, key: &str) -> bool {
+    match &schema["properties"][key]["type"] {
+        Value::String(kind) => kind == "string",
+        Value::Array(kinds) => kinds.iter().any(|kind| kind == "string"),
+        _ => false,
+    }
+}
+
+/// Text content of a tool result
+fn result_text(result: &ToolResult) -> String {
+    result
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::Text { text } => Some(text.as_str()),
+            ContentBlock::Resource { text, .. } => text.as_deref(),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Print a line to stdout; a closed pipe (e.g. `| head`) is not an error
+fn print_stdout(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    match writeln!(stdout, "{}", text).and_then(|_| stdout.flush()) {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
 /// Initialize logging
 fn init_logging(level: &str) -> Result<()> {
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
@@ -83,3 +340,90 @@ fn init_logging(level: &str) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use spec_kit_mcp::utils::PathPolicy;
+
+    #[test]
+    fn test_tool_arguments() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("args.json");
+        std::fs::write(&file, r#"{"project_path": "./a", "format": "json"}"#).unwrap();
+
+        let args = vec![
+            "project_path=.".to_string(),
+            "max_questions=3".to_string(),
+            "commit=true".to_string(),
+            "domains=[\"api\"]".to_string(),
+        ];
+        let schema = json!({
+            "properties": {
+                "project_path": { "type": "string" },
+                "max_questions": { "type": "integer" },
+                "commit": { "type": "boolean" },
+                "domains": { "type": "array" }
+            }
+        });
+        let value = tool_arguments(&args, Some(&format!("@{}", file.display())), &schema).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "project_path": ".",
+                "format": "json",
+                "max_questions": 3,
+                "commit": true,
+                "domains": ["api"]
+            })
+        );
+
+        assert!(tool_arguments(&["novalue".to_string()], None, &schema).is_err());
+        assert!(tool_arguments(&[], Some("[1, 2]"), &schema).is_err());
+    }
+
+    #[test]
+    fn test_tool_arguments_keep_numeric_strings() {
+        let schema = json!({ "properties": { "feature": { "type": "string" } } });
+        let args = vec!["feature=1".to_string(), "max_questions=3".to_string()];
+
+        // Unknown properties are still parsed as JSON
+        assert_eq!(
+            tool_arguments(&args, None, &schema).unwrap(),
+            json!({ "feature": "1", "max_questions": 3 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_exit_codes() {
+        let cli = SpecKitCli::new().with_path_policy(PathPolicy::unrestricted());
+        let registry = spec_kit_mcp::create_registry(cli);
+        let dir = tempfile::tempdir().unwrap();
+        let project = format!("project_path={}", dir.path().display());
+
+        let code = call_tool(
+            &registry,
+            "speckit_feature_list",
+            &[project],
+            None,
+            OutputFormat::Json,
+        )
+        .await;
+        assert_eq!(code, 0);
+
+        let missing = format!("spec_file={}", dir.path().join("missing.md").display());
+        let code = call_tool(
+            &registry,
+            "speckit_checklist",
+            &[missing],
+            None,
+            OutputFormat::Text,
+        )
+        .await;
+        assert_eq!(code, EXIT_TOOL_ERROR);
+
+        let code = call_tool(&registry, "speckit_missing", &[], None, OutputFormat::Text).await;
+        assert_eq!(code, EXIT_USAGE);
+    }
+}
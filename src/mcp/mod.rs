@@ -2,12 +2,16 @@
 //!
 //! This module provides the core MCP protocol implementation for the spec-kit MCP server.
 
+pub mod peer;
 pub mod protocol;
+pub mod roots;
 pub mod server;
 pub mod transport;
 pub mod types;
 
+pub use peer::*;
 pub use protocol::*;
+pub use roots::*;
 pub use server::*;
 pub use transport::*;
 pub use types::*;
@@ -0,0 +1,283 @@
+//! Client Peer
+//!
+//! Sends server-initiated requests and notifications to the client and
+//! correlates the client's responses with the requests waiting for them.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+use super::types::{
+    ClientCapabilities, CreateMessageParams, CreateMessageResult, ElicitResult,
+    JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, ListRootsResult, RequestId, Root,
+};
+
+/// How long a server-initiated request waits for the client by default.
+/// Elicitation waits on a person, so this is deliberately generous.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
+
+type PendingMap = HashMap<RequestId, oneshot::Sender<JsonRpcResponse>>;
+
+/// Handle for talking to the connected client
+///
+/// Clones share the same connection, pending requests and capabilities, so
+/// tools holding a clone see the client once the server has connected it.
+#[derive(Clone)]
+pub struct ClientPeer {
+    outgoing: Arc<Mutex<Option<mpsc::UnboundedSender<Value>>>>,
+    pending: Arc<Mutex<PendingMap>>,
+    capabilities: Arc<RwLock<ClientCapabilities>>,
+    next_id: Arc<AtomicI64>,
+    timeout: Duration,
+}
+
+impl ClientPeer {
+    /// Create a peer that is not yet connected to a client
+    pub fn new() -> Self {
+        Self {
+            outgoing: Arc::new(Mutex::new(None)),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            capabilities: Arc::new(RwLock::new(ClientCapabilities::default())),
+            next_id: Arc::new(AtomicI64::new(1)),
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+
+    /// Set how long requests wait for a response
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Connect the peer, returning the queue of messages to write to the client
+    pub fn connect(&self) -> mpsc::UnboundedReceiver<Value> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.outgoing.lock().unwrap() = Some(sender);
+        receiver
+    }
+
+    /// Disconnect the peer, failing every request still waiting for the client
+    pub fn disconnect(&self) {
+        self.outgoing.lock().unwrap().take();
+        self.fail_pending();
+    }
+
+    /// Fail every request still waiting for the client, keeping the
+    /// connection open for messages to it (the client's input has closed, so
+    /// no answer can arrive)
+    pub fn fail_pending(&self) {
+        self.pending.lock().unwrap().clear();
+    }
+
+    /// Whether the peer is connected to a client
+    pub fn is_connected(&self) -> bool {
+        self.outgoing.lock().unwrap().is_some()
+    }
+
+    /// Record the capabilities the client advertised in `initialize`
+    pub fn set_capabilities(&self, capabilities: ClientCapabilities) {
+        *self.capabilities.write().unwrap() = capabilities;
+    }
+
+    /// Capabilities the client advertised in `initialize`
+    pub fn capabilities(&self) -> ClientCapabilities {
+        self.capabilities.read().unwrap().clone()
+    }
+
+    /// Whether the connected client can answer `elicitation/create`
+    pub fn supports_elicitation(&self) -> bool {
+        self.is_connected() && self.capabilities.read().unwrap().elicitation.is_some()
+    }
+
+    /// Whether the connected client can answer `sampling/createMessage`
+    pub fn supports_sampling(&self) -> bool {
+        self.is_connected() && self.capabilities.read().unwrap().sampling.is_some()
+    }
+
+    /// Whether the connected client can answer `roots/list`
+    pub fn supports_roots(&self) -> bool {
+        self.is_connected() && self.capabilities.read().unwrap().roots.is_some()
+    }
+
+    /// Queue a message for the client
+    pub fn send(&self, message: Value) -> Result<()> {
+        let outgoing = self.outgoing.lock().unwrap();
+        let sender = outgoing
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No client is connected"))?;
+        sender
+            .send(message)
+            .map_err(|_| anyhow::anyhow!("Client connection is closed"))
+    }
+
+    /// Send a notification to the client
+    pub fn notify(&self, method: &str, params: Option<Value>) -> Result<()> {
+        let notification = JsonRpcNotification {
+            jsonrpc: "2.0".to_string(),
+            method: method.to_string(),
+            params,
+        };
+        self.send(serde_json::to_value(notification)?)
+    }
+
+    /// Send a request to the client and wait for its result
+    pub async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = RequestId::String(format!(
+            "speckit-{}",
+            self.next_id.fetch_add(1, Ordering::Relaxed)
+        ));
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: id.clone(),
+            method: method.to_string(),
+            params: Some(params),
+        };
+
+        let (sender, receiver) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id.clone(), sender);
+
+        tracing::debug!(method = %method, id = ?id, "Sending request to client");
+
+        if let Err(e) = self.send(serde_json::to_value(request)?) {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        let response = match tokio::time::timeout(self.timeout, receiver).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => anyhow::bail!("Client disconnected before answering {}", method),
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&id);
+                let _ = self.notify(
+                    "notifications/cancelled",
+                    Some(json!({ "requestId": id, "reason": "Timed out" })),
+                );
+                anyhow::bail!(
+                    "Client did not answer {} within {}s",
+                    method,
+                    self.timeout.as_secs()
+                );
+            }
+        };
+
+        if let Some(error) = response.error {
+            anyhow::bail!(
+                "Client returned error {} for {}: {}",
+                error.code,
+                method,
+                error.message
+            );
+        }
+        Ok(response.result.unwrap_or(Value::Null))
+    }
+
+    /// Deliver a response from the client to the request waiting for it.
+    /// Returns false when no request with that ID is pending.
+    pub fn handle_response(&self, response: JsonRpcResponse) -> bool {
+        let sender = self.pending.lock().unwrap().remove(&response.id);
+        match sender {
+            Some(sender) => {
+                let _ = sender.send(response);
+                true
+            }
+            None => {
+                tracing::warn!(id = ?response.id, "Response for unknown request");
+                false
+            }
+        }
+    }
+
+    /// Ask the user for input through `elicitation/create`
+    pub async fn elicit(&self, message: &str, requested_schema: Value) -> Result<ElicitResult> {
+        if !self.supports_elicitation() {
+            anyhow::bail!("Client does not support elicitation");
+        }
+
+        let result = self
+            .request(
+                "elicitation/create",
+                json!({
+                    "message": message,
+                    "requestedSchema": requested_schema
+                }),
+            )
+            .await?;
+
+        serde_json::from_value(result).context("Failed to parse elicitation result")
+    }
+
+    /// Ask the client's model for a message through `sampling/createMessage`
+    pub async fn create_message(&self, params: CreateMessageParams) -> Result<CreateMessageResult> {
+        if !self.supports_sampling() {
+            anyhow::bail!("Client does not support sampling");
+        }
+
+        let result = self
+            .request("sampling/createMessage", serde_json::to_value(params)?)
+            .await?;
+
+        serde_json::from_value(result).context("Failed to parse sampling result")
+    }
+
+    /// List the client's workspace roots through `roots/list`
+    pub async fn list_roots(&self) -> Result<Vec<Root>> {
+        if !self.supports_roots() {
+            anyhow::bail!("Client does not support roots");
+        }
+
+        let result = self.request("roots/list", json!({})).await?;
+        let result: ListRootsResult =
+            serde_json::from_value(result).context("Failed to parse roots")?;
+        Ok(result.roots)
+    }
+}
+
+impl Default for ClientPeer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_correlates_response() {
+        let peer = ClientPeer::new();
+        let mut outgoing = peer.connect();
+
+        let client = peer.clone();
+        let task = tokio::spawn(async move { client.request("roots/list", json!({})).await });
+
+        let sent = outgoing.recv().await.unwrap();
+        assert_eq!(sent["method"], "roots/list");
+        let id: RequestId = serde_json::from_value(sent["id"].clone()).unwrap();
+
+        assert!(!peer.handle_response(JsonRpcResponse::success(RequestId::Number(99), json!({}))));
+        assert!(peer.handle_response(JsonRpcResponse::success(id, json!({ "roots": [] }))));
+
+        let result = task.await.unwrap().unwrap();
+        assert_eq!(result, json!({ "roots": [] }));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_and_disconnect() {
+        let peer = ClientPeer::new().with_timeout(Duration::from_millis(10));
+        assert!(peer.request("ping", json!({})).await.is_err());
+
+        let mut outgoing = peer.connect();
+        let error = peer.request("ping", json!({})).await.unwrap_err();
+        assert!(error.to_string().contains("did not answer"));
+        outgoing.recv().await.unwrap();
+        let cancelled = outgoing.recv().await.unwrap();
+        assert_eq!(cancelled["method"], "notifications/cancelled");
+
+        let elicit = peer.elicit("Pick one", json!({})).await.unwrap_err();
+        assert!(elicit.to_string().contains("does not support elicitation"));
+    }
+}
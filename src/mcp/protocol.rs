@@ -7,6 +7,9 @@ use serde_json::{json, Value};
 
 use super::types::*;
 
+/// Protocol versions the server can speak, newest first
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
 /// Protocol handler for MCP messages
 pub struct ProtocolHandler;
 
@@ -70,20 +73,41 @@ impl ProtocolHandler {
         JsonRpcResponse::error(id, JsonRpcError::internal_error(error_msg))
     }
 
-    /// Handle initialization request
-    pub fn handle_initialize(&self, id: RequestId) -> JsonRpcResponse {
-        tracing::info!("Handling initialize request");
+    /// Parse initialization parameters, tolerating clients that send none
+    pub fn parse_initialize(&self, params: Option<Value>) -> Result<InitializeParams> {
+        match params {
+            Some(params) => {
+                serde_json::from_value(params).context("Failed to parse initialize parameters")
+            }
+            None => Ok(InitializeParams::default()),
+        }
+    }
+
+    /// Handle initialization request, answering with the client's protocol
+    /// version when supported and the newest supported version otherwise
+    pub fn handle_initialize(&self, id: RequestId, params: &InitializeParams) -> JsonRpcResponse {
+        tracing::info!(
+            protocol_version = ?params.protocol_version,
+            elicitation = params.capabilities.elicitation.is_some(),
+            "Handling initialize request"
+        );
+
+        let protocol_version = params
+            .protocol_version
+            .as_deref()
+            .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+            .unwrap_or(SUPPORTED_PROTOCOL_VERSIONS[0]);
 
         JsonRpcResponse::success(
             id,
             json!({
-                "protocolVersion": "2024-11-05",
+                "protocolVersion": protocol_version,
                 "serverInfo": {
                     "name": "spec-kit-mcp",
                     "version": env!("CARGO_PKG_VERSION")
                 },
                 "capabilities": {
-                    "tools": {}
+                    "tools": { "listChanged": true }
                 }
             }),
         )
@@ -147,13 +171,29 @@ mod tests {
     #[test]
     fn test_initialize_response() {
         let handler = ProtocolHandler::new();
-        let response = handler.handle_initialize(RequestId::Number(1));
+        let response = handler.handle_initialize(RequestId::Number(1), &Default::default());
 
         assert!(response.error.is_none());
         assert!(response.result.is_some());
 
         let result = response.result.unwrap();
         assert_eq!(result["serverInfo"]["name"], "spec-kit-mcp");
+        assert_eq!(result["protocolVersion"], SUPPORTED_PROTOCOL_VERSIONS[0]);
+    }
+
+    #[test]
+    fn test_initialize_negotiates_version() {
+        let handler = ProtocolHandler::new();
+        let params = handler
+            .parse_initialize(Some(json!({
+                "protocolVersion": "2024-11-05",
+                "capabilities": { "elicitation": {} }
+            })))
+            .unwrap();
+        assert!(params.capabilities.elicitation.is_some());
+
+        let response = handler.handle_initialize(RequestId::Number(1), &params);
+        assert_eq!(response.result.unwrap()["protocolVersion"], "2024-11-05");
     }
 
     #[test]
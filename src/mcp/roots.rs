@@ -0,0 +1,253 @@
+//! Client Workspace Roots
+//!
+//! Applies the client's workspace roots to the path policy. When
+//! `paths.allowed_roots` is set, only roots inside it are used, so a client
+//! can narrow the sandbox but never widen it; otherwise the client's roots
+//! replace the default of the server's working directory. The sandbox is narrowed to the
+//! spec-kit projects found under those roots (or to the roots themselves
+//! when there are none yet), and relative paths, including the default `.`
+//! project path, resolve against the first project.
+
+use anyhow::Result;
+use std::path::PathBuf;
+
+use super::peer::ClientPeer;
+use super::types::Root;
+use crate::artifacts::discover_projects;
+use crate::utils::PathPolicy;
+
+/// Workspace derived from the client's roots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    /// Local directories of the client's `file://` roots
+    pub roots: Vec<PathBuf>,
+
+    /// Spec-kit projects found under the roots
+    pub projects: Vec<PathBuf>,
+}
+
+impl Workspace {
+    /// Discover the spec-kit projects under the client's roots
+    pub fn from_roots(roots: &[Root]) -> Self {
+        let roots: Vec<PathBuf> = roots.iter().filter_map(Root::path).collect();
+        let projects = roots
+            .iter()
+            .flat_map(|root| discover_projects(root))
+            .collect();
+        Self { roots, projects }
+    }
+
+    /// Directories tools may access: the projects, or the roots when no
+    /// project exists yet
+    pub fn boundary(&self) -> &[PathBuf] {
+        if self.projects.is_empty() {
+            &self.roots
+        } else {
+            &self.projects
+        }
+    }
+
+    /// Directory relative paths resolve against
+    pub fn default_project(&self) -> Option<&PathBuf> {
+        self.boundary().first()
+    }
+
+    /// The part of the workspace inside a set of allowed roots
+    pub fn within(&self, allowed: &[PathBuf]) -> Self {
+        let inside = |path: &&PathBuf| {
+            let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            allowed.iter().any(|root| path.starts_with(root))
+        };
+        Self {
+            roots: self.roots.iter().filter(inside).cloned().collect(),
+            projects: self.projects.iter().filter(inside).cloned().collect(),
+        }
+    }
+
+    /// Apply the workspace to a path policy
+    ///
+    /// Roots outside the policy's explicitly configured roots are ignored.
+    /// When no root is left the configured workspace is restored and `false`
+    /// is returned.
+    pub fn apply(&self, paths: &PathPolicy) -> bool {
+        let workspace = match paths.bounding_roots() {
+            Some(allowed) => self.within(allowed),
+            None => self.clone(),
+        };
+        if workspace.roots.len() < self.roots.len() {
+            tracing::warn!(
+                roots = ?self.roots,
+                allowed = ?paths.bounding_roots(),
+                "Ignoring client roots outside the configured workspace roots"
+            );
+        }
+        if workspace.roots.is_empty() {
+            tracing::warn!(
+                "No usable file:// roots from the client; keeping the configured workspace"
+            );
+            if let Some(configured) = paths.configured_roots() {
+                paths.set_roots(configured.to_vec());
+            }
+            paths.set_base_dir(None);
+            return false;
+        }
+
+        paths.set_roots(workspace.boundary().iter().cloned());
+        paths.set_base_dir(workspace.default_project().cloned());
+        tracing::info!(
+            roots = ?workspace.roots,
+            projects = ?workspace.projects,
+            "Workspace updated from client roots"
+        );
+        true
+    }
+}
+
+/// Fetch the client's roots with `roots/list` and apply them to the policy
+pub async fn sync_roots(client: &ClientPeer, paths: &PathPolicy) -> Result<Workspace> {
+    let roots = client.list_roots().await?;
+    let workspace = Workspace::from_roots(&roots);
+    workspace.apply(paths);
+    Ok(workspace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::types::{ClientCapabilities, JsonRpcResponse};
+    use serde_json::json;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn file_uri(path: &Path) -> String {
+        format!("file://{}", path.display())
+    }
+
+    #[tokio::test]
+    async fn test_sync_roots_detects_projects() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(root.join("service/.specify")).unwrap();
+        std::fs::create_dir_all(root.join("other")).unwrap();
+
+        let client = ClientPeer::new();
+        let mut outgoing = client.connect();
+        client.set_capabilities(ClientCapabilities {
+            roots: Some(json!({ "listChanged": true })),
+            ..Default::default()
+        });
+
+        let responder = client.clone();
+        let uri = file_uri(&root);
+        tokio::spawn(async move {
+            let request = outgoing.recv().await.unwrap();
+            assert_eq!(request["method"], "roots/list");
+            let id = serde_json::from_value(request["id"].clone()).unwrap();
+            responder.handle_response(JsonRpcResponse::success(
+                id,
+                json!({ "roots": [{ "uri": uri, "name": "workspace" }] }),
+            ));
+        });
+
+        let paths = PathPolicy::unrestricted();
+        let workspace = sync_roots(&client, &paths).await.unwrap();
+
+        assert_eq!(workspace.projects, vec![root.join("service")]);
+        assert_eq!(paths.roots(), Some(vec![root.join("service")]));
+        assert_eq!(paths.check(Path::new(".")).unwrap(), root.join("service"));
+        assert!(paths.check(&root.join("other/file.md")).is_err());
+    }
+
+    #[test]
+    fn test_workspace_without_projects_uses_roots() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let workspace = Workspace::from_roots(&[
+            Root {
+                uri: file_uri(&root),
+                name: None,
+            },
+            Root {
+                uri: "https://example.com".to_string(),
+                name: None,
+            },
+        ]);
+        assert_eq!(workspace.boundary().to_vec(), vec![root.clone()]);
+
+        let paths = PathPolicy::unrestricted();
+        assert!(workspace.apply(&paths));
+        assert_eq!(paths.base_dir(), Some(root));
+
+        let empty = Workspace::from_roots(&[]);
+        assert!(!empty.apply(&paths));
+    }
+
+    #[test]
+    fn test_client_roots_cannot_widen_configured_roots() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        let configured = base.join("configured");
+        let outside = base.join("outside");
+        std::fs::create_dir_all(configured.join("app/.specify")).unwrap();
+        std::fs::create_dir_all(outside.join("evil/.specify")).unwrap();
+        let paths = PathPolicy::new([configured.clone()]);
+
+        // A root outside the configured ones is rejected
+        let workspace = Workspace::from_roots(&[Root {
+            uri: file_uri(&outside),
+            name: None,
+        }]);
+        assert!(!workspace.apply(&paths));
+        assert_eq!(paths.roots(), Some(vec![configured.clone()]));
+        assert!(paths.check(&outside.join("evil/spec.md")).is_err());
+
+        // Roots inside narrow the sandbox; outside ones are dropped
+        let workspace = Workspace::from_roots(&[
+            Root {
+                uri: file_uri(&outside),
+                name: None,
+            },
+            Root {
+                uri: file_uri(&configured),
+                name: None,
+            },
+        ]);
+        assert!(workspace.apply(&paths));
+        assert_eq!(paths.roots(), Some(vec![configured.join("app")]));
+        assert!(paths.check(&outside.join("evil/spec.md")).is_err());
+
+        // A later update can only choose among the configured roots again
+        let workspace = Workspace::from_roots(&[Root {
+            uri: file_uri(&base),
+            name: None,
+        }]);
+        assert!(!workspace.apply(&paths));
+        assert_eq!(paths.roots(), Some(vec![configured]));
+    }
+
+    #[test]
+    fn test_client_roots_outside_default_working_directory() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(root.join("app/.specify")).unwrap();
+
+        // Without allowed_roots the working directory is only a default, so
+        // an editor that launched the server elsewhere still gets its roots
+        let paths = PathPolicy::current_dir();
+        let cwd = std::env::current_dir().unwrap().canonicalize().unwrap();
+        assert!(!root.starts_with(&cwd));
+        assert!(paths.bounding_roots().is_none());
+
+        let workspace = Workspace::from_roots(&[Root {
+            uri: file_uri(&root),
+            name: None,
+        }]);
+        assert!(workspace.apply(&paths));
+        assert_eq!(paths.roots(), Some(vec![root.join("app")]));
+        assert_eq!(paths.base_dir(), Some(root.join("app")));
+
+        // Without usable roots the working directory applies again
+        assert!(!Workspace::from_roots(&[]).apply(&paths));
+        assert_eq!(paths.roots(), Some(vec![cwd]));
+    }
+}
@@ -3,63 +3,182 @@
 //! The main server that handles MCP protocol communication.
 
 use anyhow::{Context, Result};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufRead, AsyncWrite, BufReader};
+use tokio::task::JoinHandle;
 
+use super::peer::ClientPeer;
 use super::protocol::ProtocolHandler;
-use super::transport::StdioTransport;
-use super::types::{JsonRpcRequest, RequestId};
+use super::roots::sync_roots;
+use super::transport::{read_message, write_messages};
+use super::types::{
+    JsonRpcError, JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, RequestId,
+};
 use crate::tools::ToolRegistry;
 
 /// MCP Server
 pub struct McpServer {
-    transport: StdioTransport,
-    protocol: ProtocolHandler,
+    protocol: Arc<ProtocolHandler>,
     registry: Arc<ToolRegistry>,
+    client: ClientPeer,
+
+    /// Tool calls still running, by request ID, so the client can cancel them
+    in_flight: Arc<Mutex<HashMap<RequestId, JoinHandle<()>>>>,
 }
 
 impl McpServer {
     /// Create a new MCP server
     pub fn new(registry: ToolRegistry) -> Self {
+        let client = registry.client().clone();
         Self {
-            transport: StdioTransport::new(),
-            protocol: ProtocolHandler::new(),
+            protocol: Arc::new(ProtocolHandler::new()),
             registry: Arc::new(registry),
+            client,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    /// Run the server (main event loop)
+    /// Handle to the connected client
+    pub fn client(&self) -> &ClientPeer {
+        &self.client
+    }
+
+    /// Run the server over stdin/stdout
     pub async fn run(&mut self) -> Result<()> {
+        self.serve(BufReader::new(tokio::io::stdin()), tokio::io::stdout())
+            .await
+    }
+
+    /// Serve messages from `reader` until it closes (main event loop)
+    ///
+    /// Tool calls run as separate tasks so the loop keeps reading while a
+    /// tool waits for the client to answer one of its requests; all output
+    /// goes through a single writer task. A `notifications/cancelled` from the
+    /// client aborts the matching call, which then sends no response. Calls
+    /// still running when the input closes are answered before returning.
+    pub async fn serve<R, W>(&mut self, mut reader: R, writer: W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
         tracing::info!("MCP server starting...");
 
+        let writer = tokio::spawn(write_messages(writer, self.client.connect()));
+
         loop {
-            // Read request
-            let request = match self.transport.read_request().await {
-                Ok(req) => req,
+            // Read message
+            let message = match read_message(&mut reader).await {
+                Ok(message) => message,
                 Err(e) => {
                     if e.to_string().contains("EOF") {
                         tracing::info!("Client disconnected");
                         break;
                     }
-                    tracing::error!(error = %e, "Failed to read request");
+                    tracing::error!(error = %e, "Failed to read message");
                     continue;
                 }
             };
 
-            // Handle request
-            let response = self.handle_request(request).await;
-
-            // Write response
-            if let Err(e) = self.transport.write_response(response).await {
-                tracing::error!(error = %e, "Failed to write response");
+            match message {
+                JsonRpcMessage::Request(request) if request.method == "tools/call" => {
+                    let protocol = self.protocol.clone();
+                    let registry = self.registry.clone();
+                    self.spawn_request(request.id.clone(), async move {
+                        Self::handle_tool_request(&protocol, &registry, request).await
+                    });
+                }
+                JsonRpcMessage::Request(request) => {
+                    let response = self.handle_request(request).await;
+                    Self::send_response(&self.client, response);
+                }
+                JsonRpcMessage::Notification(notification) => {
+                    self.handle_notification(notification);
+                }
+                JsonRpcMessage::Response(response) => {
+                    self.client.handle_response(response);
+                }
             }
         }
 
+        // Piped clients close their input right after the last request, so
+        // let running calls finish; requests they make of the client can no
+        // longer be answered
+        self.client.fail_pending();
+        let running: Vec<_> = self
+            .in_flight
+            .lock()
+            .unwrap()
+            .drain()
+            .map(|(_, task)| task)
+            .collect();
+        for task in running {
+            let _ = task.await;
+        }
+
+        self.client.disconnect();
+        let _ = writer.await;
+
         tracing::info!("MCP server stopped");
         Ok(())
     }
 
+    /// Run a request in its own task, tracked until it responds or is
+    /// cancelled
+    fn spawn_request(
+        &self,
+        id: RequestId,
+        response: impl Future<Output = JsonRpcResponse> + Send + 'static,
+    ) {
+        let client = self.client.clone();
+        let in_flight = self.in_flight.clone();
+        // Hold the lock across the spawn so a fast task cannot remove its
+        // entry before it is inserted
+        let mut tasks = self.in_flight.lock().unwrap();
+        let task = tokio::spawn({
+            let id = id.clone();
+            async move {
+                let response = response.await;
+                in_flight.lock().unwrap().remove(&id);
+                Self::send_response(&client, response);
+            }
+        });
+        tasks.insert(id, task);
+    }
+
+    /// Abort the request named by a `notifications/cancelled`
+    fn cancel_request(&self, params: Option<serde_json::Value>) {
+        let Some(id) = params
+            .and_then(|params| params.get("requestId").cloned())
+            .and_then(|id| serde_json::from_value::<RequestId>(id).ok())
+        else {
+            tracing::warn!("Ignoring cancellation without a request ID");
+            return;
+        };
+
+        // Unknown IDs are normal: the request may already have completed
+        match self.in_flight.lock().unwrap().remove(&id) {
+            Some(task) => {
+                tracing::info!(id = ?id, "Cancelling request");
+                task.abort();
+            }
+            None => tracing::debug!(id = ?id, "No running request to cancel"),
+        }
+    }
+
+    /// Queue a response for the client
+    fn send_response(client: &ClientPeer, response: JsonRpcResponse) {
+        let result = serde_json::to_value(&response)
+            .context("Failed to serialize response")
+            .and_then(|value| client.send(value));
+        if let Err(e) = result {
+            tracing::error!(error = %e, id = ?response.id, "Failed to write response");
+        }
+    }
+
     /// Handle a JSON-RPC request
-    async fn handle_request(&self, request: JsonRpcRequest) -> super::types::JsonRpcResponse {
+    async fn handle_request(&self, request: JsonRpcRequest) -> JsonRpcResponse {
         // Validate request
         if let Err(e) = self.protocol.validate_request(&request) {
             return self.protocol.create_error_response(request.id, e);
@@ -67,57 +186,94 @@ impl McpServer {
 
         // Route to appropriate handler
         match request.method.as_str() {
-            "initialize" => self.protocol.handle_initialize(request.id),
+            "initialize" => match self.protocol.parse_initialize(request.params) {
+                Ok(params) => {
+                    self.client.set_capabilities(params.capabilities.clone());
+                    self.protocol.handle_initialize(request.id, &params)
+                }
+                Err(e) => self.protocol.create_error_response(request.id, e),
+            },
 
             "ping" => self.protocol.handle_ping(request.id),
 
             "tools/list" => {
-                let tools = self.registry.list_tools();
+                let tools = self.registry.available_tools();
                 self.protocol.create_tool_list_response(request.id, tools)
             }
 
             "tools/call" => {
-                match self
-                    .handle_tool_call(request.id.clone(), request.params)
-                    .await
-                {
-                    Ok(response) => response,
-                    Err(e) => self.protocol.create_error_response(request.id, e),
-                }
+                Self::handle_tool_request(&self.protocol, &self.registry, request).await
             }
 
             _ => {
-                let error = super::types::JsonRpcError::method_not_found(&request.method);
-                super::types::JsonRpcResponse::error(request.id, error)
+                let error = JsonRpcError::method_not_found(&request.method);
+                JsonRpcResponse::error(request.id, error)
+            }
+        }
+    }
+
+    /// Handle a JSON-RPC notification
+    fn handle_notification(&self, notification: JsonRpcNotification) {
+        match notification.method.as_str() {
+            "notifications/initialized" => {
+                tracing::info!("Client initialized");
+                self.refresh_roots();
+            }
+            "notifications/roots/list_changed" => self.refresh_roots(),
+            "notifications/cancelled" => self.cancel_request(notification.params),
+            method => tracing::debug!(method = %method, "Ignoring notification"),
+        }
+    }
+
+    /// Fetch the client's roots in the background and apply them to the
+    /// workspace path policy
+    fn refresh_roots(&self) {
+        if !self.client.supports_roots() {
+            return;
+        }
+
+        let client = self.client.clone();
+        let paths = self.registry.paths().clone();
+        tokio::spawn(async move {
+            if let Err(e) = sync_roots(&client, &paths).await {
+                tracing::warn!(error = %e, "Failed to update workspace from client roots");
             }
+        });
+    }
+
+    /// Handle a `tools/call` request
+    async fn handle_tool_request(
+        protocol: &ProtocolHandler,
+        registry: &ToolRegistry,
+        request: JsonRpcRequest,
+    ) -> JsonRpcResponse {
+        if let Err(e) = protocol.validate_request(&request) {
+            return protocol.create_error_response(request.id, e);
+        }
+
+        match Self::handle_tool_call(protocol, registry, request.id.clone(), request.params).await {
+            Ok(response) => response,
+            Err(e) => protocol.create_error_response(request.id, e),
         }
     }
 
     /// Handle a tool call
     async fn handle_tool_call(
-        &self,
+        protocol: &ProtocolHandler,
+        registry: &ToolRegistry,
         id: RequestId,
         params: Option<serde_json::Value>,
-    ) -> Result<super::types::JsonRpcResponse> {
+    ) -> Result<JsonRpcResponse> {
         // Parse tool call parameters
-        let tool_call = self.protocol.parse_tool_call(params)?;
+        let tool_call = protocol.parse_tool_call(params)?;
 
         tracing::info!(tool_name = %tool_call.name, "Executing tool");
 
-        // Get the tool
-        let tool = self
-            .registry
-            .get(&tool_call.name)
-            .ok_or_else(|| anyhow::anyhow!("Tool not found: {}", tool_call.name))?;
-
         // Execute the tool
-        let result = tool
-            .execute(tool_call.arguments)
-            .await
-            .context("Tool execution failed")?;
+        let result = registry.call(&tool_call.name, tool_call.arguments).await?;
 
         // Create response
-        Ok(self.protocol.create_tool_result_response(id, result))
+        Ok(protocol.create_tool_result_response(id, result))
     }
 }
 
@@ -126,6 +282,7 @@ mod tests {
     use super::*;
     use crate::speckit::SpecKitCli;
     use crate::tools::create_registry;
+    use serde_json::json;
 
     #[test]
     fn test_server_creation() {
@@ -136,4 +293,84 @@ mod tests {
         // Just ensure server can be created
         assert!(std::mem::size_of_val(&server) > 0);
     }
+
+    #[tokio::test]
+    async fn test_initialize_records_client_capabilities() {
+        let server = McpServer::new(create_registry(SpecKitCli::new_test_mode()));
+        let _outgoing = server.client().connect();
+        assert!(!server.client().supports_elicitation());
+
+        let response = server
+            .handle_request(JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: RequestId::Number(1),
+                method: "initialize".to_string(),
+                params: Some(json!({
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": { "elicitation": {} },
+                    "clientInfo": { "name": "test", "version": "1.0" }
+                })),
+            })
+            .await;
+
+        assert_eq!(response.result.unwrap()["protocolVersion"], "2025-06-18");
+        assert!(server.client().supports_elicitation());
+    }
+
+    #[tokio::test]
+    async fn test_responds_to_calls_running_at_eof() {
+        let mut server = McpServer::new(create_registry(SpecKitCli::new_test_mode()));
+        let call = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "speckit_feature_list", "arguments": {} }
+        });
+        let input = format!("{}\n", call);
+
+        let (output, mut collected) = tokio::io::duplex(64 * 1024);
+        server.serve(input.as_bytes(), output).await.unwrap();
+
+        let mut written = String::new();
+        tokio::io::AsyncReadExt::read_to_string(&mut collected, &mut written)
+            .await
+            .unwrap();
+        let response: serde_json::Value =
+            serde_json::from_str(written.lines().next().unwrap()).unwrap();
+        assert_eq!(response["id"], 1);
+        assert!(response["result"]["content"][0]["text"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_request_is_aborted() {
+        let server = McpServer::new(create_registry(SpecKitCli::new_test_mode()));
+        let mut outgoing = server.client().connect();
+
+        // The sender is dropped when the task is aborted
+        let (started, mut dropped) = tokio::sync::oneshot::channel::<()>();
+        server.spawn_request(RequestId::Number(7), async move {
+            let _started = started;
+            std::future::pending().await
+        });
+        server.spawn_request(RequestId::Number(8), async {
+            JsonRpcResponse::success(RequestId::Number(8), json!({}))
+        });
+
+        let response = outgoing.recv().await.unwrap();
+        assert_eq!(response["id"], 8);
+        assert_eq!(
+            dropped.try_recv(),
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty)
+        );
+
+        server.handle_notification(JsonRpcNotification {
+            jsonrpc: "2.0".to_string(),
+            method: "notifications/cancelled".to_string(),
+            params: Some(json!({ "requestId": 7, "reason": "User cancelled" })),
+        });
+
+        assert!(dropped.await.is_err());
+        assert!(server.in_flight.lock().unwrap().is_empty());
+        assert!(outgoing.try_recv().is_err());
+    }
 }
@@ -4,10 +4,11 @@
 
 use anyhow::{Context, Result};
 use serde_json::Value;
-use tokio::io::{self, Stdin, Stdout};
+use tokio::io::{self, AsyncBufRead, AsyncWrite, Stdin, Stdout};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
 
-use super::types::{JsonRpcRequest, JsonRpcResponse};
+use super::types::{JsonRpcMessage, JsonRpcResponse};
 
 /// Stdio transport for MCP communication
 pub struct StdioTransport {
@@ -24,28 +25,9 @@ impl StdioTransport {
         }
     }
 
-    /// Read a JSON-RPC request from stdin
-    pub async fn read_request(&mut self) -> Result<JsonRpcRequest> {
-        let mut line = String::new();
-        self.reader
-            .read_line(&mut line)
-            .await
-            .context("Failed to read from stdin")?;
-
-        if line.is_empty() {
-            anyhow::bail!("EOF reached");
-        }
-
-        let request: JsonRpcRequest =
-            serde_json::from_str(&line).context("Failed to parse JSON-RPC request")?;
-
-        tracing::debug!(
-            method = %request.method,
-            id = ?request.id,
-            "Received request"
-        );
-
-        Ok(request)
+    /// Read a JSON-RPC message (request, notification or response) from stdin
+    pub async fn read_message(&mut self) -> Result<JsonRpcMessage> {
+        read_message(&mut self.reader).await
     }
 
     /// Write a JSON-RPC response to stdout
@@ -99,6 +81,65 @@ impl StdioTransport {
     }
 }
 
+/// Read one line-delimited JSON-RPC message
+///
+/// Fails with "EOF reached" once the input is closed.
+pub async fn read_message<R>(reader: &mut R) -> Result<JsonRpcMessage>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .await
+        .context("Failed to read message")?;
+
+    if line.is_empty() {
+        anyhow::bail!("EOF reached");
+    }
+
+    let message = JsonRpcMessage::parse(&line).context("Failed to parse JSON-RPC message")?;
+
+    match &message {
+        JsonRpcMessage::Request(request) => {
+            tracing::debug!(method = %request.method, id = ?request.id, "Received request")
+        }
+        JsonRpcMessage::Notification(notification) => {
+            tracing::debug!(method = %notification.method, "Received notification")
+        }
+        JsonRpcMessage::Response(response) => {
+            tracing::debug!(id = ?response.id, "Received response")
+        }
+    }
+
+    Ok(message)
+}
+
+/// Write queued JSON-RPC messages, one per line, until every sender is dropped
+pub async fn write_messages<W>(mut writer: W, mut outgoing: mpsc::UnboundedReceiver<Value>)
+where
+    W: AsyncWrite + Unpin,
+{
+    while let Some(message) = outgoing.recv().await {
+        let mut line = match serde_json::to_vec(&message) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to serialize message");
+                continue;
+            }
+        };
+        line.push(b'\n');
+
+        let written = async {
+            writer.write_all(&line).await?;
+            writer.flush().await
+        };
+        if let Err(e) = written.await {
+            tracing::error!(error = %e, "Failed to write message");
+        }
+    }
+}
+
 impl Default for StdioTransport {
     fn default() -> Self {
         Self::new()
@@ -114,4 +155,19 @@ mod tests {
         let transport = StdioTransport::new();
         assert!(std::mem::size_of_val(&transport) > 0);
     }
+
+    #[tokio::test]
+    async fn test_write_messages_one_per_line() {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        sender.send(serde_json::json!({"id": 1})).unwrap();
+        sender.send(serde_json::json!({"id": 2})).unwrap();
+        drop(sender);
+
+        let mut output = Vec::new();
+        write_messages(&mut output, receiver).await;
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n"
+        );
+    }
 }
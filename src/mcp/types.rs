@@ -40,6 +40,49 @@ pub struct JsonRpcResponse {
     pub error: Option<JsonRpcError>,
 }
 
+/// JSON-RPC 2.0 Notification (a request without an ID, never answered)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcNotification {
+    /// JSON-RPC version (must be "2.0")
+    pub jsonrpc: String,
+
+    /// Method name
+    pub method: String,
+
+    /// Parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+/// Any message received from the client
+#[derive(Debug, Clone)]
+pub enum JsonRpcMessage {
+    /// A request the server must answer
+    Request(JsonRpcRequest),
+
+    /// A notification that expects no answer
+    Notification(JsonRpcNotification),
+
+    /// A response to a request the server sent to the client
+    Response(JsonRpcResponse),
+}
+
+impl JsonRpcMessage {
+    /// Parse a single JSON-RPC message, telling requests, notifications and
+    /// responses apart by the presence of `method` and `id`
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        let has_method = value.get("method").is_some();
+        let has_id = value.get("id").is_some();
+
+        Ok(match (has_method, has_id) {
+            (true, true) => Self::Request(serde_json::from_value(value)?),
+            (true, false) => Self::Notification(serde_json::from_value(value)?),
+            (false, _) => Self::Response(serde_json::from_value(value)?),
+        })
+    }
+}
+
 /// Request ID (can be string, number, or null)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(untagged)]
@@ -124,6 +167,195 @@ impl ContentBlock {
     }
 }
 
+/// Capabilities advertised by the client in `initialize`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientCapabilities {
+    /// Present when the client can answer `elicitation/create` requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elicitation: Option<Value>,
+
+    /// Present when the client can answer `sampling/createMessage` requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<Value>,
+
+    /// Present when the client can answer `roots/list` requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roots: Option<Value>,
+}
+
+/// Parameters of the `initialize` request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeParams {
+    /// Protocol version requested by the client
+    #[serde(default)]
+    pub protocol_version: Option<String>,
+
+    /// Client capabilities
+    #[serde(default)]
+    pub capabilities: ClientCapabilities,
+}
+
+/// How the user responded to an elicitation request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElicitAction {
+    /// The user submitted the form
+    Accept,
+
+    /// The user explicitly declined to answer
+    Decline,
+
+    /// The user dismissed the request without choosing
+    Cancel,
+}
+
+/// Result of an `elicitation/create` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitResult {
+    /// The user's action
+    pub action: ElicitAction,
+
+    /// Submitted values, present when the action is `accept`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<serde_json::Map<String, Value>>,
+}
+
+/// A workspace root exposed by the client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Root {
+    /// Root URI; only `file://` URIs are used
+    pub uri: String,
+
+    /// Display name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Root {
+    /// Local path of a `file://` root, percent-decoded
+    pub fn path(&self) -> Option<std::path::PathBuf> {
+        let rest = self.uri.strip_prefix("file://")?;
+        let rest = rest.strip_prefix("localhost").unwrap_or(rest);
+        if !rest.starts_with('/') {
+            return None;
+        }
+
+        let bytes = rest.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = (bytes[i] == b'%')
+                .then(|| bytes.get(i + 1..i + 3))
+                .flatten()
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            match hex {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                None => {
+                    decoded.push(bytes[i]);
+                    i += 1;
+                }
+            }
+        }
+        let path = String::from_utf8(decoded).ok()?;
+
+        // `file:///C:/work` names the Windows path `C:/work`
+        let path = match path.as_bytes() {
+            [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => path[1..].to_string(),
+            _ => path,
+        };
+        Some(std::path::PathBuf::from(path))
+    }
+}
+
+/// Result of a `roots/list` request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListRootsResult {
+    /// The client's roots
+    #[serde(default)]
+    pub roots: Vec<Root>,
+}
+
+/// Speaker of a sampling message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    User,
+    Assistant,
+}
+
+/// A message in a sampling conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingMessage {
+    /// Speaker
+    pub role: Role,
+
+    /// Message content
+    pub content: ContentBlock,
+}
+
+impl SamplingMessage {
+    /// Create a text message
+    pub fn text(role: Role, text: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: ContentBlock::text(text),
+        }
+    }
+}
+
+/// Parameters of a `sampling/createMessage` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMessageParams {
+    /// Conversation so far
+    pub messages: Vec<SamplingMessage>,
+
+    /// System prompt the client may use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+
+    /// Maximum number of tokens to generate
+    pub max_tokens: u32,
+
+    /// Sampling temperature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+}
+
+/// Result of a `sampling/createMessage` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMessageResult {
+    /// Speaker of the generated message
+    pub role: Role,
+
+    /// Generated content
+    pub content: ContentBlock,
+
+    /// Model that generated the message
+    #[serde(default)]
+    pub model: String,
+
+    /// Why generation stopped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_reason: Option<String>,
+}
+
+impl CreateMessageResult {
+    /// Generated text, if the content is text
+    pub fn text(&self) -> Option<&str> {
+        match &self.content {
+            ContentBlock::Text { text } => Some(text),
+            _ => None,
+        }
+    }
+}
+
 /// Standard JSON-RPC error codes
 pub mod error_codes {
     pub const PARSE_ERROR: i32 = -32700;
@@ -227,6 +459,45 @@ mod tests {
         assert!(request.params.is_some());
     }
 
+    #[test]
+    fn test_parse_message_kinds() {
+        let request = JsonRpcMessage::parse(r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#);
+        assert!(matches!(request, Ok(JsonRpcMessage::Request(_))));
+
+        let notification =
+            JsonRpcMessage::parse(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#);
+        assert!(matches!(notification, Ok(JsonRpcMessage::Notification(_))));
+
+        let response = JsonRpcMessage::parse(
+            r#"{"jsonrpc":"2.0","id":"speckit-1","result":{"action":"decline"}}"#,
+        );
+        let Ok(JsonRpcMessage::Response(response)) = response else {
+            panic!("expected response");
+        };
+        assert_eq!(response.id, RequestId::String("speckit-1".to_string()));
+    }
+
+    #[test]
+    fn test_root_path_from_file_uri() {
+        let root = |uri: &str| Root {
+            uri: uri.to_string(),
+            name: None,
+        };
+        assert_eq!(
+            root("file:///home/dev/My%20Project").path(),
+            Some(std::path::PathBuf::from("/home/dev/My Project"))
+        );
+        assert_eq!(
+            root("file://localhost/srv/app").path(),
+            Some(std::path::PathBuf::from("/srv/app"))
+        );
+        assert_eq!(
+            root("file:///C:/work").path(),
+            Some(std::path::PathBuf::from("C:/work"))
+        );
+        assert_eq!(root("https://example.com/repo").path(), None);
+    }
+
     #[test]
     fn test_success_response() {
         let response = JsonRpcResponse::success(RequestId::Number(1), json!({"status": "ok"}));
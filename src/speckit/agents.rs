@@ -0,0 +1,208 @@
+//! AI Agent Targets
+//!
+//! The agents `specify init --ai` can set up and where each one keeps the
+//! spec-kit command files it writes (`.claude/commands/*.md`,
+//! `.github/prompts/*.prompt.md`, ...).
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// An AI agent supported by `specify init --ai`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AiAgent {
+    /// Claude Code
+    Claude,
+
+    /// GitHub Copilot
+    Copilot,
+
+    /// Gemini CLI
+    Gemini,
+
+    /// Cursor
+    Cursor,
+
+    /// Windsurf
+    Windsurf,
+
+    /// Qwen Code
+    Qwen,
+
+    /// opencode
+    Opencode,
+
+    /// Codex CLI
+    Codex,
+
+    /// Kilo Code
+    Kilocode,
+
+    /// Auggie CLI
+    Auggie,
+
+    /// Roo Code
+    Roo,
+
+    /// Amazon Q Developer CLI
+    Q,
+}
+
+impl AiAgent {
+    /// All agents, in `specify init` order
+    pub const ALL: [AiAgent; 12] = [
+        AiAgent::Claude,
+        AiAgent::Copilot,
+        AiAgent::Gemini,
+        AiAgent::Cursor,
+        AiAgent::Windsurf,
+        AiAgent::Qwen,
+        AiAgent::Opencode,
+        AiAgent::Codex,
+        AiAgent::Kilocode,
+        AiAgent::Auggie,
+        AiAgent::Roo,
+        AiAgent::Q,
+    ];
+
+    /// Value passed to `--ai`
+    pub fn key(&self) -> &'static str {
+        match self {
+            AiAgent::Claude => "claude",
+            AiAgent::Copilot => "copilot",
+            AiAgent::Gemini => "gemini",
+            AiAgent::Cursor => "cursor",
+            AiAgent::Windsurf => "windsurf",
+            AiAgent::Qwen => "qwen",
+            AiAgent::Opencode => "opencode",
+            AiAgent::Codex => "codex",
+            AiAgent::Kilocode => "kilocode",
+            AiAgent::Auggie => "auggie",
+            AiAgent::Roo => "roo",
+            AiAgent::Q => "q",
+        }
+    }
+
+    /// Directory the agent's command files are written to, relative to the
+    /// project
+    pub fn commands_dir(&self) -> &'static str {
+        match self {
+            AiAgent::Claude => ".claude/commands",
+            AiAgent::Copilot => ".github/prompts",
+            AiAgent::Gemini => ".gemini/commands",
+            AiAgent::Cursor => ".cursor/commands",
+            AiAgent::Windsurf => ".windsurf/workflows",
+            AiAgent::Qwen => ".qwen/commands",
+            AiAgent::Opencode => ".opencode/command",
+            AiAgent::Codex => ".codex/prompts",
+            AiAgent::Kilocode => ".kilocode/workflows",
+            AiAgent::Auggie => ".augment/commands",
+            AiAgent::Roo => ".roo/commands",
+            AiAgent::Q => ".amazonq/prompts",
+        }
+    }
+
+    /// File name suffix of the agent's command files
+    pub fn command_suffix(&self) -> &'static str {
+        match self {
+            AiAgent::Copilot => ".prompt.md",
+            AiAgent::Gemini | AiAgent::Qwen => ".toml",
+            _ => ".md",
+        }
+    }
+
+    /// Glob describing the command files, e.g. `.claude/commands/*.md`
+    pub fn command_glob(&self) -> String {
+        format!("{}/*{}", self.commands_dir(), self.command_suffix())
+    }
+
+    /// Command files present in a project, sorted, relative to the project
+    pub fn command_files(&self, project_dir: &Path) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(project_dir.join(self.commands_dir())) else {
+            return Vec::new();
+        };
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                name.ends_with(self.command_suffix())
+                    .then(|| Path::new(self.commands_dir()).join(name))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+}
+
+impl fmt::Display for AiAgent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+/// Script flavor of the helper scripts `specify init` installs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptType {
+    /// POSIX shell (`.specify/scripts/bash`)
+    Sh,
+
+    /// PowerShell (`.specify/scripts/powershell`)
+    Ps,
+}
+
+impl ScriptType {
+    /// Value passed to `--script`
+    pub fn key(&self) -> &'static str {
+        match self {
+            ScriptType::Sh => "sh",
+            ScriptType::Ps => "ps",
+        }
+    }
+}
+
+impl fmt::Display for ScriptType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_agent_keys_round_trip() {
+        for agent in AiAgent::ALL {
+            let parsed: AiAgent = serde_json::from_value(agent.key().into()).unwrap();
+            assert_eq!(parsed, agent);
+        }
+        assert!(serde_json::from_value::<AiAgent>("vim".into()).is_err());
+        assert_eq!(
+            AiAgent::Copilot.command_glob(),
+            ".github/prompts/*.prompt.md"
+        );
+    }
+
+    #[test]
+    fn test_command_files() {
+        let dir = tempdir().unwrap();
+        let prompts = dir.path().join(".github/prompts");
+        std::fs::create_dir_all(&prompts).unwrap();
+        std::fs::write(prompts.join("specify.prompt.md"), "").unwrap();
+        std::fs::write(prompts.join("plan.prompt.md"), "").unwrap();
+        std::fs::write(prompts.join("notes.txt"), "").unwrap();
+
+        assert_eq!(
+            AiAgent::Copilot.command_files(dir.path()),
+            [
+                PathBuf::from(".github/prompts/plan.prompt.md"),
+                PathBuf::from(".github/prompts/specify.prompt.md"),
+            ]
+        );
+        assert!(AiAgent::Claude.command_files(dir.path()).is_empty());
+    }
+}
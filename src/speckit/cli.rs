@@ -4,11 +4,24 @@
 
 use anyhow::{Context, Result};
 use async_process::{Command, Stdio};
-use std::path::Path;
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::time::timeout;
 
 use super::errors::SpecKitError;
+use super::invocation::{command_env, parse_env_entries, CommandOptions, InitOptions};
+use super::output::CommandOutcome;
+use super::probe::{
+    parse_version, probe_cache_file, CachedProbe, CliProbe, CliStatus, ProbeReport, ProbeResult,
+    ProbeSource, DEFAULT_PROBE_TTL_SECONDS, REPROBE_INTERVAL,
+};
+use crate::artifacts::{write_artifact, WriteOptions, WriteOutcome};
+use crate::config::CliConfig;
+use crate::utils::PathPolicy;
+
+/// Default package source for spec-kit
+pub const SPEC_KIT_SOURCE: &str = "git+https://github.com/github/spec-kit.git";
 
 /// Result of executing a spec-kit command
 #[derive(Debug, Clone)]
@@ -29,15 +42,21 @@ impl CommandResult {
         self.exit_code == 0
     }
 
-    /// Get a summary of the result
+    /// Parse the output into files created, warnings and next steps
+    pub fn outcome(&self) -> CommandOutcome {
+        CommandOutcome::parse(&self.stdout, &self.stderr, self.exit_code)
+    }
+
+    /// Get a summary of the result, without colors or Rich markup
     pub fn summary(&self) -> String {
+        let outcome = self.outcome();
         if self.is_success() {
-            format!("Success\n{}", self.stdout.trim())
+            format!("Success\n{}", outcome.text)
         } else {
             format!(
                 "Failed (exit code {})\n{}",
                 self.exit_code,
-                self.stderr.trim()
+                outcome.error_text()
             )
         }
     }
@@ -49,6 +68,9 @@ pub struct SpecKitCli {
     /// Path to the specify command
     cli_path: String,
 
+    /// Package source passed to `uvx --from`
+    source: String,
+
     /// Path to Python interpreter (reserved for future use)
     #[allow(dead_code)]
     python_path: String,
@@ -58,6 +80,24 @@ pub struct SpecKitCli {
 
     /// Test mode flag
     test_mode: bool,
+
+    /// Workspace roots tools may read and write
+    paths: PathPolicy,
+
+    /// Availability of the CLI, shared between clones
+    probe: CliProbe,
+
+    /// File successful probes are cached in
+    probe_cache: Option<PathBuf>,
+
+    /// Lifetime of a cached probe
+    probe_ttl: Duration,
+
+    /// Variables passed through on top of the built-in allow-list
+    env_allow: Vec<String>,
+
+    /// Variables set for every command
+    env: BTreeMap<String, String>,
 }
 
 impl SpecKitCli {
@@ -65,9 +105,16 @@ impl SpecKitCli {
     pub fn new() -> Self {
         Self {
             cli_path: "uvx".to_string(),
+            source: SPEC_KIT_SOURCE.to_string(),
             python_path: "python3".to_string(),
             timeout_seconds: 300, // 5 minutes
             test_mode: false,
+            paths: PathPolicy::current_dir(),
+            probe: CliProbe::new(),
+            probe_cache: None,
+            probe_ttl: Duration::from_secs(DEFAULT_PROBE_TTL_SECONDS),
+            env_allow: Vec::new(),
+            env: BTreeMap::new(),
         }
     }
 
@@ -76,9 +123,16 @@ impl SpecKitCli {
     pub fn new_test_mode() -> Self {
         Self {
             cli_path: "specify".to_string(),
+            source: SPEC_KIT_SOURCE.to_string(),
             python_path: "python3".to_string(),
             timeout_seconds: 300,
             test_mode: true,
+            paths: PathPolicy::unrestricted(),
+            probe: CliProbe::new(),
+            probe_cache: None,
+            probe_ttl: Duration::from_secs(DEFAULT_PROBE_TTL_SECONDS),
+            env_allow: Vec::new(),
+            env: BTreeMap::new(),
         }
     }
 
@@ -88,6 +142,56 @@ impl SpecKitCli {
         self
     }
 
+    /// Set the package source passed to `uvx --from`
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Build a CLI interface from the `[cli]` configuration section
+    pub fn from_config(config: &CliConfig) -> Self {
+        Self::new()
+            .with_cli_path(&config.path)
+            .with_source(&config.source)
+            .with_timeout(config.timeout_seconds)
+            .with_probe_cache(probe_cache_file(), config.probe_ttl_seconds)
+            .with_env_allow(config.env_allow.clone())
+            .with_env(parse_env_entries(&config.env))
+    }
+
+    /// Pass these variables through on top of the built-in allow-list
+    /// ([`ENV_ALLOW_LIST`](super::ENV_ALLOW_LIST)); a trailing `*` matches a
+    /// prefix
+    pub fn with_env_allow(mut self, patterns: Vec<String>) -> Self {
+        self.env_allow = patterns;
+        self
+    }
+
+    /// Set these variables for every command
+    pub fn with_env(mut self, env: BTreeMap<String, String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Cache successful probes in a file for `ttl_seconds`; `0` disables
+    /// the cache
+    pub fn with_probe_cache(mut self, path: Option<PathBuf>, ttl_seconds: u64) -> Self {
+        self.probe_cache = path.filter(|_| ttl_seconds > 0);
+        self.probe_ttl = Duration::from_secs(ttl_seconds);
+        self
+    }
+
+    /// Set the workspace path policy
+    pub fn with_path_policy(mut self, paths: PathPolicy) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Workspace path policy that all file access goes through
+    pub fn paths(&self) -> &PathPolicy {
+        &self.paths
+    }
+
     /// Set the timeout
     pub fn with_timeout(mut self, seconds: u64) -> Self {
         self.timeout_seconds = seconds;
@@ -95,44 +199,218 @@ impl SpecKitCli {
     }
 
     /// Check if spec-kit is installed (via uvx)
+    ///
+    /// Each check is killed after the command timeout, so a hanging `uvx`
+    /// (e.g. stuck on a network fetch) reports the CLI as unavailable.
     pub async fn is_installed(&self) -> bool {
         if self.test_mode {
             return true;
         }
 
         // Check if uvx is available
-        let uvx_available = Command::new(&self.cli_path)
-            .arg("--version")
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .await
-            .map(|s| s.success())
-            .unwrap_or(false);
-
-        if !uvx_available {
+        if !self.probe_succeeds(&["--version"]).await {
             tracing::warn!("uvx command not found - spec-kit requires uv/uvx");
             return false;
         }
 
         // Check if we can run spec-kit via uvx
-        Command::new(&self.cli_path)
-            .args([
-                "--from",
-                "git+https://github.com/github/spec-kit.git",
-                "specify",
-                "--help",
-            ])
+        self.probe_succeeds(&["--from", &self.source, "specify", "--help"])
+            .await
+    }
+
+    /// Run a probe command, killing it when it outlives the command timeout
+    async fn probe_succeeds(&self, args: &[&str]) -> bool {
+        let mut child = match self
+            .command(None, &[])
+            .args(args)
             .stdout(Stdio::null())
             .stderr(Stdio::null())
-            .status()
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(_) => return false,
+        };
+
+        match timeout(Duration::from_secs(self.timeout_seconds), child.status()).await {
+            Ok(status) => status.map(|s| s.success()).unwrap_or(false),
+            Err(_) => {
+                tracing::warn!(
+                    command = %self.cli_path,
+                    args = ?args,
+                    timeout_seconds = self.timeout_seconds,
+                    "spec-kit CLI probe timed out"
+                );
+                match child.kill() {
+                    Ok(()) => {
+                        let _ = child.status().await;
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Failed to kill timed-out probe"),
+                }
+                false
+            }
+        }
+    }
+
+    /// Availability recorded by the last probe
+    pub fn status(&self) -> CliStatus {
+        self.probe.status()
+    }
+
+    /// Whether the last probe is stale: never probed, or missing at a probe
+    /// older than [`REPROBE_INTERVAL`]
+    pub fn needs_probe(&self) -> bool {
+        self.probe.needs_probe(REPROBE_INTERVAL)
+    }
+
+    /// Probe state: status, version, timing and where it came from
+    pub fn probe_report(&self) -> ProbeReport {
+        self.probe.report()
+    }
+
+    /// Probe the CLI, bypassing the cache, and record the result
+    pub async fn probe(&self) -> CliStatus {
+        let _running = self.probe.lock().await;
+        self.run_probe().await
+    }
+
+    /// Whether the CLI can run, probing when it was never probed or was
+    /// missing at a probe older than [`REPROBE_INTERVAL`]
+    ///
+    /// The first check uses the on-disk cache when it holds a fresh
+    /// successful probe for the same launcher and source.
+    pub async fn ensure_available(&self) -> bool {
+        if self.needs_probe() {
+            let _running = self.probe.lock().await;
+            // Another caller may have probed while we waited
+            if self.needs_probe()
+                && (self.status() != CliStatus::Unknown || !self.load_cached_probe().await)
+            {
+                self.run_probe().await;
+            }
+        }
+        self.status() == CliStatus::Available
+    }
+
+    /// Record a fresh cached probe, returning whether there was one
+    async fn load_cached_probe(&self) -> bool {
+        let Some(path) = &self.probe_cache else {
+            return false;
+        };
+        match CachedProbe::load(path).await {
+            Some(entry) if entry.is_valid(&self.cli_path, &self.source, self.probe_ttl) => {
+                tracing::info!(version = ?entry.version, "Using cached spec-kit CLI probe");
+                self.probe.record(entry.result(), ProbeSource::Cache);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Run the CLI, record the result and update the cache
+    async fn run_probe(&self) -> CliStatus {
+        let started = Instant::now();
+        let checked_at = SystemTime::now();
+        let available = self.is_installed().await;
+        let version = if available {
+            self.detect_version().await
+        } else {
+            None
+        };
+        let result = ProbeResult {
+            available,
+            version,
+            duration: started.elapsed(),
+            checked_at,
+        };
+
+        tracing::info!(
+            available,
+            version = ?result.version,
+            duration_ms = result.duration.as_millis() as u64,
+            "Probed spec-kit CLI"
+        );
+
+        if let Some(path) = &self.probe_cache {
+            let updated = if available {
+                CachedProbe::new(&self.cli_path, &self.source, &result)
+                    .store(path)
+                    .await
+            } else {
+                match tokio::fs::remove_file(path).await {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+                    _ => Ok(()),
+                }
+            };
+            if let Err(e) = updated {
+                tracing::warn!(error = %e, path = %path.display(), "Failed to update probe cache");
+            }
+        }
+
+        self.probe.record(result, ProbeSource::Probe)
+    }
+
+    /// Version reported by `specify version`
+    pub async fn version(&self) -> Result<String> {
+        let result = self
+            .execute_command(&["version"], &CommandOptions::default())
+            .await?;
+        if !result.is_success() {
+            return Err(SpecKitError::command_failed(
+                "specify version",
+                result.outcome().error_text(),
+                result.exit_code,
+            )
+            .into());
+        }
+        let outcome = result.outcome();
+        parse_version(&outcome.text).ok_or_else(|| {
+            SpecKitError::ParseError(format!(
+                "no version number in `specify version` output: {}",
+                outcome.text
+            ))
+            .into()
+        })
+    }
+
+    /// Version for the probe; failures are logged, not fatal
+    async fn detect_version(&self) -> Option<String> {
+        self.version()
             .await
-            .map(|s| s.success())
-            .unwrap_or(false)
+            .map_err(|e| tracing::debug!(error = %e, "Could not detect spec-kit version"))
+            .ok()
+    }
+
+    /// Launcher command with a filtered environment, run in `cwd` (or the
+    /// inherited directory) with stdin closed
+    fn command(&self, cwd: Option<&Path>, overrides: &[(String, String)]) -> Command {
+        let env = command_env(std::env::vars(), &self.env_allow, &self.env, overrides);
+        let mut command = Command::new(&self.cli_path);
+        command
+            .env_clear()
+            .envs(env)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        if let Some(cwd) = cwd {
+            command.current_dir(cwd);
+        }
+        command
     }
 
     /// Execute a spec-kit command
-    async fn execute_command(&self, args: &[&str]) -> Result<CommandResult> {
+    ///
+    /// The command runs in `options.cwd` (checked against the path policy)
+    /// or the workspace base directory, so relative arguments resolve the
+    /// same way tool paths do.
+    async fn execute_command(
+        &self,
+        args: &[&str],
+        options: &CommandOptions,
+    ) -> Result<CommandResult> {
+        let cwd = match &options.cwd {
+            Some(cwd) => Some(self.paths.check(cwd)?),
+            None => self.paths.base_dir(),
+        };
+
         if self.test_mode {
             return Ok(CommandResult {
                 stdout: "Test mode: command executed successfully".to_string(),
@@ -142,20 +420,19 @@ impl SpecKitCli {
         }
 
         // Build the full command with uvx + spec-kit repo + specify + args
-        let mut full_args = vec![
-            "--from",
-            "git+https://github.com/github/spec-kit.git",
-            "specify",
-        ];
+        let mut full_args = vec!["--from", self.source.as_str(), "specify"];
         full_args.extend_from_slice(args);
 
         tracing::debug!(
             command = %self.cli_path,
             args = ?full_args,
+            cwd = ?cwd,
+            env = ?options.env,
             "Executing spec-kit command via uvx"
         );
 
-        let command_future = Command::new(&self.cli_path)
+        let command_future = self
+            .command(cwd.as_deref(), &options.env)
             .args(&full_args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -176,10 +453,21 @@ impl SpecKitCli {
             exit_code,
         };
 
-        if !result.is_success() {
+        let outcome = result.outcome();
+        for warning in &outcome.warnings {
+            tracing::warn!(command = ?args, warning = %warning, "Spec-kit warning");
+        }
+        if outcome.success {
+            tracing::debug!(
+                command = ?args,
+                files_created = ?outcome.files_created,
+                next_steps = ?outcome.next_steps,
+                "Spec-kit command succeeded"
+            );
+        } else {
             tracing::warn!(
-                exit_code = result.exit_code,
-                stderr = %result.stderr,
+                exit_code = outcome.exit_code,
+                output = %outcome.error_text(),
                 "Spec-kit command failed"
             );
         }
@@ -187,20 +475,24 @@ impl SpecKitCli {
         Ok(result)
     }
 
-    /// Initialize a new spec-kit project
-    pub async fn init(&self, project_name: &str, path: &Path) -> Result<CommandResult> {
-        let path_str = path
-            .to_str()
-            .ok_or_else(|| SpecKitError::InvalidPath("Path contains invalid UTF-8".to_string()))?;
+    /// Initialize a spec-kit project
+    ///
+    /// Runs `specify init` in `options.directory`, creating a `<name>`
+    /// subdirectory or, with `here`, initializing the directory itself.
+    pub async fn init(&self, options: &InitOptions) -> Result<CommandResult> {
+        let directory = self.paths.check(&options.directory)?;
+        self.paths.check(&options.project_dir())?;
 
+        let args = options.args();
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
         let result = self
-            .execute_command(&["init", project_name, "--path", path_str])
+            .execute_command(&args, &CommandOptions::in_dir(directory))
             .await?;
 
         if !result.is_success() {
             return Err(SpecKitError::command_failed(
-                format!("specify init {}", project_name),
-                &result.stderr,
+                format!("specify {}", args.join(" ")),
+                result.outcome().error_text(),
                 result.exit_code,
             )
             .into());
@@ -210,18 +502,17 @@ impl SpecKitCli {
     }
 
     /// Create a constitution file
-    pub async fn constitution(&self, content: &str, output_path: &Path) -> Result<CommandResult> {
+    pub async fn constitution(
+        &self,
+        content: &str,
+        output_path: &Path,
+        options: WriteOptions,
+    ) -> Result<WriteOutcome> {
         // For now, we'll write directly to the file since spec-kit
         // accepts input via stdin or prompts
-        tokio::fs::write(output_path, content)
+        write_artifact(&self.paths, output_path, content, options)
             .await
-            .context("Failed to write constitution file")?;
-
-        Ok(CommandResult {
-            stdout: format!("Constitution written to {}", output_path.display()),
-            stderr: String::new(),
-            exit_code: 0,
-        })
+            .context("Failed to write constitution file")
     }
 
     /// Create a specification file
@@ -230,22 +521,23 @@ impl SpecKitCli {
         requirements: &str,
         output_path: &Path,
         _format: &str,
-    ) -> Result<CommandResult> {
-        // Write requirements to file
+        options: WriteOptions,
+    ) -> Result<WriteOutcome> {
         // Spec-kit typically uses interactive prompts or file input
-        tokio::fs::write(output_path, requirements)
+        write_artifact(&self.paths, output_path, requirements, options)
             .await
-            .context("Failed to write specification file")?;
-
-        Ok(CommandResult {
-            stdout: format!("Specification written to {}", output_path.display()),
-            stderr: String::new(),
-            exit_code: 0,
-        })
+            .context("Failed to write specification file")
     }
 
     /// Create a technical plan
-    pub async fn plan(&self, spec_file: &Path, output_path: &Path) -> Result<CommandResult> {
+    pub async fn plan(
+        &self,
+        spec_file: &Path,
+        output_path: &Path,
+        options: &CommandOptions,
+    ) -> Result<CommandResult> {
+        let spec_file = self.paths.check(spec_file)?;
+        let output_path = self.paths.check(output_path)?;
         let spec_str = spec_file.to_str().ok_or_else(|| {
             SpecKitError::InvalidPath("Spec file path contains invalid UTF-8".to_string())
         })?;
@@ -255,13 +547,16 @@ impl SpecKitCli {
         })?;
 
         let result = self
-            .execute_command(&["plan", "--spec", spec_str, "--output", output_str])
+            .execute_command(
+                &["plan", "--spec", spec_str, "--output", output_str],
+                options,
+            )
             .await?;
 
         if !result.is_success() {
             return Err(SpecKitError::command_failed(
                 "specify plan",
-                &result.stderr,
+                result.outcome().error_text(),
                 result.exit_code,
             )
             .into());
@@ -271,7 +566,14 @@ impl SpecKitCli {
     }
 
     /// Generate task list
-    pub async fn tasks(&self, plan_file: &Path, output_path: &Path) -> Result<CommandResult> {
+    pub async fn tasks(
+        &self,
+        plan_file: &Path,
+        output_path: &Path,
+        options: &CommandOptions,
+    ) -> Result<CommandResult> {
+        let plan_file = self.paths.check(plan_file)?;
+        let output_path = self.paths.check(output_path)?;
         let plan_str = plan_file.to_str().ok_or_else(|| {
             SpecKitError::InvalidPath("Plan file path contains invalid UTF-8".to_string())
         })?;
@@ -281,13 +583,16 @@ impl SpecKitCli {
         })?;
 
         let result = self
-            .execute_command(&["tasks", "--plan", plan_str, "--output", output_str])
+            .execute_command(
+                &["tasks", "--plan", plan_str, "--output", output_str],
+                options,
+            )
             .await?;
 
         if !result.is_success() {
             return Err(SpecKitError::command_failed(
                 "specify tasks",
-                &result.stderr,
+                result.outcome().error_text(),
                 result.exit_code,
             )
             .into());
@@ -298,18 +603,22 @@ impl SpecKitCli {
 
     /// Analyze project consistency
     pub async fn analyze(&self, project_path: &Path) -> Result<CommandResult> {
+        let project_path = self.paths.check(project_path)?;
         let path_str = project_path.to_str().ok_or_else(|| {
             SpecKitError::InvalidPath("Project path contains invalid UTF-8".to_string())
         })?;
 
         let result = self
-            .execute_command(&["analyze", "--path", path_str])
+            .execute_command(
+                &["analyze", "--path", path_str],
+                &CommandOptions::in_dir(&project_path),
+            )
             .await?;
 
         if !result.is_success() {
             return Err(SpecKitError::command_failed(
                 "specify analyze",
-                &result.stderr,
+                result.outcome().error_text(),
                 result.exit_code,
             )
             .into());
@@ -328,6 +637,7 @@ impl Default for SpecKitCli {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::artifacts::WriteAction;
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -336,6 +646,121 @@ mod tests {
         assert_eq!(cli.timeout_seconds, 300);
     }
 
+    #[tokio::test]
+    async fn test_ensure_available_records_missing_cli() {
+        let cli = SpecKitCli::new().with_cli_path("/nonexistent/uvx");
+        assert_eq!(cli.status(), CliStatus::Unknown);
+
+        assert!(!cli.ensure_available().await);
+        assert_eq!(cli.clone().status(), CliStatus::Missing);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_available_uses_probe_cache() {
+        let dir = tempdir().unwrap();
+        let cache = dir.path().join("cli-probe.json");
+        let cached = ProbeResult {
+            available: true,
+            version: Some("0.0.20".to_string()),
+            duration: Duration::from_secs(12),
+            checked_at: SystemTime::now(),
+        };
+        CachedProbe::new("/nonexistent/uvx", SPEC_KIT_SOURCE, &cached)
+            .store(&cache)
+            .await
+            .unwrap();
+
+        let cli = SpecKitCli::new()
+            .with_cli_path("/nonexistent/uvx")
+            .with_probe_cache(Some(cache.clone()), DEFAULT_PROBE_TTL_SECONDS);
+        assert!(cli.ensure_available().await);
+        let report = cli.probe_report();
+        assert_eq!(report.source, Some(ProbeSource::Cache));
+        assert_eq!(report.version.as_deref(), Some("0.0.20"));
+
+        // A failed probe drops the cached result
+        assert_eq!(cli.probe().await, CliStatus::Missing);
+        assert!(!cache.exists());
+    }
+
+    #[tokio::test]
+    async fn test_version_without_number_is_parse_error() {
+        let cli = SpecKitCli::new_test_mode();
+        let err = cli.version().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SpecKitError>(),
+            Some(SpecKitError::ParseError(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_probe_times_out_and_kills_hanging_cli() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script = dir.path().join("uvx");
+        let pid_file = dir.path().join("pid");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\n[ \"$1\" = --version ] && exit 0\necho $$ > {}\nexec sleep 30\n",
+                pid_file.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let cli = SpecKitCli::new()
+            .with_cli_path(script.display().to_string())
+            .with_timeout(1);
+        let started = Instant::now();
+        assert_eq!(cli.probe().await, CliStatus::Missing);
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        let alive = std::process::Command::new("kill")
+            .args(["-0", pid.trim()])
+            .stderr(Stdio::null())
+            .status()
+            .unwrap()
+            .success();
+        assert!(!alive, "the timed-out probe should be killed");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_command_cwd_and_env() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script = dir.path().join("uvx");
+        std::fs::write(&script, "#!/bin/sh\npwd\nenv\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let project = dir.path().join("project");
+        std::fs::create_dir(&project).unwrap();
+
+        let cli = SpecKitCli::new()
+            .with_cli_path(script.display().to_string())
+            .with_path_policy(PathPolicy::new([dir.path().to_path_buf()]))
+            .with_env(BTreeMap::from([("GH_HOST".to_string(), "ghe".to_string())]));
+        let options = CommandOptions::in_dir(&project).with_env("SPECIFY_FEATURE", "001-auth");
+        let result = cli.execute_command(&["version"], &options).await.unwrap();
+
+        let mut lines = result.stdout.lines();
+        let cwd = PathBuf::from(lines.next().unwrap());
+        assert_eq!(cwd.canonicalize().unwrap(), project.canonicalize().unwrap());
+        let env: Vec<&str> = lines.collect();
+        assert!(env.contains(&"NO_COLOR=1"));
+        assert!(env.contains(&"GH_HOST=ghe"));
+        assert!(env.contains(&"SPECIFY_FEATURE=001-auth"));
+        assert!(!env.iter().any(|line| line.starts_with("CARGO_")));
+
+        // The working directory must stay inside the workspace
+        let outside = CommandOptions::in_dir(std::env::temp_dir());
+        assert!(cli.execute_command(&["version"], &outside).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_constitution_write() {
         let cli = SpecKitCli::new_test_mode();
@@ -343,11 +768,11 @@ mod tests {
         let output_path = dir.path().join("constitution.md");
 
         let result = cli
-            .constitution("Test constitution", &output_path)
+            .constitution("Test constitution", &output_path, WriteOptions::default())
             .await
             .unwrap();
 
-        assert!(result.is_success());
+        assert_eq!(result.action, WriteAction::Created);
         assert!(output_path.exists());
     }
 
@@ -358,11 +783,16 @@ mod tests {
         let output_path = dir.path().join("specification.md");
 
         let result = cli
-            .specify("Test requirements", &output_path, "markdown")
+            .specify(
+                "Test requirements",
+                &output_path,
+                "markdown",
+                WriteOptions::default(),
+            )
             .await
             .unwrap();
 
-        assert!(result.is_success());
+        assert_eq!(result.action, WriteAction::Created);
         assert!(output_path.exists());
     }
 }
@@ -5,7 +5,10 @@ use thiserror::Error;
 /// Errors that can occur when interacting with spec-kit CLI
 #[derive(Error, Debug)]
 pub enum SpecKitError {
-    #[error("Spec-kit CLI not found. Please install with: uv tool install specify-cli")]
+    #[error(
+        "Spec-kit CLI not found. Please install with: uv tool install specify-cli --from {}",
+        super::SPEC_KIT_SOURCE
+    )]
     CliNotFound,
 
     #[error("Python 3.11+ required but not found")]
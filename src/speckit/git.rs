@@ -0,0 +1,293 @@
+//! Git Integration
+//!
+//! Optional git support mirroring spec-kit's scripts: each feature gets a
+//! `NNN-slug` branch, the active feature can be read from the current branch,
+//! and artifacts can be committed after each workflow phase.
+
+use anyhow::{Context, Result};
+use async_process::{Command, Stdio};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::cli::CommandResult;
+use super::errors::SpecKitError;
+
+/// What happened when switching to a feature branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BranchSwitch {
+    /// The branch did not exist and was created
+    Created(String),
+
+    /// An existing branch was checked out
+    Switched(String),
+
+    /// The branch was already checked out
+    Unchanged(String),
+}
+
+impl fmt::Display for BranchSwitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Created(branch) => write!(f, "created and switched to branch {}", branch),
+            Self::Switched(branch) => write!(f, "switched to branch {}", branch),
+            Self::Unchanged(branch) => write!(f, "already on branch {}", branch),
+        }
+    }
+}
+
+/// Result of committing an artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitOutcome {
+    /// A commit was created (short hash)
+    Committed(String),
+
+    /// The artifact had no changes to commit
+    NoChanges,
+
+    /// The artifact is not inside a git repository
+    NotARepository,
+}
+
+impl fmt::Display for CommitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Committed(hash) => write!(f, "committed as {}", hash),
+            Self::NoChanges => f.write_str("no changes to commit"),
+            Self::NotARepository => f.write_str("not a git repository, commit skipped"),
+        }
+    }
+}
+
+/// A git working tree
+#[derive(Debug, Clone)]
+pub struct GitRepo {
+    root: PathBuf,
+}
+
+impl GitRepo {
+    /// Find the repository containing a path
+    pub fn discover(path: &Path) -> Option<Self> {
+        let start = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        start
+            .ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .map(|root| Self {
+                root: root.to_path_buf(),
+            })
+    }
+
+    /// Working tree root
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Name of the checked-out branch, read from `HEAD`
+    ///
+    /// Returns `None` for a detached `HEAD`. Works on repositories without
+    /// commits and in linked worktrees, without spawning git.
+    pub fn current_branch(&self) -> Option<String> {
+        let head = std::fs::read_to_string(self.git_dir()?.join("HEAD")).ok()?;
+        head.trim()
+            .strip_prefix("ref: refs/heads/")
+            .map(str::to_string)
+    }
+
+    /// The `.git` directory, following the `gitdir:` file of linked worktrees
+    fn git_dir(&self) -> Option<PathBuf> {
+        let dot_git = self.root.join(".git");
+        if dot_git.is_dir() {
+            return Some(dot_git);
+        }
+
+        let pointer = std::fs::read_to_string(&dot_git).ok()?;
+        let target = pointer.trim().strip_prefix("gitdir:")?.trim();
+        Some(self.root.join(target))
+    }
+
+    /// Check whether a local branch exists
+    pub async fn branch_exists(&self, name: &str) -> Result<bool> {
+        let result = self
+            .git(&[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("refs/heads/{}", name),
+            ])
+            .await?;
+        Ok(result.is_success())
+    }
+
+    /// Switch to a branch, creating it from the current `HEAD` if needed
+    pub async fn switch_branch(&self, name: &str) -> Result<BranchSwitch> {
+        if self.current_branch().as_deref() == Some(name) {
+            return Ok(BranchSwitch::Unchanged(name.to_string()));
+        }
+
+        if self.branch_exists(name).await? {
+            self.git_checked(&["checkout", name]).await?;
+            Ok(BranchSwitch::Switched(name.to_string()))
+        } else {
+            self.git_checked(&["checkout", "-b", name]).await?;
+            Ok(BranchSwitch::Created(name.to_string()))
+        }
+    }
+
+    /// Commit the given paths (and only those) with a message
+    pub async fn commit_paths(&self, paths: &[&Path], message: &str) -> Result<CommitOutcome> {
+        let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+
+        let mut add = vec!["add", "--"];
+        add.extend(paths.iter().map(String::as_str));
+        self.git_checked(&add).await?;
+
+        let mut status = vec!["status", "--porcelain", "--"];
+        status.extend(paths.iter().map(String::as_str));
+        if self.git_checked(&status).await?.stdout.trim().is_empty() {
+            return Ok(CommitOutcome::NoChanges);
+        }
+
+        let mut commit = vec!["commit", "--quiet", "-m", message, "--"];
+        commit.extend(paths.iter().map(String::as_str));
+        self.git_checked(&commit).await?;
+
+        let hash = self.git_checked(&["rev-parse", "--short", "HEAD"]).await?;
+        Ok(CommitOutcome::Committed(hash.stdout.trim().to_string()))
+    }
+
+    /// Run git in the working tree
+    async fn git(&self, args: &[&str]) -> Result<CommandResult> {
+        tracing::debug!(root = %self.root.display(), args = ?args, "Executing git command");
+
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&self.root)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute git")?;
+
+        Ok(CommandResult {
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: output.status.code().unwrap_or(-1),
+        })
+    }
+
+    /// Run git, failing on a non-zero exit code
+    async fn git_checked(&self, args: &[&str]) -> Result<CommandResult> {
+        let result = self.git(args).await?;
+        if !result.is_success() {
+            return Err(SpecKitError::command_failed(
+                format!("git {}", args.join(" ")),
+                result.stderr.trim(),
+                result.exit_code,
+            )
+            .into());
+        }
+        Ok(result)
+    }
+}
+
+/// Commit an artifact written by a workflow phase
+///
+/// The message names the phase and the artifact path relative to the
+/// repository root, e.g. `docs(speckit): plan specs/001-user-auth/plan.md`.
+pub async fn commit_artifact(path: &Path, phase: &str) -> Result<CommitOutcome> {
+    let parent = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let Some(repo) = GitRepo::discover(parent) else {
+        return Ok(CommitOutcome::NotARepository);
+    };
+
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let relative = path.strip_prefix(repo.root()).unwrap_or(&path);
+    let message = format!("docs(speckit): {} {}", phase, relative.display());
+
+    tracing::info!(path = %relative.display(), phase, "Committing artifact");
+    repo.commit_paths(&[relative], &message).await
+}
+
+/// Create a throwaway repository with a committer identity and an initial
+/// commit, for tests
+#[cfg(test)]
+pub(crate) fn init_test_repo(path: &Path) {
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .stdout(std::process::Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "--quiet", "--initial-branch", "main"]);
+    git(&["config", "user.name", "Spec Kit"]);
+    git(&["config", "user.email", "speckit@example.com"]);
+    git(&["config", "commit.gpgsign", "false"]);
+    git(&["commit", "--quiet", "--allow-empty", "-m", "Initial commit"]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_switch_branch() {
+        let dir = tempdir().unwrap();
+        init_test_repo(dir.path());
+        let repo = GitRepo::discover(dir.path()).unwrap();
+        assert_eq!(repo.current_branch().as_deref(), Some("main"));
+
+        let switch = repo.switch_branch("001-user-auth").await.unwrap();
+        assert_eq!(switch, BranchSwitch::Created("001-user-auth".to_string()));
+        assert_eq!(repo.current_branch().as_deref(), Some("001-user-auth"));
+
+        let switch = repo.switch_branch("001-user-auth").await.unwrap();
+        assert_eq!(switch, BranchSwitch::Unchanged("001-user-auth".to_string()));
+        assert!(repo.branch_exists("001-user-auth").await.unwrap());
+        assert!(!repo.branch_exists("002-billing").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_commit_artifact() {
+        let dir = tempdir().unwrap();
+        init_test_repo(dir.path());
+        let spec = dir.path().join("specs/001-login/spec.md");
+        std::fs::create_dir_all(spec.parent().unwrap()).unwrap();
+        std::fs::write(&spec, "# Spec\n").unwrap();
+        std::fs::write(dir.path().join("unrelated.txt"), "untouched").unwrap();
+
+        let outcome = commit_artifact(&spec, "specify").await.unwrap();
+        assert!(matches!(outcome, CommitOutcome::Committed(_)));
+        assert_eq!(
+            commit_artifact(&spec, "specify").await.unwrap(),
+            CommitOutcome::NoChanges
+        );
+
+        let repo = GitRepo::discover(dir.path()).unwrap();
+        let log = repo.git(&["log", "-1", "--format=%s"]).await.unwrap();
+        assert_eq!(
+            log.stdout.trim(),
+            "docs(speckit): specify specs/001-login/spec.md"
+        );
+        let status = repo.git(&["status", "--porcelain"]).await.unwrap();
+        assert!(status.stdout.contains("?? unrelated.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_commit_outside_repository() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("spec.md");
+        std::fs::write(&path, "# Spec\n").unwrap();
+
+        assert_eq!(
+            commit_artifact(&path, "specify").await.unwrap(),
+            CommitOutcome::NotARepository
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Spec-Kit CLI Invocation
+//!
+//! Working directory and environment for spec-kit commands. Commands run
+//! with a cleared environment: only allow-listed variables are passed
+//! through, then the defaults, configured overrides and per-call overrides
+//! are applied in that order.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::agents::{AiAgent, ScriptType};
+use crate::artifacts::{Feature, FEATURE_ENV};
+
+/// Variables passed through to spec-kit commands; a trailing `*` matches a
+/// prefix
+pub const ENV_ALLOW_LIST: &[&str] = &[
+    // Process basics
+    "PATH",
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "SHELL",
+    "TMPDIR",
+    "TEMP",
+    "TMP",
+    "LANG",
+    "LC_*",
+    "XDG_*",
+    // Windows
+    "SYSTEMROOT",
+    "SYSTEMDRIVE",
+    "COMSPEC",
+    "PATHEXT",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+    // uv and Python
+    "UV_*",
+    "PYTHON*",
+    "VIRTUAL_ENV",
+    // Network and certificates
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "NO_PROXY",
+    "ALL_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+    "all_proxy",
+    "SSL_CERT_FILE",
+    "SSL_CERT_DIR",
+    "REQUESTS_CA_BUNDLE",
+    // spec-kit
+    "GH_TOKEN",
+    "GITHUB_TOKEN",
+    "SPECIFY_*",
+    "GIT_*",
+];
+
+/// Variables set for every command unless overridden
+pub const DEFAULT_ENV: &[(&str, &str)] = &[("NO_COLOR", "1")];
+
+/// Per-call settings for a spec-kit command
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOptions {
+    /// Working directory, checked against the path policy; the policy's
+    /// base directory when unset
+    pub cwd: Option<PathBuf>,
+
+    /// Environment overrides, applied last
+    pub env: Vec<(String, String)>,
+}
+
+impl CommandOptions {
+    /// Run in a directory
+    pub fn in_dir(cwd: impl Into<PathBuf>) -> Self {
+        Self {
+            cwd: Some(cwd.into()),
+            env: Vec::new(),
+        }
+    }
+
+    /// Set an environment variable
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Name the active feature through `SPECIFY_FEATURE`
+    pub fn with_feature(self, feature: Option<&Feature>) -> Self {
+        match feature {
+            Some(feature) => self.with_env(FEATURE_ENV, &feature.name),
+            None => self,
+        }
+    }
+}
+
+/// Options of `specify init`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InitOptions {
+    /// Project name; `specify init <name>` creates `<directory>/<name>`
+    pub project_name: Option<String>,
+
+    /// Directory the command runs in
+    pub directory: PathBuf,
+
+    /// Initialize `directory` itself (`--here`) instead of a new subdirectory
+    pub here: bool,
+
+    /// AI agent to set up (`--ai`)
+    pub ai: Option<AiAgent>,
+
+    /// Script flavor (`--script`)
+    pub script: Option<ScriptType>,
+
+    /// Skip git repository initialization (`--no-git`)
+    pub no_git: bool,
+
+    /// Skip the check for the agent's CLI tool (`--ignore-agent-tools`)
+    pub ignore_agent_tools: bool,
+}
+
+impl InitOptions {
+    /// Directory the project ends up in
+    pub fn project_dir(&self) -> PathBuf {
+        match (&self.project_name, self.here) {
+            (Some(name), false) => self.directory.join(name),
+            _ => self.directory.clone(),
+        }
+    }
+
+    /// Arguments after `specify`
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec!["init".to_string()];
+        match (&self.project_name, self.here) {
+            (Some(name), false) => args.push(name.clone()),
+            _ => args.push("--here".to_string()),
+        }
+        if let Some(ai) = self.ai {
+            args.extend(["--ai".to_string(), ai.key().to_string()]);
+        }
+        if let Some(script) = self.script {
+            args.extend(["--script".to_string(), script.key().to_string()]);
+        }
+        if self.no_git {
+            args.push("--no-git".to_string());
+        }
+        if self.ignore_agent_tools {
+            args.push("--ignore-agent-tools".to_string());
+        }
+        args
+    }
+}
+
+/// Whether a variable name matches an allow-list pattern
+pub fn env_allowed(name: &str, patterns: &[impl AsRef<str>]) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match pattern.as_ref().strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => name == pattern.as_ref(),
+        })
+}
+
+/// Parse `KEY=VALUE` entries; entries without `=` or with an empty key are
+/// skipped with a warning
+pub fn parse_env_entries(entries: &[String]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Some((key.to_string(), value.to_string())),
+            _ => {
+                tracing::warn!(entry = %entry, "Ignoring invalid cli.env entry, expected KEY=VALUE");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Environment for a command: allow-listed variables from `inherited`, then
+/// [`DEFAULT_ENV`], the configured overrides and the per-call overrides
+pub fn command_env(
+    inherited: impl IntoIterator<Item = (String, String)>,
+    extra_allow: &[String],
+    configured: &BTreeMap<String, String>,
+    overrides: &[(String, String)],
+) -> BTreeMap<String, String> {
+    let mut env: BTreeMap<String, String> = inherited
+        .into_iter()
+        .filter(|(name, _)| env_allowed(name, ENV_ALLOW_LIST) || env_allowed(name, extra_allow))
+        .collect();
+    for (name, value) in DEFAULT_ENV {
+        env.insert(name.to_string(), value.to_string());
+    }
+    env.extend(configured.iter().map(|(k, v)| (k.clone(), v.clone())));
+    env.extend(overrides.iter().cloned());
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_command_env_layers() {
+        let inherited = vars(&[
+            ("PATH", "/usr/bin"),
+            ("UV_CACHE_DIR", "/cache"),
+            ("GH_TOKEN", "secret"),
+            ("AWS_SECRET_ACCESS_KEY", "hidden"),
+            ("CORP_PROXY", "proxy"),
+            ("NO_COLOR", "0"),
+        ]);
+        let configured = BTreeMap::from([("TERM".to_string(), "dumb".to_string())]);
+        let overrides = vars(&[("SPECIFY_FEATURE", "001-auth")]);
+
+        let env = command_env(inherited, &["CORP_*".to_string()], &configured, &overrides);
+
+        assert_eq!(env["PATH"], "/usr/bin");
+        assert_eq!(env["UV_CACHE_DIR"], "/cache");
+        assert_eq!(env["GH_TOKEN"], "secret");
+        assert_eq!(env["CORP_PROXY"], "proxy");
+        assert!(!env.contains_key("AWS_SECRET_ACCESS_KEY"));
+        assert_eq!(env["NO_COLOR"], "1");
+        assert_eq!(env["TERM"], "dumb");
+        assert_eq!(env["SPECIFY_FEATURE"], "001-auth");
+    }
+
+    #[test]
+    fn test_parse_env_entries() {
+        let entries = vec![
+            "GH_HOST=github.example.com".to_string(),
+            "EMPTY=".to_string(),
+            "A=b=c".to_string(),
+            "invalid".to_string(),
+            "=value".to_string(),
+        ];
+        let env = parse_env_entries(&entries);
+        assert_eq!(env.len(), 3);
+        assert_eq!(env["GH_HOST"], "github.example.com");
+        assert_eq!(env["EMPTY"], "");
+        assert_eq!(env["A"], "b=c");
+    }
+
+    #[test]
+    fn test_init_args() {
+        let options = InitOptions {
+            project_name: Some("demo".to_string()),
+            directory: PathBuf::from("/work"),
+            ai: Some(AiAgent::Claude),
+            script: Some(ScriptType::Sh),
+            no_git: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.args(),
+            ["init", "demo", "--ai", "claude", "--script", "sh", "--no-git"]
+        );
+        assert_eq!(options.project_dir(), PathBuf::from("/work/demo"));
+
+        let here = InitOptions {
+            project_name: Some("demo".to_string()),
+            directory: PathBuf::from("/work"),
+            here: true,
+            ignore_agent_tools: true,
+            ..Default::default()
+        };
+        assert_eq!(here.args(), ["init", "--here", "--ignore-agent-tools"]);
+        assert_eq!(here.project_dir(), PathBuf::from("/work"));
+    }
+}
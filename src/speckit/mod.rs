@@ -2,8 +2,18 @@
 //!
 //! This module provides integration with the spec-kit Python CLI.
 
+pub mod agents;
 pub mod cli;
 pub mod errors;
+pub mod git;
+pub mod invocation;
+pub mod output;
+pub mod probe;
 
+pub use agents::*;
 pub use cli::*;
 pub use errors::*;
+pub use git::*;
+pub use invocation::*;
+pub use output::*;
+pub use probe::*;
@@ -0,0 +1,435 @@
+//! Spec-Kit CLI Output
+//!
+//! The `specify` CLI prints Rich-formatted output: ANSI colors, console
+//! markup such as `[bold green]...[/]`, and panels drawn with box characters.
+//! This module reduces that output to plain text and extracts the files
+//! created, warnings and next-step hints into a [`CommandOutcome`].
+
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// Rich style words that may appear in console markup tags
+const STYLE_WORDS: &[&str] = &[
+    "bold",
+    "dim",
+    "italic",
+    "underline",
+    "strike",
+    "blink",
+    "reverse",
+    "not",
+    "on",
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "grey",
+    "gray",
+    "default",
+];
+
+/// Verbs that introduce a created file (`Created: path`, `Wrote path`)
+const CREATED_PREFIXES: &[&str] = &["created", "creating", "wrote", "writing", "generated"];
+
+/// Structured view of a spec-kit command's output
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CommandOutcome {
+    /// Whether the command exited successfully
+    pub success: bool,
+
+    /// Exit code
+    pub exit_code: i32,
+
+    /// Files the command reported creating
+    pub files_created: Vec<PathBuf>,
+
+    /// Warnings printed by the command
+    pub warnings: Vec<String>,
+
+    /// Next-step hints printed by the command
+    pub next_steps: Vec<String>,
+
+    /// Plain-text output (stdout, then stderr) without colors, markup or boxes
+    pub text: String,
+}
+
+impl CommandOutcome {
+    /// Parse the output of a command
+    pub fn parse(stdout: &str, stderr: &str, exit_code: i32) -> Self {
+        let stdout = clean_output(stdout);
+        let stderr = clean_output(stderr);
+        let text = [stdout.as_str(), stderr.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut outcome = Self {
+            success: exit_code == 0,
+            exit_code,
+            text,
+            ..Default::default()
+        };
+        outcome.extract();
+        outcome
+    }
+
+    /// Short summary for failure messages: the cleaned output, or the exit
+    /// code when there was none
+    pub fn error_text(&self) -> String {
+        if self.text.is_empty() {
+            format!("exit code {}", self.exit_code)
+        } else {
+            self.text.clone()
+        }
+    }
+
+    /// Markdown sections for tool results; empty when nothing was extracted
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        if !self.files_created.is_empty() {
+            markdown.push_str("Files created:\n");
+            for file in &self.files_created {
+                let _ = writeln!(markdown, "- {}", file.display());
+            }
+        }
+        if !self.warnings.is_empty() {
+            if !markdown.is_empty() {
+                markdown.push('\n');
+            }
+            markdown.push_str("Warnings:\n");
+            for warning in &self.warnings {
+                let _ = writeln!(markdown, "- {}", warning);
+            }
+        }
+        if !self.next_steps.is_empty() {
+            if !markdown.is_empty() {
+                markdown.push('\n');
+            }
+            markdown.push_str("Next steps from spec-kit:\n");
+            for (index, step) in self.next_steps.iter().enumerate() {
+                let _ = writeln!(markdown, "{}. {}", index + 1, step);
+            }
+        }
+        markdown.trim_end().to_string()
+    }
+
+    /// Fill the files, warnings and next steps from the text
+    fn extract(&mut self) {
+        let mut in_next_steps = false;
+        let mut in_files = false;
+
+        for line in self.text.lines() {
+            let trimmed = line.trim();
+            let lower = trimmed.to_lowercase();
+
+            if trimmed.is_empty() {
+                // A blank line ends a list once it has items
+                if in_next_steps && !self.next_steps.is_empty() {
+                    in_next_steps = false;
+                }
+                in_files = false;
+                continue;
+            }
+
+            if let Some(warning) = warning_text(trimmed) {
+                self.warnings.push(warning);
+                continue;
+            }
+
+            if lower.starts_with("next step") {
+                in_next_steps = true;
+                in_files = false;
+                if let Some((_, rest)) = trimmed.split_once(':') {
+                    if !rest.trim().is_empty() {
+                        self.next_steps.push(rest.trim().to_string());
+                    }
+                }
+                continue;
+            }
+
+            if lower.trim_end_matches(':').ends_with("files created")
+                || lower.trim_end_matches(':').ends_with("created files")
+            {
+                in_files = true;
+                in_next_steps = false;
+                continue;
+            }
+
+            if let Some(path) = created_file(trimmed) {
+                self.files_created.push(path);
+                continue;
+            }
+
+            if in_files {
+                if let Some(item) = list_item(trimmed) {
+                    self.files_created.push(PathBuf::from(item));
+                    continue;
+                }
+                in_files = false;
+            }
+
+            if in_next_steps {
+                match list_item(trimmed) {
+                    Some(item) => self.next_steps.push(item.to_string()),
+                    // Continuation of a wrapped item
+                    None => match self.next_steps.last_mut() {
+                        Some(last) => {
+                            last.push(' ');
+                            last.push_str(trimmed);
+                        }
+                        None => self.next_steps.push(trimmed.to_string()),
+                    },
+                }
+            }
+        }
+
+        self.files_created.dedup();
+    }
+}
+
+/// Reduce Rich output to plain text: strip ANSI escapes, console markup and
+/// box drawing, trim lines and collapse runs of blank lines
+pub fn clean_output(raw: &str) -> String {
+    let text = strip_rich_markup(&strip_ansi(raw));
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = strip_box_drawing(line);
+        if line.is_empty() && lines.last().map_or(true, |l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Remove ANSI escape sequences (CSI, OSC and two-character escapes)
+pub fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            if c != '\r' {
+                result.push(c);
+            }
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters, then a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Remove Rich console markup tags such as `[bold cyan]`, `[/]` and
+/// `[link=https://...]`, keeping other bracketed text like `[x]`
+pub fn strip_rich_markup(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find(']') {
+            Some(end) if is_markup_tag(&after[..end]) => {
+                rest = &after[end + 1..];
+            }
+            _ => {
+                result.push('[');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Whether the inside of a `[...]` is a Rich style tag
+fn is_markup_tag(tag: &str) -> bool {
+    let tag = tag.trim();
+    if tag == "/" {
+        return true;
+    }
+    let tag = tag.strip_prefix('/').unwrap_or(tag);
+    if tag.starts_with("link") {
+        return tag == "link" || tag.starts_with("link=");
+    }
+    !tag.is_empty()
+        && tag.split_whitespace().all(|word| {
+            let word = word.strip_prefix("bright_").unwrap_or(word);
+            STYLE_WORDS.contains(&word)
+                || word.starts_with('#')
+                || word.starts_with("rgb(")
+                || word.starts_with("color(")
+                || (word.starts_with("grey") || word.starts_with("gray"))
+                    && word[4..].chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+/// Replace box-drawing and block characters with spaces and trim the line
+fn strip_box_drawing(line: &str) -> String {
+    line.chars()
+        .map(|c| match c {
+            '\u{2500}'..='\u{259f}' => ' ',
+            _ => c,
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Warning text of a `Warning: ...` or `⚠ ...` line
+fn warning_text(line: &str) -> Option<String> {
+    let unmarked = line.trim_start_matches(['⚠', '\u{fe0f}']).trim_start();
+    let has_symbol = unmarked.len() < line.len();
+    let text = match unmarked.get(..7) {
+        Some(word) if word.eq_ignore_ascii_case("warning") => {
+            let rest = &unmarked[7..];
+            if !(rest.is_empty() || rest.starts_with([':', ' '])) {
+                return None;
+            }
+            rest.trim_start_matches(':').trim()
+        }
+        _ if has_symbol => unmarked,
+        _ => return None,
+    };
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Path of a `Created: path` / `✓ Wrote path` line
+fn created_file(line: &str) -> Option<PathBuf> {
+    let line = line
+        .trim_start_matches(['✓', '✔', '•', '-', '*'])
+        .trim_start();
+    let (verb, rest) = line.split_once([' ', ':'])?;
+    if !CREATED_PREFIXES.contains(&verb.to_lowercase().as_str()) {
+        return None;
+    }
+    let rest = rest.trim_start_matches(':').trim();
+    let rest = rest
+        .strip_prefix("file ")
+        .or_else(|| rest.strip_prefix("file: "))
+        .unwrap_or(rest)
+        .trim_matches(['`', '\'', '"']);
+    // A path is a single token with a separator or an extension
+    if rest.is_empty() || rest.contains(' ') || !(rest.contains('/') || rest.contains('.')) {
+        return None;
+    }
+    Some(PathBuf::from(rest))
+}
+
+/// Text of a `- item`, `• item` or `1. item` line
+fn list_item(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "• ", "● "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some(rest.trim());
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        let rest = &line[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some(rest.trim());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INIT_OUTPUT: &str = "\u{1b}[1;36m╭──────── Next Steps ────────╮\u{1b}[0m\n\
+        │ 1. Go to the project folder: [cyan]cd demo[/cyan] │\n\
+        │ 2. Start using slash commands with your AI agent:  │\n\
+        │    /speckit.constitution                           │\n\
+        ╰────────────────────────────────────────────────────╯\n\
+        \n\
+        [yellow]Warning:[/yellow] Git not found - skipped repository init\n\
+        Created: .claude/commands/speckit.plan.md\n\
+        ✓ Wrote .specify/memory/constitution.md\n\
+        Files created:\n\
+        - .specify/templates/spec-template.md\n\
+        Progress [x] done\n";
+
+    #[test]
+    fn test_clean_output() {
+        let clean = clean_output(INIT_OUTPUT);
+        assert!(clean.starts_with("Next Steps\n1. Go to the project folder: cd demo\n"));
+        assert!(!clean.contains('\u{1b}'));
+        assert!(!clean.contains("[cyan]"));
+        assert!(!clean.contains('│'));
+        assert!(clean.contains("Progress [x] done"));
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let outcome = CommandOutcome::parse(INIT_OUTPUT, "", 0);
+        assert!(outcome.success);
+        assert_eq!(
+            outcome.next_steps,
+            vec![
+                "Go to the project folder: cd demo",
+                "Start using slash commands with your AI agent: /speckit.constitution",
+            ]
+        );
+        assert_eq!(
+            outcome.warnings,
+            vec!["Git not found - skipped repository init"]
+        );
+        assert_eq!(
+            outcome.files_created,
+            vec![
+                PathBuf::from(".claude/commands/speckit.plan.md"),
+                PathBuf::from(".specify/memory/constitution.md"),
+                PathBuf::from(".specify/templates/spec-template.md"),
+            ]
+        );
+
+        let markdown = outcome.to_markdown();
+        assert!(markdown.starts_with("Files created:\n- .claude/commands/speckit.plan.md\n"));
+        assert!(markdown.contains("Warnings:\n- Git not found"));
+        assert!(markdown
+            .ends_with("2. Start using slash commands with your AI agent: /speckit.constitution"));
+    }
+
+    #[test]
+    fn test_failure_text() {
+        let outcome =
+            CommandOutcome::parse("", "\u{1b}[31mError:\u{1b}[0m [bold]bad flag[/bold]\n", 2);
+        assert!(!outcome.success);
+        assert_eq!(outcome.error_text(), "Error: bad flag");
+        assert_eq!(CommandOutcome::parse("", "", 1).error_text(), "exit code 1");
+    }
+}
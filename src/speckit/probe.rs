@@ -0,0 +1,339 @@
+//! Spec-Kit CLI Availability
+//!
+//! Tracks whether the spec-kit CLI can run. The server starts without it
+//! and probes in the background, so tools that need the CLI become available
+//! once it is installed without restarting the server. Successful probes are
+//! cached on disk so later starts skip the slow `uvx` round trip.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::utils::write_atomic;
+
+/// Minimum time between probes while the CLI is missing
+pub const REPROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default lifetime of a cached probe result in seconds
+pub const DEFAULT_PROBE_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+/// Availability of the spec-kit CLI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CliStatus {
+    /// Not probed yet
+    #[default]
+    Unknown,
+
+    /// The CLI runs
+    Available,
+
+    /// The CLI could not be run at the last probe
+    Missing,
+}
+
+impl fmt::Display for CliStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliStatus::Unknown => write!(f, "unknown"),
+            CliStatus::Available => write!(f, "available"),
+            CliStatus::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Where the recorded status came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeSource {
+    /// Running the CLI in this process
+    Probe,
+
+    /// The on-disk probe cache
+    Cache,
+}
+
+impl fmt::Display for ProbeSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeSource::Probe => write!(f, "probe"),
+            ProbeSource::Cache => write!(f, "cache"),
+        }
+    }
+}
+
+/// Outcome of running the CLI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProbeResult {
+    /// Whether the CLI ran
+    pub available: bool,
+
+    /// Version reported by the CLI
+    pub version: Option<String>,
+
+    /// Time the probe took
+    pub duration: Duration,
+
+    /// When the probe ran
+    pub checked_at: SystemTime,
+}
+
+/// Snapshot of the probe state, reported by `speckit_check`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProbeReport {
+    /// Current status
+    pub status: CliStatus,
+
+    /// Version reported by the CLI
+    pub version: Option<String>,
+
+    /// Where the status came from
+    pub source: Option<ProbeSource>,
+
+    /// When the recorded probe ran
+    pub checked_at: Option<SystemTime>,
+
+    /// Time the recorded probe took
+    pub duration: Option<Duration>,
+
+    /// Whether a probe is running now
+    pub running: bool,
+}
+
+/// Probe state shared by every clone of a [`SpecKitCli`](super::SpecKitCli)
+#[derive(Debug, Clone, Default)]
+pub struct CliProbe {
+    state: Arc<Mutex<ProbeState>>,
+
+    /// Held while a probe runs so concurrent callers share its result
+    running: Arc<tokio::sync::Mutex<()>>,
+}
+
+#[derive(Debug, Default)]
+struct ProbeState {
+    status: CliStatus,
+    recorded_at: Option<Instant>,
+    result: Option<ProbeResult>,
+    source: Option<ProbeSource>,
+}
+
+impl CliProbe {
+    /// Create an unprobed state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current status
+    pub fn status(&self) -> CliStatus {
+        self.state.lock().unwrap().status
+    }
+
+    /// Record a probe result and return the new status
+    pub fn record(&self, result: ProbeResult, source: ProbeSource) -> CliStatus {
+        let mut state = self.state.lock().unwrap();
+        state.status = if result.available {
+            CliStatus::Available
+        } else {
+            CliStatus::Missing
+        };
+        state.recorded_at = Some(Instant::now());
+        state.result = Some(result);
+        state.source = Some(source);
+        state.status
+    }
+
+    /// Whether the CLI should be probed: never probed, or missing at a probe
+    /// older than `interval`
+    pub fn needs_probe(&self, interval: Duration) -> bool {
+        let state = self.state.lock().unwrap();
+        match state.status {
+            CliStatus::Unknown => true,
+            CliStatus::Available => false,
+            CliStatus::Missing => state
+                .recorded_at
+                .map_or(true, |recorded_at| recorded_at.elapsed() >= interval),
+        }
+    }
+
+    /// Snapshot of the state
+    pub fn report(&self) -> ProbeReport {
+        let state = self.state.lock().unwrap();
+        ProbeReport {
+            status: state.status,
+            version: state.result.as_ref().and_then(|r| r.version.clone()),
+            source: state.source,
+            checked_at: state.result.as_ref().map(|r| r.checked_at),
+            duration: state.result.as_ref().map(|r| r.duration),
+            running: self.running.try_lock().is_err(),
+        }
+    }
+
+    /// Serialize probes: the guard is held while a probe runs
+    pub(crate) async fn lock(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.running.lock().await
+    }
+}
+
+/// A successful probe stored on disk, valid for one launcher and source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedProbe {
+    /// Launcher command the probe ran
+    pub launcher: String,
+
+    /// Package source the probe ran
+    pub source: String,
+
+    /// Version reported by the CLI
+    pub version: Option<String>,
+
+    /// When the probe ran, in seconds since the Unix epoch
+    pub checked_at: u64,
+
+    /// Time the probe took, in milliseconds
+    pub duration_ms: u64,
+}
+
+impl CachedProbe {
+    /// Cache entry for a successful probe
+    pub fn new(launcher: &str, source: &str, result: &ProbeResult) -> Self {
+        Self {
+            launcher: launcher.to_string(),
+            source: source.to_string(),
+            version: result.version.clone(),
+            checked_at: result
+                .checked_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            duration_ms: result.duration.as_millis() as u64,
+        }
+    }
+
+    /// Whether the entry applies to a launcher and source and is younger
+    /// than `ttl`
+    pub fn is_valid(&self, launcher: &str, source: &str, ttl: Duration) -> bool {
+        let age = SystemTime::now()
+            .duration_since(UNIX_EPOCH + Duration::from_secs(self.checked_at))
+            .unwrap_or_default();
+        self.launcher == launcher && self.source == source && age < ttl
+    }
+
+    /// The cached probe as a result
+    pub fn result(&self) -> ProbeResult {
+        ProbeResult {
+            available: true,
+            version: self.version.clone(),
+            duration: Duration::from_millis(self.duration_ms),
+            checked_at: UNIX_EPOCH + Duration::from_secs(self.checked_at),
+        }
+    }
+
+    /// Read a cache file; a missing or unreadable file yields `None`
+    pub async fn load(path: &Path) -> Option<Self> {
+        let content = tokio::fs::read_to_string(path).await.ok()?;
+        serde_json::from_str(&content)
+            .map_err(|e| tracing::debug!(error = %e, path = %path.display(), "Ignoring invalid probe cache"))
+            .ok()
+    }
+
+    /// Write the cache file
+    pub async fn store(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        write_atomic(path, serde_json::to_string_pretty(self)?).await
+    }
+}
+
+/// Default probe cache (`<cache dir>/spec-kit-mcp/cli-probe.json`)
+pub fn probe_cache_file() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("spec-kit-mcp").join("cli-probe.json"))
+}
+
+/// First version number (`1.2` or `1.2.3`) in CLI output
+pub fn parse_version(output: &str) -> Option<String> {
+    output
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+        .map(|word| word.trim_start_matches('v').trim_end_matches('.'))
+        .find(|word| {
+            let parts: Vec<&str> = word.split('.').collect();
+            parts.len() >= 2
+                && parts
+                    .iter()
+                    .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn result(available: bool) -> ProbeResult {
+        ProbeResult {
+            available,
+            version: available.then(|| "0.0.20".to_string()),
+            duration: Duration::from_millis(1500),
+            checked_at: SystemTime::now(),
+        }
+    }
+
+    #[test]
+    fn test_probe_state() {
+        let probe = CliProbe::new();
+        assert_eq!(probe.status(), CliStatus::Unknown);
+        assert!(probe.needs_probe(REPROBE_INTERVAL));
+
+        assert_eq!(
+            probe.record(result(false), ProbeSource::Probe),
+            CliStatus::Missing
+        );
+        assert!(!probe.needs_probe(REPROBE_INTERVAL));
+        assert!(probe.needs_probe(Duration::ZERO));
+
+        let shared = probe.clone();
+        shared.record(result(true), ProbeSource::Cache);
+        assert_eq!(probe.status(), CliStatus::Available);
+        assert!(!probe.needs_probe(Duration::ZERO));
+
+        let report = probe.report();
+        assert_eq!(report.version.as_deref(), Some("0.0.20"));
+        assert_eq!(report.source, Some(ProbeSource::Cache));
+        assert!(!report.running);
+    }
+
+    #[tokio::test]
+    async fn test_probe_cache_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cache/cli-probe.json");
+        let ttl = Duration::from_secs(DEFAULT_PROBE_TTL_SECONDS);
+
+        let entry = CachedProbe::new("uvx", "git+spec-kit", &result(true));
+        entry.store(&path).await.unwrap();
+
+        let loaded = CachedProbe::load(&path).await.unwrap();
+        assert_eq!(loaded, entry);
+        assert!(loaded.is_valid("uvx", "git+spec-kit", ttl));
+        assert!(!loaded.is_valid("uvx", "git+fork", ttl));
+        assert!(!loaded.is_valid("uvx", "git+spec-kit", Duration::ZERO));
+        assert_eq!(loaded.result().version.as_deref(), Some("0.0.20"));
+
+        tokio::fs::write(&path, "not json").await.unwrap();
+        assert!(CachedProbe::load(&path).await.is_none());
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("│ CLI Version   0.0.20 │\n│ Template   v0.0.19. │"),
+            Some("0.0.20".to_string())
+        );
+        assert_eq!(parse_version("specify v1.2"), Some("1.2".to_string()));
+        assert_eq!(parse_version("no version here, 3 items"), None);
+    }
+}
@@ -6,8 +6,11 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::artifacts::{
+    analyze, AnalysisOptions, ArtifactKind, ArtifactSet, ProjectLayout, Severity,
+};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
 use crate::tools::Tool;
@@ -16,6 +19,7 @@ use crate::tools::Tool;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AnalyzeParams {
     /// Path to project directory
+    #[serde(default = "default_project_path")]
     project_path: PathBuf,
 
     /// Check consistency across artifacts
@@ -26,22 +30,25 @@ pub struct AnalyzeParams {
     #[serde(default = "default_true")]
     check_coverage: bool,
 
-    /// Output path for analysis report
-    #[serde(default = "default_analyze_path")]
-    output_path: PathBuf,
+    /// Output path for analysis report (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Output path for the JSON report (defaults to the output path with a `.json` extension)
+    #[serde(default)]
+    json_output_path: Option<PathBuf>,
 }
 
 fn default_true() -> bool {
     true
 }
 
-fn default_analyze_path() -> PathBuf {
-    PathBuf::from("./speckit.analyze")
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
 }
 
 /// Tool for analyzing spec-kit artifacts
 pub struct AnalyzeTool {
-    #[allow(dead_code)]
     cli: SpecKitCli,
 }
 
@@ -57,13 +64,14 @@ impl Tool for AnalyzeTool {
     fn definition(&self) -> ToolDefinition {
         ToolDefinition {
             name: "speckit_analyze".to_string(),
-            description: "Analyze cross-artifact consistency and coverage - ensures constitution, specs, plans, and tasks are aligned".to_string(),
+            description: "Analyze cross-artifact consistency and coverage - reports duplicate requirements, ambiguous language, terminology drift, constitution violations, underspecified tasks and coverage gaps with CRITICAL/HIGH/MEDIUM/LOW severities".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "project_path": {
                         "type": "string",
-                        "description": "Path to the project directory containing spec-kit artifacts"
+                        "description": "Path to the project directory containing spec-kit artifacts (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     },
                     "check_consistency": {
                         "type": "boolean",
@@ -77,11 +85,14 @@ impl Tool for AnalyzeTool {
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where analysis report will be written",
-                        "default": "./speckit.analyze"
+                        "description": "Path where the markdown analysis report will be written (defaults to the active feature's analysis.md, or ./speckit.analyze)"
+                    },
+                    "json_output_path": {
+                        "type": "string",
+                        "description": "Path where the JSON analysis report will be written (defaults to the output path with a .json extension, e.g. analysis.json or speckit.analyze.json; must differ from output_path)"
                     }
                 },
-                "required": ["project_path"]
+                "required": []
             })
         }
     }
@@ -95,144 +106,100 @@ impl Tool for AnalyzeTool {
             "Analyzing project artifacts"
         );
 
-        let mut analysis = String::from("# Spec-Kit Analysis Report\n\n");
-        analysis.push_str(&format!("Project: {}\n\n", params.project_path.display()));
-
-        // Check for artifacts
-        let artifacts = vec![
-            ("Constitution", "speckit.constitution"),
-            ("Specification", "speckit.specify"),
-            ("Plan", "speckit.plan"),
-            ("Tasks", "speckit.tasks"),
-        ];
-
-        analysis.push_str("## Artifact Status\n\n");
-
-        let mut found_artifacts = Vec::new();
-        for (name, filename) in &artifacts {
-            let path = params.project_path.join(filename);
-            if path.exists() {
-                analysis.push_str(&format!("✓ {} found\n", name));
-                found_artifacts.push((*name, path));
-            } else {
-                analysis.push_str(&format!("✗ {} missing\n", name));
-            }
-        }
-
-        // Consistency checks
-        if params.check_consistency && found_artifacts.len() >= 2 {
-            analysis.push_str("\n## Consistency Analysis\n\n");
-
-            // Read all artifacts
-            let mut contents = Vec::new();
-            for (name, path) in &found_artifacts {
-                match tokio::fs::read_to_string(path).await {
-                    Ok(content) => contents.push((*name, content)),
-                    Err(e) => analysis.push_str(&format!("⚠ Failed to read {}: {}\n", name, e)),
-                }
-            }
-
-            // Check for keywords mentioned in spec but missing in plan
-            if contents.len() >= 2 {
-                let spec_content = contents
-                    .iter()
-                    .find(|(n, _)| n.contains("Spec"))
-                    .map(|(_, c)| c);
-                let plan_content = contents
-                    .iter()
-                    .find(|(n, _)| n.contains("Plan"))
-                    .map(|(_, c)| c);
-
-                if let (Some(spec), Some(plan)) = (spec_content, plan_content) {
-                    // Extract key terms from spec
-                    let important_terms = spec
-                        .split_whitespace()
-                        .filter(|w| w.len() > 5 && w.chars().next().unwrap().is_uppercase())
-                        .take(10)
-                        .collect::<Vec<_>>();
-
-                    let mut missing = Vec::new();
-                    for term in important_terms {
-                        if !plan.contains(term) {
-                            missing.push(term);
-                        }
-                    }
-
-                    if missing.is_empty() {
-                        analysis.push_str("✓ Key terms from specification are addressed in plan\n");
-                    } else {
-                        analysis.push_str("⚠ Terms in spec but not in plan:\n");
-                        for term in missing {
-                            analysis.push_str(&format!("  - {}\n", term));
-                        }
-                    }
-                }
-            }
-        }
-
-        // Coverage checks
-        if params.check_coverage {
-            analysis.push_str("\n## Coverage Analysis\n\n");
-
-            if found_artifacts
-                .iter()
-                .any(|(n, _)| n.contains("Specification"))
-            {
-                analysis.push_str("✓ Requirements are specified\n");
-            } else {
-                analysis.push_str("✗ Missing specification\n");
-            }
-
-            if found_artifacts.iter().any(|(n, _)| n.contains("Plan")) {
-                analysis.push_str("✓ Technical plan exists\n");
-            } else {
-                analysis.push_str("✗ Missing technical plan\n");
-            }
-
-            if found_artifacts.iter().any(|(n, _)| n.contains("Tasks")) {
-                analysis.push_str("✓ Tasks are defined\n");
-            } else {
-                analysis.push_str("✗ Missing task breakdown\n");
-            }
-        }
-
-        // Recommendations
-        analysis.push_str("\n## Recommendations\n\n");
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let artifacts = ArtifactSet::load_layout(&layout, self.cli.paths()).await?;
+        let report = analyze(
+            &artifacts,
+            AnalysisOptions {
+                check_consistency: params.check_consistency,
+                check_coverage: params.check_coverage,
+            },
+        );
 
-        if found_artifacts.len() < 4 {
-            analysis.push_str("1. Complete missing artifacts\n");
+        let markdown = report.to_markdown();
+        let json = report.to_json();
+        let output_path = params
+            .output_path
+            .clone()
+            .unwrap_or_else(|| layout.output_path("analysis.md", "speckit.analyze"));
+        let json_output_path = params
+            .json_output_path
+            .clone()
+            .unwrap_or_else(|| json_path_for(&output_path));
+        if self.cli.paths().check(&output_path)? == self.cli.paths().check(&json_output_path)? {
+            anyhow::bail!(
+                "output_path and json_output_path are both {}; pass a different json_output_path",
+                output_path.display()
+            );
         }
-        analysis.push_str("2. Review consistency issues (if any)\n");
-        analysis.push_str("3. Ensure all requirements are covered in tasks\n");
-        analysis.push_str("4. Keep artifacts updated as project evolves\n");
 
         // Write analysis
-        tokio::fs::write(&params.output_path, &analysis)
+        self.cli
+            .paths()
+            .write(&output_path, &markdown)
             .await
             .context("Failed to write analysis")?;
+        self.cli
+            .paths()
+            .write(&json_output_path, &json)
+            .await
+            .context("Failed to write JSON analysis")?;
+
+        tracing::info!(
+            findings = report.findings.len(),
+            critical = report.count(Severity::Critical),
+            "Analysis complete"
+        );
 
         let message = format!(
             "Analysis complete!\n\n\
             Artifacts found: {}/{}\n\
-            Report: {}\n\n\
+            Findings: {} CRITICAL, {} HIGH, {} MEDIUM, {} LOW\n\
+            Requirement coverage: {}%\n\
+            Report: {}\n\
+            JSON: {}\n\n\
             {}",
-            found_artifacts.len(),
-            artifacts.len(),
-            params.output_path.display(),
-            if found_artifacts.len() == artifacts.len() {
-                "✓ All artifacts present"
+            report.metrics.artifacts,
+            ArtifactKind::ALL.len(),
+            report.count(Severity::Critical),
+            report.count(Severity::High),
+            report.count(Severity::Medium),
+            report.count(Severity::Low),
+            report.metrics.coverage_percent,
+            output_path.display(),
+            json_output_path.display(),
+            if report.has_blocking_issues() {
+                "⚠ Resolve CRITICAL issues before implementation"
             } else {
-                "⚠ Some artifacts are missing"
+                "✓ No blocking issues"
             }
         );
 
         Ok(ToolResult {
-            content: vec![ContentBlock::text(message)],
+            content: vec![
+                ContentBlock::text(message),
+                ContentBlock::text(markdown),
+                ContentBlock::text(json),
+            ],
             is_error: None,
         })
     }
 }
 
+/// Default JSON report path: `analysis.md` → `analysis.json`, while other
+/// names (such as the flat layout's `speckit.analyze`) get `.json` appended
+fn json_path_for(output_path: &Path) -> PathBuf {
+    if output_path
+        .extension()
+        .is_some_and(|extension| extension == "md")
+    {
+        return output_path.with_extension("json");
+    }
+    let mut path = output_path.as_os_str().to_owned();
+    path.push(".json");
+    PathBuf::from(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,13 +231,134 @@ mod tests {
             .await
             .unwrap();
 
+        let output_path = dir.path().join("analysis.md");
         let params = json!({
             "project_path": dir.path().to_str().unwrap(),
             "check_consistency": true,
-            "check_coverage": true
+            "check_coverage": true,
+            "output_path": output_path.to_str().unwrap()
         });
 
         let result = tool.execute(params).await.unwrap();
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
+        assert!(output_path.exists());
+        assert!(dir.path().join("analysis.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_flat_layout_json_path() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = AnalyzeTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("speckit.specify"), "Requirements")
+            .await
+            .unwrap();
+
+        tool.execute(json!({ "project_path": dir.path().to_str().unwrap() }))
+            .await
+            .unwrap();
+        assert!(dir.path().join("speckit.analyze").exists());
+        assert!(dir.path().join("speckit.analyze.json").exists());
+        assert!(!dir.path().join("speckit.json").exists());
+
+        // A markdown path ending in .json would otherwise be overwritten
+        let output_path = dir.path().join("report.json");
+        tool.execute(json!({
+            "project_path": dir.path().to_str().unwrap(),
+            "output_path": output_path.to_str().unwrap()
+        }))
+        .await
+        .unwrap();
+        assert!(dir.path().join("report.json.json").exists());
+
+        let error = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "output_path": output_path.to_str().unwrap(),
+                "json_output_path": output_path.to_str().unwrap()
+            }))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("json_output_path"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_reports_findings() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = AnalyzeTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("speckit.specify"),
+            "- **FR-001**: Search MUST be fast\n- **FR-002**: Users MUST export reports\n",
+        )
+        .await
+        .unwrap();
+        fs::write(
+            dir.path().join("speckit.tasks"),
+            "- [ ] T001 Build the search index for FR-001\n",
+        )
+        .await
+        .unwrap();
+
+        let json_output_path = dir.path().join("analysis.json");
+        let params = json!({
+            "project_path": dir.path().to_str().unwrap(),
+            "output_path": dir.path().join("analysis.md").to_str().unwrap(),
+            "json_output_path": json_output_path.to_str().unwrap()
+        });
+        tool.execute(params).await.unwrap();
+
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(json_output_path).await.unwrap()).unwrap();
+        let findings = json["findings"].as_array().unwrap();
+
+        assert!(findings.iter().any(|f| f["category"] == "ambiguity"
+            && f["location"]["file"] == "speckit.specify"
+            && f["location"]["line"] == 1));
+        assert!(findings.iter().any(
+            |f| f["category"] == "coverage" && f["summary"] == "FR-002 has no associated task"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_speckit_layout() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = AnalyzeTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let feature = dir.path().join("specs/001-search");
+        fs::create_dir_all(dir.path().join(".specify/memory"))
+            .await
+            .unwrap();
+        fs::create_dir_all(&feature).await.unwrap();
+        fs::write(
+            dir.path().join(".specify/memory/constitution.md"),
+            "# Constitution",
+        )
+        .await
+        .unwrap();
+        fs::write(feature.join("spec.md"), "- **FR-001**: Users can search\n")
+            .await
+            .unwrap();
+
+        let params = json!({ "project_path": dir.path().to_str().unwrap() });
+        tool.execute(params).await.unwrap();
+
+        let json: Value = serde_json::from_str(
+            &fs::read_to_string(feature.join("analysis.json"))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(feature.join("analysis.md").exists());
+        assert!(!feature.join("analysis.md.json").exists());
+        assert_eq!(json["metrics"]["artifacts"], 2);
+        assert!(json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|f| f["location"]["file"] == "specs/001-search/plan.md"));
     }
 }
@@ -6,9 +6,11 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::time::{Duration, SystemTime};
 
+use crate::config::{Config, PROJECT_CONFIG_FILE};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
-use crate::speckit::SpecKitCli;
+use crate::speckit::{CliStatus, ProbeReport, SpecKitCli};
 use crate::tools::Tool;
 
 /// Parameters for the speckit_check tool
@@ -25,6 +27,14 @@ pub struct CheckParams {
     /// Check for common AI coding assistants
     #[serde(default = "default_true")]
     check_ai_tools: bool,
+
+    /// Print the effective server configuration
+    #[serde(default)]
+    show_config: bool,
+
+    /// Re-run the spec-kit CLI probe, bypassing the cache
+    #[serde(default)]
+    probe: bool,
 }
 
 fn default_true() -> bool {
@@ -37,20 +47,33 @@ impl Default for CheckParams {
             check_speckit: true,
             check_git: true,
             check_ai_tools: true,
+            show_config: false,
+            probe: false,
         }
     }
 }
 
 /// Tool for checking required tool installations
 pub struct CheckTool {
-    #[allow(dead_code)]
     cli: SpecKitCli,
+
+    /// Effective configuration, reported with `show_config`
+    config: Config,
 }
 
 impl CheckTool {
     /// Create a new check tool
     pub fn new(cli: SpecKitCli) -> Self {
-        Self { cli }
+        Self {
+            cli,
+            config: Config::default(),
+        }
+    }
+
+    /// Set the configuration reported with `show_config`
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
     }
 
     /// Check if a command is available
@@ -90,6 +113,16 @@ impl Tool for CheckTool {
                         "type": "boolean",
                         "default": true,
                         "description": "Check for AI coding assistants (claude, cursor, etc.)"
+                    },
+                    "show_config": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Print the effective server configuration and the layers it was loaded from"
+                    },
+                    "probe": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Re-run the spec-kit CLI probe now, bypassing the on-disk cache (can take tens of seconds on a cold uvx cache)"
                     }
                 },
                 "required": []
@@ -120,13 +153,25 @@ impl Tool for CheckTool {
 
             if has_uvx || has_uv {
                 report.push_str("✅ `uv`/`uvx` is available\n");
-                report.push_str("✅ Spec-kit can be run via: `uvx --from git+https://github.com/github/spec-kit.git specify`\n");
+                report.push_str(&format!(
+                    "✅ Spec-kit can be run via: `{} --from {} specify`\n",
+                    self.config.cli.path, self.config.cli.source
+                ));
             } else {
                 report.push_str("❌ `uv`/`uvx` not found\n");
                 report.push_str("   Install uv from: https://docs.astral.sh/uv/\n");
                 report.push_str("   Or via pip: `pip install uv`\n");
                 all_good = false;
             }
+
+            if params.probe {
+                self.cli.probe().await;
+            }
+            let probe = self.cli.probe_report();
+            report.push_str(&probe_status(&probe));
+            if probe.status == CliStatus::Missing {
+                all_good = false;
+            }
             report.push('\n');
         }
 
@@ -173,6 +218,25 @@ impl Tool for CheckTool {
             report.push('\n');
         }
 
+        // Effective configuration
+        if params.show_config {
+            report.push_str("## Configuration\n\n");
+            report.push_str(&format!(
+                "Loaded from: {}\n\n",
+                self.config.sources.join(" → ")
+            ));
+            if !self.config.ignored.is_empty() {
+                report.push_str(&format!(
+                    "⚠️  Ignored in {} (set them in the user file, environment or command line): {}\n\n",
+                    PROJECT_CONFIG_FILE,
+                    self.config.ignored.join(", ")
+                ));
+            }
+            report.push_str("```toml\n");
+            report.push_str(&self.config.to_toml());
+            report.push_str("```\n\n");
+        }
+
         // Summary
         report.push_str("## Summary\n\n");
         if all_good {
@@ -190,6 +254,53 @@ impl Tool for CheckTool {
     }
 }
 
+/// Report lines for the spec-kit CLI probe
+fn probe_status(probe: &ProbeReport) -> String {
+    let mut status = match probe.status {
+        CliStatus::Available => format!(
+            "✅ Spec-kit CLI probe: available{}\n",
+            probe
+                .version
+                .as_ref()
+                .map(|v| format!(" (version {})", v))
+                .unwrap_or_default()
+        ),
+        CliStatus::Missing => {
+            "❌ Spec-kit CLI probe: missing; tools that need the CLI are unavailable\n".to_string()
+        }
+        CliStatus::Unknown => "⏳ Spec-kit CLI probe: not run yet\n".to_string(),
+    };
+
+    if probe.running {
+        status.push_str("   A probe is running now\n");
+    }
+    if let (Some(checked_at), Some(duration), Some(source)) =
+        (probe.checked_at, probe.duration, probe.source)
+    {
+        let age = SystemTime::now()
+            .duration_since(checked_at)
+            .unwrap_or_default();
+        status.push_str(&format!(
+            "   Last probe: took {:.1}s, {} ago (from {})\n",
+            duration.as_secs_f64(),
+            format_age(age),
+            source
+        ));
+    }
+    status
+}
+
+/// Coarse human-readable age
+fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m", seconds / 60),
+        3600..=86399 => format!("{}h", seconds / 3600),
+        _ => format!("{}d", seconds / 86400),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +335,58 @@ mod tests {
         assert!(!result.content.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_check_shows_config() {
+        let cli = SpecKitCli::new_test_mode();
+        let mut config = Config::default();
+        config.cli.timeout_seconds = 42;
+        let tool = CheckTool::new(cli).with_config(config);
+
+        let params = json!({
+            "check_speckit": false,
+            "check_git": false,
+            "check_ai_tools": false,
+            "show_config": true
+        });
+        let result = tool.execute(params).await.unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("## Configuration"));
+        assert!(text.contains("timeout_seconds = 42"));
+    }
+
+    #[tokio::test]
+    async fn test_check_reports_probe() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = CheckTool::new(cli.clone());
+        let params = json!({ "check_git": false, "check_ai_tools": false });
+
+        let result = tool.execute(params.clone()).await.unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("Spec-kit CLI probe: not run yet"));
+
+        let params = json!({ "check_git": false, "check_ai_tools": false, "probe": true });
+        let result = tool.execute(params).await.unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("Spec-kit CLI probe: available"));
+        assert!(text.contains("ago (from probe)"));
+        assert_eq!(cli.status(), CliStatus::Available);
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::from_secs(42)), "42s");
+        assert_eq!(format_age(Duration::from_secs(300)), "5m");
+        assert_eq!(format_age(Duration::from_secs(7200)), "2h");
+        assert_eq!(format_age(Duration::from_secs(3 * 86400)), "3d");
+    }
+
     #[tokio::test]
     async fn test_check_command() {
         let cli = SpecKitCli::new_test_mode();
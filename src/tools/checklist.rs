@@ -1,6 +1,6 @@
 //! Spec-Kit Checklist Tool
 //!
-//! Generates validation checklists from specifications.
+//! Generates requirement-quality checklists per domain from specifications.
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -8,35 +8,48 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{
+    build_checklists, detect_domains, extract_requirements, render_checklists, summarize_checklist,
+    write_artifact, ArtifactKind, ChecklistDomain, ProjectLayout, WriteMode, WriteOptions,
+};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::tools::{commit_phase, dry_run_result, Tool};
 
 /// Parameters for the speckit_checklist tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ChecklistParams {
-    /// Path to specification file
-    spec_file: PathBuf,
+    /// Path to specification file (defaults to the project layout)
+    #[serde(default)]
+    spec_file: Option<PathBuf>,
 
-    /// Include implementation checklist items
-    #[serde(default = "default_true")]
-    include_implementation: bool,
+    /// Domains to cover (defaults to the domains the spec touches)
+    #[serde(default)]
+    domains: Option<Vec<ChecklistDomain>>,
 
-    /// Include testing checklist items
-    #[serde(default = "default_true")]
-    include_testing: bool,
+    /// Output path for checklist (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
 
-    /// Output path for checklist
-    #[serde(default = "default_checklist_path")]
-    output_path: PathBuf,
-}
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to treat an existing file
+    #[serde(default)]
+    mode: WriteMode,
 
-fn default_true() -> bool {
-    true
+    /// Preview the write without touching the file
+    #[serde(default)]
+    dry_run: bool,
 }
 
-fn default_checklist_path() -> PathBuf {
-    PathBuf::from("./speckit.checklist")
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
 }
 
 /// Tool for generating validation checklists
@@ -57,31 +70,49 @@ impl Tool for ChecklistTool {
     fn definition(&self) -> ToolDefinition {
         ToolDefinition {
             name: "speckit_checklist".to_string(),
-            description: "Generate a validation checklist from the specification to ensure all requirements are met".to_string(),
+            description: "Generate requirement-quality checklists (ux, api, security, performance, accessibility) from the specification, with items linked to requirement IDs; re-running keeps existing check marks and reports completion by category".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "spec_file": {
                         "type": "string",
-                        "description": "Path to the specification file"
+                        "description": "Path to the specification file (defaults to the active feature's spec.md, or ./speckit.specify)"
                     },
-                    "include_implementation": {
+                    "domains": {
+                        "type": "array",
+                        "items": {
+                            "type": "string",
+                            "enum": ["ux", "api", "security", "performance", "accessibility"]
+                        },
+                        "description": "Domains to cover (defaults to the domains the specification's requirements touch, or all of them when none match)"
+                    },
+                    "output_path": {
+                        "type": "string",
+                        "description": "Path where checklist will be written (defaults to the active feature's checklists/requirements.md, or ./speckit.checklist)"
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["create_only", "overwrite", "merge"],
+                        "default": "overwrite",
+                        "description": "How to treat an existing file: fail (create_only), replace it keeping a timestamped backup (overwrite), or replace matching ## sections and keep the rest (merge)"
+                    },
+                    "dry_run": {
                         "type": "boolean",
-                        "default": true,
-                        "description": "Include implementation checklist items"
+                        "default": false,
+                        "description": "Return the would-be content and a unified diff without writing anything"
                     },
-                    "include_testing": {
+                    "commit": {
                         "type": "boolean",
-                        "default": true,
-                        "description": "Include testing checklist items"
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
                     },
-                    "output_path": {
+                    "project_path": {
                         "type": "string",
-                        "description": "Path where checklist will be written",
-                        "default": "./speckit.checklist"
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     }
                 },
-                "required": ["spec_file"]
+                "required": []
             })
         }
     }
@@ -90,109 +121,90 @@ impl Tool for ChecklistTool {
         let params: ChecklistParams =
             serde_json::from_value(params).context("Failed to parse checklist parameters")?;
 
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let spec_file = params
+            .spec_file
+            .unwrap_or_else(|| layout.read_path(ArtifactKind::Specification));
+        let output_path = params.output_path.unwrap_or_else(|| {
+            layout.output_path("checklists/requirements.md", "speckit.checklist")
+        });
+
         tracing::info!(
-            spec_file = %params.spec_file.display(),
+            spec_file = %spec_file.display(),
             "Generating validation checklist"
         );
 
         // Read specification
-        let spec_content = tokio::fs::read_to_string(&params.spec_file)
+        let spec_content = self
+            .cli
+            .paths()
+            .read_to_string(&spec_file)
             .await
             .context("Failed to read specification file")?;
 
-        // Generate checklist
-        let mut checklist = String::from("# Implementation & Validation Checklist\n\n");
-        checklist.push_str(&format!("Based on: {}\n\n", params.spec_file.display()));
-
-        // Requirements checklist
-        checklist.push_str("## Requirements Validation\n\n");
-
-        // Extract requirements (simple heuristic)
-        let mut req_count = 0;
-        for line in spec_content.lines() {
-            if line.trim().starts_with('-')
-                || line.trim().starts_with('*')
-                || line.contains("shall")
-                || line.contains("must")
-                || line.contains("should")
-            {
-                req_count += 1;
-                let requirement = line
-                    .trim()
-                    .trim_start_matches('-')
-                    .trim_start_matches('*')
-                    .trim();
-                if !requirement.is_empty() && requirement.len() < 100 {
-                    checklist.push_str(&format!("- [ ] {}\n", requirement));
-                }
-            }
-        }
-
-        if req_count == 0 {
-            checklist.push_str("- [ ] All specified requirements are implemented\n");
-            checklist.push_str("- [ ] Edge cases are handled\n");
-            checklist.push_str("- [ ] Error conditions are addressed\n");
-        }
-
-        // Implementation checklist
-        if params.include_implementation {
-            checklist.push_str("\n## Implementation Checklist\n\n");
-            checklist.push_str("- [ ] Code follows project style guide\n");
-            checklist.push_str("- [ ] Functions have clear documentation\n");
-            checklist.push_str("- [ ] Error handling is comprehensive\n");
-            checklist.push_str("- [ ] Input validation is performed\n");
-            checklist.push_str("- [ ] Logging is appropriate\n");
-            checklist.push_str("- [ ] Performance is acceptable\n");
-            checklist.push_str("- [ ] Security considerations addressed\n");
-        }
-
-        // Testing checklist
-        if params.include_testing {
-            checklist.push_str("\n## Testing Checklist\n\n");
-            checklist.push_str("- [ ] Unit tests written for all functions\n");
-            checklist.push_str("- [ ] Integration tests cover main workflows\n");
-            checklist.push_str("- [ ] Edge cases are tested\n");
-            checklist.push_str("- [ ] Error conditions are tested\n");
-            checklist.push_str("- [ ] Performance tests (if applicable)\n");
-            checklist.push_str("- [ ] All tests pass\n");
-            checklist.push_str("- [ ] Test coverage >80%\n");
-        }
-
-        // Quality checklist
-        checklist.push_str("\n## Quality Assurance\n\n");
-        checklist.push_str("- [ ] Code review completed\n");
-        checklist.push_str("- [ ] Documentation updated\n");
-        checklist.push_str("- [ ] CHANGELOG.md updated\n");
-        checklist.push_str("- [ ] No compiler warnings\n");
-        checklist.push_str("- [ ] Linter passes (clippy, etc.)\n");
-        checklist.push_str("- [ ] Dependencies are up to date\n");
-
-        // Deployment checklist
-        checklist.push_str("\n## Deployment Readiness\n\n");
-        checklist.push_str("- [ ] All tests pass in CI\n");
-        checklist.push_str("- [ ] Version number updated\n");
-        checklist.push_str("- [ ] Release notes prepared\n");
-        checklist.push_str("- [ ] Breaking changes documented\n");
-        checklist.push_str("- [ ] Migration guide provided (if needed)\n");
+        let requirements = extract_requirements(&spec_content);
+        let domains = params
+            .domains
+            .filter(|domains| !domains.is_empty())
+            .unwrap_or_else(|| detect_domains(&requirements));
+        let existing = self.cli.paths().read_to_string(&output_path).await.ok();
+
+        let mut checklists = build_checklists(&requirements, &domains);
+        let checklist = render_checklists(
+            "Requirements Quality Checklist",
+            &spec_file.display().to_string(),
+            &mut checklists,
+            existing.as_deref(),
+        );
 
         // Write checklist
-        tokio::fs::write(&params.output_path, &checklist)
+        let options = WriteOptions {
+            mode: params.mode,
+            dry_run: params.dry_run,
+        };
+        let outcome = write_artifact(self.cli.paths(), &output_path, &checklist, options)
             .await
             .context("Failed to write checklist")?;
+        if outcome.dry_run {
+            return Ok(dry_run_result(&outcome));
+        }
 
-        let total_items = checklist.matches("- [ ]").count();
+        let linked = checklists
+            .iter()
+            .flat_map(|c| &c.items)
+            .filter(|item| !item.requirements.is_empty())
+            .count();
+        let total_items: usize = checklists.iter().map(|c| c.items.len()).sum();
 
-        let message = format!(
-            "Validation checklist generated!\n\n\
+        let mut message = format!(
+            "Requirements checklist generated!\n\n\
             Source: {}\n\
-            Total items: {}\n\
+            Requirements: {}\n\
+            Items: {} ({} linked to requirements, {} gaps)\n\
             Output: {}\n\n\
-            Use this checklist to ensure all requirements are met and\n\
-            quality standards are maintained throughout implementation.",
-            params.spec_file.display(),
+            Completion by category:",
+            spec_file.display(),
+            requirements.len(),
             total_items,
-            params.output_path.display()
+            linked,
+            total_items - linked,
+            output_path.display()
         );
+        for category in summarize_checklist(&checklist) {
+            message.push_str(&format!(
+                "\n- {}: {}/{} ({}%)",
+                category.category,
+                category.checked,
+                category.total,
+                category.percent()
+            ));
+        }
+
+        message.push_str(&format!("\n\nFile: {}", outcome.summary()));
+
+        if params.commit {
+            message.push_str(&commit_phase(&outcome.resolved, "checklist").await);
+        }
 
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
@@ -226,27 +238,70 @@ mod tests {
         let spec_file = dir.path().join("spec.md");
         let output_path = dir.path().join("checklist.md");
 
-        // Create spec with requirements
         fs::write(
             &spec_file,
-            "- User must login\n- System shall validate input\n- Should handle errors",
+            "# Spec\n\n## Requirements\n\n\
+            - **FR-001**: Users MUST log in with a password\n\
+            - **FR-002**: The search endpoint MUST return JSON within 200 milliseconds\n",
         )
         .await
         .unwrap();
 
         let params = json!({
             "spec_file": spec_file.to_str().unwrap(),
-            "include_implementation": true,
-            "include_testing": true,
             "output_path": output_path.to_str().unwrap()
         });
 
+        let result = tool.execute(params.clone()).await.unwrap();
+        assert!(result.is_error.is_none());
+
+        let content = fs::read_to_string(&output_path).await.unwrap();
+        assert!(content.contains("## API\n"));
+        assert!(content.contains("## Security\n"));
+        assert!(content.contains("## Performance\n"));
+        assert!(!content.contains("## UX\n"));
+        assert!(content.contains("[Clarity, Spec §FR-001]"));
+        assert!(content.contains("[Gap]"));
+        assert!(!content.contains("CHANGELOG"));
+
+        // Check an item off and regenerate
+        fs::write(&output_path, content.replacen("- [ ]", "- [x]", 1))
+            .await
+            .unwrap();
         let result = tool.execute(params).await.unwrap();
-        assert!(result.is_error.is_none() || !result.is_error.unwrap());
-        assert!(output_path.exists());
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("- API: 1/"));
+        assert!(text.contains("- Security: 0/"));
+
+        let content = fs::read_to_string(&output_path).await.unwrap();
+        assert_eq!(content.matches("- [x]").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_checklist_selected_domains() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = ChecklistTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let spec_file = dir.path().join("spec.md");
+        let output_path = dir.path().join("checklist.md");
+        fs::write(&spec_file, "# Spec\n\n- **FR-001**: Reports are archived\n")
+            .await
+            .unwrap();
+
+        tool.execute(json!({
+            "spec_file": spec_file.to_str().unwrap(),
+            "output_path": output_path.to_str().unwrap(),
+            "domains": ["accessibility"]
+        }))
+        .await
+        .unwrap();
 
-        // Verify checklist has items
-        let content = fs::read_to_string(output_path).await.unwrap();
-        assert!(content.contains("- [ ]"));
+        let content = fs::read_to_string(&output_path).await.unwrap();
+        assert!(content.contains("## Accessibility\n"));
+        assert_eq!(content.matches("## ").count(), 1);
+        assert!(content.contains("- [ ] CHK001 Is the target conformance level"));
     }
 }
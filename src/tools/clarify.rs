@@ -6,41 +6,108 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::artifacts::{
+    integrate_answers, write_artifact, ArtifactKind, ClarificationReport, ProjectLayout, Question,
+    ResolvedAnswer, WriteMode, WriteOptions, DEFAULT_MAX_QUESTIONS,
+};
+use crate::mcp::peer::ClientPeer;
+use crate::mcp::types::{ContentBlock, ElicitAction, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::tools::{commit_phase, dry_run_result, Tool};
 
 /// Parameters for the speckit_clarify tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ClarifyParams {
-    /// Path to specification file
-    spec_file: PathBuf,
+    /// Path to specification file (defaults to the project layout)
+    #[serde(default)]
+    spec_file: Option<PathBuf>,
 
     /// Specific questions to clarify (optional)
     #[serde(default)]
     questions: Option<Vec<String>>,
 
-    /// Output path for clarifications
-    #[serde(default = "default_clarify_path")]
-    output_path: PathBuf,
+    /// Output path for clarifications (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to treat an existing file
+    #[serde(default)]
+    mode: WriteMode,
+
+    /// Preview the write without touching the file
+    #[serde(default)]
+    dry_run: bool,
+
+    /// Maximum number of questions to ask
+    #[serde(default = "default_max_questions")]
+    max_questions: usize,
+
+    /// Answers to record in the specification
+    #[serde(default)]
+    answers: Vec<ClarifyAnswer>,
+
+    /// Ask the questions through the client when it supports elicitation
+    #[serde(default = "default_elicit")]
+    elicit: bool,
 }
 
-fn default_clarify_path() -> PathBuf {
-    PathBuf::from("./speckit.clarify")
+/// An answer to a clarification question
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ClarifyAnswer {
+    /// ID of a question from the previous call (`Q-3f9a2c`)
+    #[serde(default)]
+    id: Option<String>,
+
+    /// Question text: checked against the question with `id` when both are
+    /// given, otherwise answers a question of the caller's own
+    #[serde(default)]
+    question: Option<String>,
+
+    /// Option label (`A`, `B`, ...) or a short free-form answer
+    answer: String,
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+fn default_max_questions() -> usize {
+    DEFAULT_MAX_QUESTIONS
+}
+
+fn default_elicit() -> bool {
+    true
 }
 
 /// Tool for clarifying specifications
 pub struct ClarifyTool {
-    #[allow(dead_code)]
     cli: SpecKitCli,
+    client: ClientPeer,
 }
 
 impl ClarifyTool {
     /// Create a new clarify tool
     pub fn new(cli: SpecKitCli) -> Self {
-        Self { cli }
+        Self {
+            cli,
+            client: ClientPeer::new(),
+        }
+    }
+
+    /// Ask questions through this client when it supports elicitation
+    pub fn with_client(mut self, client: ClientPeer) -> Self {
+        self.client = client;
+        self
     }
 }
 
@@ -49,26 +116,79 @@ impl Tool for ClarifyTool {
     fn definition(&self) -> ToolDefinition {
         ToolDefinition {
             name: "speckit_clarify".to_string(),
-            description: "Identify underspecified areas in the specification and generate clarification questions".to_string(),
+            description: "Score the specification's coverage (functional scope, data model, non-functional, edge cases, terminology) and ask up to five prioritized multiple-choice questions, interactively when the client supports elicitation; otherwise pass answers in a follow-up call to record them in the spec's ## Clarifications section".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "spec_file": {
                         "type": "string",
-                        "description": "Path to the specification file to analyze"
+                        "description": "Path to the specification file to analyze (defaults to the active feature's spec.md, or ./speckit.specify)"
                     },
                     "questions": {
                         "type": "array",
                         "items": { "type": "string" },
-                        "description": "Specific questions to address (optional - will auto-detect if not provided)"
+                        "description": "Additional questions to ask ahead of the detected ones (optional)"
+                    },
+                    "max_questions": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "default": 5,
+                        "description": "Maximum number of questions to ask"
+                    },
+                    "answers": {
+                        "type": "array",
+                        "description": "Answers to record in the specification's ## Clarifications section (follow-up call). Placeholders such as [NEEDS CLARIFICATION: ...] that a question came from are replaced with the answer",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                    "description": "Question ID from the previous call (e.g. Q-3f9a2c); IDs are derived from the question's content, so an answer is rejected if its question is no longer asked"
+                                },
+                                "question": {
+                                    "type": "string",
+                                    "description": "Question text: echo it with the id to have it checked, or give it alone to record an answer to a question of your own"
+                                },
+                                "answer": {
+                                    "type": "string",
+                                    "description": "Option label (A, B, ...) or a short answer"
+                                }
+                            },
+                            "required": ["answer"]
+                        }
+                    },
+                    "elicit": {
+                        "type": "boolean",
+                        "default": true,
+                        "description": "When the client supports elicitation, ask each question interactively and record the answers in the specification"
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where clarifications will be written",
-                        "default": "./speckit.clarify"
+                        "description": "Path where clarifications will be written (defaults to the active feature's clarifications.md, or ./speckit.clarify)"
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["create_only", "overwrite", "merge"],
+                        "default": "overwrite",
+                        "description": "How to treat an existing file: fail (create_only), replace it keeping a timestamped backup (overwrite), or replace matching ## sections and keep the rest (merge); recording answers updates the spec, so create_only is rejected there"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Return the would-be content and a unified diff without writing anything"
+                    },
+                    "commit": {
+                        "type": "boolean",
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     }
                 },
-                "required": ["spec_file"]
+                "required": []
             })
         }
     }
@@ -77,86 +197,307 @@ impl Tool for ClarifyTool {
         let params: ClarifyParams =
             serde_json::from_value(params).context("Failed to parse clarify parameters")?;
 
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let spec_file = params
+            .spec_file
+            .clone()
+            .unwrap_or_else(|| layout.read_path(ArtifactKind::Specification));
+        let output_path = params
+            .output_path
+            .clone()
+            .unwrap_or_else(|| layout.output_path("clarifications.md", "speckit.clarify"));
+
         tracing::info!(
-            spec_file = %params.spec_file.display(),
+            spec_file = %spec_file.display(),
             "Analyzing specification for ambiguities"
         );
 
         // Read the specification
-        let spec_content = tokio::fs::read_to_string(&params.spec_file)
+        let spec_content = self
+            .cli
+            .paths()
+            .read_to_string(&spec_file)
             .await
             .context("Failed to read specification file")?;
 
-        // Analyze for common ambiguities
-        let mut clarifications = Vec::new();
+        let custom = params.questions.clone().unwrap_or_default();
+        let report =
+            ClarificationReport::build(&spec_content, &custom, params.max_questions.max(1));
+        let options = WriteOptions {
+            mode: params.mode,
+            dry_run: params.dry_run,
+        };
 
-        // Check for vague terms
-        let vague_terms = ["maybe", "probably", "might", "could", "should consider"];
-        for term in &vague_terms {
-            if spec_content.to_lowercase().contains(term) {
-                clarifications.push(format!(
-                    "Found vague term '{}' - needs concrete definition",
-                    term
-                ));
-            }
-        }
-
-        // Check for missing details
-        if !spec_content.to_lowercase().contains("performance") {
-            clarifications.push("Performance requirements not specified".to_string());
-        }
-        if !spec_content.to_lowercase().contains("error") {
-            clarifications.push("Error handling approach not specified".to_string());
-        }
-        if !spec_content.to_lowercase().contains("test") {
-            clarifications.push("Testing strategy not specified".to_string());
+        if !params.answers.is_empty() {
+            let answers = resolve_answers(&params.answers, &report)?;
+            return self
+                .record_answers(
+                    &params,
+                    &report,
+                    &spec_file,
+                    &spec_content,
+                    answers,
+                    options,
+                )
+                .await;
         }
 
-        // Add user-provided questions
-        if let Some(questions) = params.questions {
-            clarifications.extend(questions.into_iter().map(|q| format!("Question: {}", q)));
-        }
-
-        // Create clarification document
-        let mut content = String::from("# Specification Clarifications\n\n");
-        content.push_str(&format!("Source: {}\n\n", params.spec_file.display()));
-        content.push_str("## Issues Found\n\n");
-
-        if clarifications.is_empty() {
-            content.push_str("✓ No major ambiguities detected.\n");
-            content.push_str("\nThe specification appears well-defined.\n");
-        } else {
-            for (i, clarification) in clarifications.iter().enumerate() {
-                content.push_str(&format!("{}. {}\n", i + 1, clarification));
+        if params.elicit && self.client.supports_elicitation() && !report.questions.is_empty() {
+            let answers = self.elicit_answers(&report).await;
+            if !answers.is_empty() {
+                return self
+                    .record_answers(
+                        &params,
+                        &report,
+                        &spec_file,
+                        &spec_content,
+                        answers,
+                        options,
+                    )
+                    .await;
             }
-            content.push_str("\n## Recommendations\n\n");
-            content.push_str("1. Address each issue above\n");
-            content.push_str("2. Update the specification with concrete details\n");
-            content.push_str("3. Review with stakeholders\n");
-            content.push_str("4. Re-run clarify to verify improvements\n");
         }
 
+        let content = report.to_markdown(&spec_file.display().to_string());
+
         // Write clarifications
-        tokio::fs::write(&params.output_path, &content)
+        let outcome = write_artifact(self.cli.paths(), &output_path, &content, options)
             .await
             .context("Failed to write clarifications")?;
+        if outcome.dry_run {
+            return Ok(dry_run_result(&outcome));
+        }
 
-        let message = format!(
+        let coverage = report
+            .coverage
+            .iter()
+            .map(|c| format!("- {}: {}% ({})", c.category, c.score, c.status()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut message = format!(
             "Clarification analysis complete!\n\n\
             Analyzed: {}\n\
-            Issues found: {}\n\
+            Coverage: {}%\n{}\n\n\
+            Questions: {}\n\
             Output: {}\n\n\
             {}",
-            params.spec_file.display(),
-            clarifications.len(),
-            params.output_path.display(),
-            if clarifications.is_empty() {
+            spec_file.display(),
+            report.overall_score(),
+            coverage,
+            report.questions.len(),
+            output_path.display(),
+            if report.questions.is_empty() {
                 "✓ Specification is well-defined"
             } else {
-                "⚠ Please review and address the identified issues"
+                "⚠ Answer the questions and call speckit_clarify again with `answers`"
             }
         );
 
+        message.push_str(&format!("\n\nFile: {}", outcome.summary()));
+
+        if params.commit {
+            message.push_str(&commit_phase(&outcome.resolved, "clarify").await);
+        }
+
+        Ok(ToolResult {
+            content: vec![ContentBlock::text(message), ContentBlock::text(content)],
+            is_error: None,
+        })
+    }
+}
+
+/// Match caller-supplied answers to the report's questions
+fn resolve_answers(
+    answers: &[ClarifyAnswer],
+    report: &ClarificationReport,
+) -> Result<Vec<ResolvedAnswer>> {
+    answers
+        .iter()
+        .map(|answer| match (&answer.id, &answer.question) {
+            (Some(id), _) => {
+                let question = report.question(id).with_context(|| {
+                    format!(
+                        "Unknown question {}; the current questions are {}",
+                        id,
+                        report
+                            .questions
+                            .iter()
+                            .map(|q| q.id.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+                if let Some(text) = &answer.question {
+                    if !text.trim().eq_ignore_ascii_case(question.question.trim()) {
+                        anyhow::bail!(
+                            "Question {} is \"{}\", not \"{}\"; ask again to get the current questions",
+                            question.id,
+                            question.question,
+                            text.trim()
+                        );
+                    }
+                }
+                Ok(ResolvedAnswer::new(
+                    question,
+                    question.resolve_answer(&answer.answer),
+                ))
+            }
+            (None, Some(question)) => Ok(ResolvedAnswer {
+                question: question.clone(),
+                answer: answer.answer.trim().to_string(),
+                marker: None,
+                line: None,
+                column: None,
+            }),
+            (None, None) => anyhow::bail!("Each answer needs an id or a question"),
+        })
+        .collect()
+}
+
+/// Elicitation message for a question
+fn elicitation_message(question: &Question) -> String {
+    let mut message = match question.category {
+        Some(category) => format!("{} ({}): {}", question.id, category, question.question),
+        None => format!("{}: {}", question.id, question.question),
+    };
+    if !question.context.is_empty() {
+        message.push_str(&format!("\n\n{}", question.context));
+    }
+    message
+}
+
+/// Flat elicitation schema for a question: a choice between its options,
+/// or a short answer of the user's own
+fn elicitation_schema(question: &Question) -> Value {
+    if question.options.is_empty() {
+        return json!({
+            "type": "object",
+            "properties": {
+                "answer": {
+                    "type": "string",
+                    "title": "Answer",
+                    "description": "Short answer (5 words or fewer)"
+                }
+            },
+            "required": ["answer"]
+        });
+    }
+
+    json!({
+        "type": "object",
+        "properties": {
+            "choice": {
+                "type": "string",
+                "title": "Choice",
+                "enum": question.options.iter().map(|o| o.label.as_str()).collect::<Vec<_>>(),
+                "enumNames": question
+                    .options
+                    .iter()
+                    .map(|o| format!("{}: {}", o.label, o.description))
+                    .collect::<Vec<_>>()
+            },
+            "answer": {
+                "type": "string",
+                "title": "Other",
+                "description": "Short answer (5 words or fewer) instead of a choice"
+            }
+        },
+        "required": []
+    })
+}
+
+impl ClarifyTool {
+    /// Ask each question through `elicitation/create`, stopping when the user
+    /// cancels or the client fails; declined questions are skipped
+    async fn elicit_answers(&self, report: &ClarificationReport) -> Vec<ResolvedAnswer> {
+        let mut answers = Vec::new();
+
+        for question in &report.questions {
+            let result = match self
+                .client
+                .elicit(&elicitation_message(question), elicitation_schema(question))
+                .await
+            {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::warn!(error = %e, question = %question.id, "Elicitation failed");
+                    break;
+                }
+            };
+
+            match result.action {
+                ElicitAction::Accept => {}
+                ElicitAction::Decline => continue,
+                ElicitAction::Cancel => break,
+            }
+
+            let content = result.content.unwrap_or_default();
+            let field = |name: &str| {
+                content
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+            };
+            if let Some(answer) = field("answer").or_else(|| field("choice")) {
+                answers.push(ResolvedAnswer::new(
+                    question,
+                    question.resolve_answer(answer),
+                ));
+            }
+        }
+
+        answers
+    }
+
+    /// Record answers in the spec's `## Clarifications` section
+    ///
+    /// Answers always update the existing spec, so `create_only` is rejected;
+    /// `overwrite` and `merge` apply as for any other write.
+    async fn record_answers(
+        &self,
+        params: &ClarifyParams,
+        report: &ClarificationReport,
+        spec_file: &Path,
+        spec_content: &str,
+        answers: Vec<ResolvedAnswer>,
+        options: WriteOptions,
+    ) -> Result<ToolResult> {
+        if options.mode == WriteMode::CreateOnly {
+            anyhow::bail!(
+                "Answers update the existing specification {}; use mode \"overwrite\" or \"merge\"",
+                spec_file.display()
+            );
+        }
+        let updated = integrate_answers(spec_content, &answers, chrono::Local::now().date_naive())?;
+        let outcome = write_artifact(self.cli.paths(), spec_file, &updated, options)
+            .await
+            .context("Failed to update specification")?;
+        if outcome.dry_run {
+            return Ok(dry_run_result(&outcome));
+        }
+
+        let remaining = ClarificationReport::build(&updated, &[], params.max_questions.max(1));
+        let mut message = format!(
+            "Recorded {} answer(s) in {}\n\n{}\n\n\
+            Coverage: {}% (was {}%)\n\n\
+            File: {}",
+            answers.len(),
+            spec_file.display(),
+            answers
+                .iter()
+                .map(|a| format!("- Q: {} → A: {}", a.question, a.answer))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            remaining.overall_score(),
+            report.overall_score(),
+            outcome.summary()
+        );
+
+        if params.commit {
+            message.push_str(&commit_phase(&outcome.resolved, "clarify").await);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -206,4 +547,145 @@ mod tests {
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
         assert!(output_path.exists());
     }
+
+    #[tokio::test]
+    async fn test_clarify_records_answers_in_spec() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = ClarifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let spec_file = dir.path().join("spec.md");
+        let output_path = dir.path().join("clarify.md");
+        fs::write(
+            &spec_file,
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export reports as [NEEDS CLARIFICATION: which formats?]\n",
+        )
+        .await
+        .unwrap();
+
+        let result = tool
+            .execute(json!({
+                "spec_file": spec_file.to_str().unwrap(),
+                "output_path": output_path.to_str().unwrap()
+            }))
+            .await
+            .unwrap();
+        let ContentBlock::Text { text } = &result.content[1] else {
+            panic!("expected text");
+        };
+        let spec = fs::read_to_string(&spec_file).await.unwrap();
+        let report = ClarificationReport::build(&spec, &[], DEFAULT_MAX_QUESTIONS);
+        let (formats, users) = (&report.questions[0].id, &report.questions[1].id);
+        assert!(text.contains(&format!(
+            "### {} (Functional Scope): which formats?",
+            formats
+        )));
+        assert!(text.contains(&format!(
+            "### {} (Functional Scope): Who are the primary users",
+            users
+        )));
+
+        let error = tool
+            .execute(json!({
+                "spec_file": spec_file.to_str().unwrap(),
+                "answers": [{ "id": formats, "question": "Which columns?", "answer": "A" }]
+            }))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("not \"Which columns?\""));
+
+        let error = tool
+            .execute(json!({
+                "spec_file": spec_file.to_str().unwrap(),
+                "mode": "create_only",
+                "answers": [{ "id": formats, "answer": "CSV" }]
+            }))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("use mode"));
+
+        tool.execute(json!({
+            "spec_file": spec_file.to_str().unwrap(),
+            "answers": [
+                { "id": formats, "question": "Which formats?", "answer": "CSV and PDF" },
+                { "id": users.to_lowercase(), "answer": "B" }
+            ]
+        }))
+        .await
+        .unwrap();
+
+        let spec = fs::read_to_string(&spec_file).await.unwrap();
+        assert!(spec.contains("- **FR-001**: Export reports as CSV and PDF\n"));
+        assert!(spec.contains("## Clarifications\n\n### Session "));
+        assert!(spec.contains("- Q: which formats? → A: CSV and PDF"));
+        assert!(spec.contains("→ A: Several roles with different permissions"));
+
+        let error = tool
+            .execute(json!({
+                "spec_file": spec_file.to_str().unwrap(),
+                "answers": [{ "id": "Q9", "answer": "A" }]
+            }))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Unknown question Q9"));
+    }
+
+    #[tokio::test]
+    async fn test_clarify_elicits_answers() {
+        let client = ClientPeer::new();
+        let mut outgoing = client.connect();
+        client.set_capabilities(crate::mcp::types::ClientCapabilities {
+            elicitation: Some(json!({})),
+            ..Default::default()
+        });
+        let tool = ClarifyTool::new(SpecKitCli::new_test_mode()).with_client(client.clone());
+
+        let dir = tempdir().unwrap();
+        let spec_file = dir.path().join("spec.md");
+        fs::write(
+            &spec_file,
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export reports as [NEEDS CLARIFICATION: which formats?]\n",
+        )
+        .await
+        .unwrap();
+
+        // Answer the first question, pick option B for the second, then cancel
+        let responder = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            let results = [
+                json!({ "action": "accept", "content": { "answer": "CSV and PDF" } }),
+                json!({ "action": "accept", "content": { "choice": "B" } }),
+                json!({ "action": "cancel" }),
+            ];
+            for result in results {
+                let request = outgoing.recv().await.unwrap();
+                let id = serde_json::from_value(request["id"].clone()).unwrap();
+                requests.push(request);
+                client.handle_response(crate::mcp::types::JsonRpcResponse::success(id, result));
+            }
+            requests
+        });
+
+        let result = tool
+            .execute(json!({ "spec_file": spec_file.to_str().unwrap(), "max_questions": 3 }))
+            .await
+            .unwrap();
+        let requests = responder.await.unwrap();
+
+        assert_eq!(requests[0]["method"], "elicitation/create");
+        let message = requests[0]["params"]["message"].as_str().unwrap();
+        assert!(message.contains(" (Functional Scope): which formats?"));
+        assert_eq!(
+            requests[1]["params"]["requestedSchema"]["properties"]["choice"]["enum"][1],
+            "B"
+        );
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.starts_with("Recorded 2 answer(s)"));
+        let spec = fs::read_to_string(&spec_file).await.unwrap();
+        assert!(spec.contains("- **FR-001**: Export reports as CSV and PDF\n"));
+        assert!(spec.contains("→ A: Several roles with different permissions"));
+    }
 }
@@ -0,0 +1,184 @@
+//! Spec-Kit Compliance Tool
+//!
+//! Checks plans and tasks against the constitution's principles.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+use crate::artifacts::{
+    check_compliance, ArtifactKind, ArtifactSet, ComplianceStatus, ProjectLayout,
+};
+use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::speckit::SpecKitCli;
+use crate::tools::Tool;
+
+/// Parameters for the speckit_compliance tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ComplianceParams {
+    /// Project directory used to resolve artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Output path for the markdown report (optional)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+/// Tool for checking constitution compliance
+pub struct ComplianceTool {
+    cli: SpecKitCli,
+}
+
+impl ComplianceTool {
+    /// Create a new compliance tool
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Tool for ComplianceTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_compliance".to_string(),
+            description: "Constitution Check gate: evaluate the plan and tasks against each constitution principle's machine-checkable rules (tests before implementation, maximum N projects, justified dependencies, must/must not use X) and report PASS/FAIL per principle with evidence and line references".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
+                    },
+                    "output_path": {
+                        "type": "string",
+                        "description": "Path where the markdown report will be written (optional - report is always returned)"
+                    }
+                },
+                "required": []
+            })
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: ComplianceParams =
+            serde_json::from_value(params).context("Failed to parse compliance parameters")?;
+
+        tracing::info!(
+            project_path = %params.project_path.display(),
+            "Checking constitution compliance"
+        );
+
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let artifacts = ArtifactSet::load_layout(&layout, self.cli.paths()).await?;
+        if artifacts.constitution.is_none() {
+            anyhow::bail!(
+                "No constitution found at {}; create one with speckit_constitution",
+                artifacts.label_for(ArtifactKind::Constitution)
+            );
+        }
+
+        let report = check_compliance(&artifacts);
+        let markdown = report.to_markdown();
+
+        if let Some(output_path) = &params.output_path {
+            self.cli
+                .paths()
+                .write(output_path, &markdown)
+                .await
+                .context("Failed to write compliance report")?;
+        }
+
+        let rules: usize = report.principles.iter().map(|p| p.rules.len()).sum();
+        let message = format!(
+            "Constitution check complete!\n\n\
+            Gate: {}\n\
+            Principles: {} ({} machine-checkable rules)\n\
+            Passed: {}, failed: {}, unverified: {}, manual: {}\n\
+            {}\n\
+            {}",
+            if report.passed() { "PASS" } else { "FAIL" },
+            report.principles.len(),
+            rules,
+            report.count(ComplianceStatus::Pass),
+            report.count(ComplianceStatus::Fail),
+            report.count(ComplianceStatus::Unverified),
+            report.count(ComplianceStatus::Manual),
+            match &params.output_path {
+                Some(path) => format!("Report: {}\n", path.display()),
+                None => String::new(),
+            },
+            if report.passed() {
+                "✓ Plan and tasks comply with the constitution"
+            } else {
+                "⚠ Fix the plan or tasks, or amend the constitution, before implementation"
+            }
+        );
+
+        Ok(ToolResult {
+            content: vec![
+                ContentBlock::text(message),
+                ContentBlock::text(markdown),
+                ContentBlock::text(report.to_json()),
+            ],
+            is_error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tokio::fs;
+
+    #[tokio::test]
+    async fn test_compliance_tool_definition() {
+        let cli = SpecKitCli::new();
+        let tool = ComplianceTool::new(cli);
+        let def = tool.definition();
+
+        assert_eq!(def.name, "speckit_compliance");
+        assert!(!def.description.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compliance_tool_execute() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = ComplianceTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("speckit.constitution"),
+            "# Constitution\n\n## Simplicity\n\nMaximum 2 projects.\n",
+        )
+        .await
+        .unwrap();
+        fs::write(dir.path().join("speckit.plan"), "# Plan\n\nProjects: 3\n")
+            .await
+            .unwrap();
+
+        let output_path = dir.path().join("compliance.md");
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "output_path": output_path.to_str().unwrap()
+            }))
+            .await
+            .unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Gate: FAIL"));
+        let report = fs::read_to_string(output_path).await.unwrap();
+        assert!(report.contains("speckit.plan:3"));
+    }
+}
@@ -8,27 +8,54 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{
+    Amendment, ArtifactKind, ProjectLayout, Template, TemplateContext, TemplateKind, WriteMode,
+    WriteOptions,
+};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::tools::{
+    amend_artifact, amend_schema, amendment_report, commit_phase, dry_run_result, Tool,
+};
 
 /// Parameters for the speckit_constitution tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ConstitutionParams {
-    /// Core principles and values
-    principles: String,
+    /// Core principles and values (required unless amending)
+    #[serde(default)]
+    principles: Option<String>,
 
     /// Technical constraints (optional)
     #[serde(default)]
     constraints: Option<String>,
 
-    /// Output path for constitution file
-    #[serde(default = "default_constitution_path")]
-    output_path: PathBuf,
+    /// Output path for constitution file (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to treat an existing file
+    #[serde(default)]
+    mode: WriteMode,
+
+    /// Preview the write without touching the file
+    #[serde(default)]
+    dry_run: bool,
+
+    /// Section-level change to the existing constitution
+    #[serde(default)]
+    amend: Option<Amendment>,
 }
 
-fn default_constitution_path() -> PathBuf {
-    PathBuf::from("./speckit.constitution")
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
 }
 
 /// Tool for creating project constitutions
@@ -54,7 +81,7 @@ impl Tool for ConstitutionTool {
                 "properties": {
                     "principles": {
                         "type": "string",
-                        "description": "Core principles and values that govern the project (e.g., simplicity, performance, security)"
+                        "description": "Core principles and values that govern the project (e.g., simplicity, performance, security). Required unless amend is given"
                     },
                     "constraints": {
                         "type": "string",
@@ -62,11 +89,32 @@ impl Tool for ConstitutionTool {
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where the constitution file will be written",
-                        "default": "./speckit.constitution"
-                    }
+                        "description": "Path where the constitution file will be written (defaults to .specify/memory/constitution.md, or ./speckit.constitution in flat projects)"
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["create_only", "overwrite", "merge"],
+                        "default": "overwrite",
+                        "description": "How to treat an existing file: fail (create_only), replace it keeping a timestamped backup (overwrite), or replace matching ## sections and keep the rest (merge)"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Return the would-be content and a unified diff without writing anything"
+                    },
+                    "commit": {
+                        "type": "boolean",
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
+                    },
+                    "amend": amend_schema("principle")
                 },
-                "required": ["principles"]
+                "required": []
             })
         }
     }
@@ -75,44 +123,102 @@ impl Tool for ConstitutionTool {
         let params: ConstitutionParams =
             serde_json::from_value(params).context("Failed to parse constitution parameters")?;
 
-        tracing::info!(
-            output_path = %params.output_path.display(),
-            "Creating constitution"
-        );
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let output_path = params
+            .output_path
+            .unwrap_or_else(|| layout.artifact_path(ArtifactKind::Constitution));
 
-        // Format the constitution content
-        let mut content = format!(
-            "# Project Constitution\n\n## Core Principles\n\n{}\n",
-            params.principles
-        );
+        let options = WriteOptions {
+            mode: params.mode,
+            dry_run: params.dry_run,
+        };
 
-        if let Some(constraints) = params.constraints {
-            content.push_str(&format!("\n## Technical Constraints\n\n{}\n", constraints));
-        }
+        if let Some(amendment) = &params.amend {
+            tracing::info!(
+                output_path = %output_path.display(),
+                target = %amendment.target(),
+                "Amending constitution"
+            );
 
-        // Write constitution file
-        let result = self.cli.constitution(&content, &params.output_path).await?;
+            let amended = amend_artifact(self.cli.paths(), &output_path, amendment).await?;
+            let options = WriteOptions {
+                mode: WriteMode::Overwrite,
+                ..options
+            };
+            let outcome = self
+                .cli
+                .constitution(&amended.content, &output_path, options)
+                .await?;
+            if outcome.dry_run {
+                return Ok(dry_run_result(&outcome));
+            }
+
+            let mut message = format!(
+                "Constitution amended at {}\n\n\
+                Sync Impact Report:\n{}\n\n\
+                Review specs, plans and tasks for consistency with the amended principles.\n\n\
+                File: {}",
+                output_path.display(),
+                amendment_report(&amended),
+                outcome.summary()
+            );
+            if params.commit {
+                message.push_str(&commit_phase(&outcome.resolved, "constitution").await);
+            }
 
-        if !result.is_success() {
             return Ok(ToolResult {
-                content: vec![ContentBlock::text(format!(
-                    "Failed to create constitution: {}",
-                    result.stderr
-                ))],
-                is_error: Some(true),
+                content: vec![ContentBlock::text(message)],
+                is_error: None,
             });
         }
 
-        let message = format!(
+        let principles = params
+            .principles
+            .context("principles is required unless amend is given")?;
+
+        tracing::info!(
+            output_path = %output_path.display(),
+            "Creating constitution"
+        );
+
+        // Render the constitution from the project's template
+        let template =
+            Template::load(layout.root(), TemplateKind::Constitution, self.cli.paths()).await?;
+        let context = TemplateContext::for_layout(&layout, chrono::Local::now().date_naive())
+            .set("[CONSTITUTION_VERSION]", "1.0.0")
+            .set("$ARGUMENTS", principles.trim())
+            .section("Core Principles", principles.trim());
+        let context = match &params.constraints {
+            Some(constraints) => context.section("Technical Constraints", constraints.trim()),
+            None => context.omit("Technical Constraints"),
+        };
+        let content = template.render(&context)?;
+
+        // Write constitution file
+        let outcome = self
+            .cli
+            .constitution(&content, &output_path, options)
+            .await?;
+        if outcome.dry_run {
+            return Ok(dry_run_result(&outcome));
+        }
+
+        let mut message = format!(
             "Constitution created successfully at {}\n\n\
             The constitution defines:\n\
             - Core principles that guide development\n\
             - Technical constraints and boundaries\n\
             - Standards for code quality and architecture\n\n\
             Next step: Use speckit_specify tool to define requirements",
-            params.output_path.display()
+            output_path.display()
         );
 
+        message.push_str(&format!("\n\nFile: {}", outcome.summary()));
+
+        if params.commit {
+            message.push_str(&commit_phase(&outcome.resolved, "constitution").await);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -123,6 +229,8 @@ impl Tool for ConstitutionTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::speckit::SpecKitError;
+    use crate::utils::PathPolicy;
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -153,4 +261,70 @@ mod tests {
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
         assert!(output_path.exists());
     }
+
+    #[tokio::test]
+    async fn test_constitution_rejects_paths_outside_workspace() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path().join("workspace");
+        std::fs::create_dir(&workspace).unwrap();
+        let cli = SpecKitCli::new_test_mode().with_path_policy(PathPolicy::new([workspace]));
+        let tool = ConstitutionTool::new(cli);
+
+        let outside = dir.path().join(".bashrc");
+        let params = json!({
+            "principles": "Simplicity",
+            "output_path": outside.to_str().unwrap()
+        });
+
+        let error = tool.execute(params).await.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SpecKitError>(),
+            Some(SpecKitError::InvalidPath(_))
+        ));
+        assert!(!outside.exists());
+    }
+
+    #[tokio::test]
+    async fn test_constitution_amend_principle() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = ConstitutionTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let output_path = dir.path().join("constitution.md");
+        tool.execute(json!({
+            "principles": "### Simplicity\n\nPrefer boring solutions.",
+            "constraints": "Rust 1.70+",
+            "output_path": output_path.to_str().unwrap()
+        }))
+        .await
+        .unwrap();
+
+        let result = tool
+            .execute(json!({
+                "output_path": output_path.to_str().unwrap(),
+                "amend": {
+                    "action": "add",
+                    "section": "Core Principles",
+                    "principle": "Observability",
+                    "content": "Every service exports metrics.",
+                    "rationale": "Incident review"
+                }
+            }))
+            .await
+            .unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Sync Impact Report"));
+        assert!(text.contains("1.0.0 → 1.1.0 (minor)"));
+
+        let content = std::fs::read_to_string(&output_path).unwrap();
+        assert!(content.contains("**Version**: 1.1.0"));
+        assert!(content.contains(
+            "Prefer boring solutions.\n\n### Observability\n\nEvery service exports metrics.\n\n## Technical Constraints\n\nRust 1.70+"
+        ));
+        assert!(content.contains("## Amendment History"));
+        assert!(content.contains("Rationale: Incident review"));
+    }
 }
@@ -0,0 +1,258 @@
+//! Sampling Drafts
+//!
+//! Drafts plans and task lists with the client's model through MCP sampling
+//! for `speckit_plan` and `speckit_tasks`: builds the prompt from the
+//! project's template and existing artifacts, validates each draft and sends
+//! the problems back to the model for a bounded number of attempts.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::artifacts::{
+    clean_draft, draft_prompt, retry_prompt, system_prompt, validate_draft, write_artifact,
+    Artifact, ArtifactKind, ArtifactSet, DraftKind, ProjectLayout, Template, TemplateContext,
+    WriteOptions, DEFAULT_DRAFT_ATTEMPTS, DRAFT_MAX_TOKENS, MAX_DRAFT_ATTEMPTS,
+};
+use crate::mcp::peer::ClientPeer;
+use crate::mcp::types::{ContentBlock, CreateMessageParams, Role, SamplingMessage, ToolResult};
+use crate::speckit::SpecKitCli;
+use crate::tools::commit_phase;
+
+/// How `speckit_plan` and `speckit_tasks` produce their artifact
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Generator {
+    /// The spec-kit CLI when installed, otherwise the client's model
+    #[default]
+    Auto,
+
+    /// Always the spec-kit CLI
+    Cli,
+
+    /// Always the client's model through MCP sampling
+    Sampling,
+}
+
+/// JSON schema of the `generator` parameter
+pub(crate) fn generator_schema() -> Value {
+    json!({
+        "type": "string",
+        "enum": ["auto", "cli", "sampling"],
+        "default": "auto",
+        "description": "How to produce the artifact: the spec-kit CLI (cli), the client's model through MCP sampling using spec-kit's template and the existing artifacts (sampling), or the CLI when installed and sampling otherwise (auto)"
+    })
+}
+
+/// JSON schema of the `max_attempts` parameter
+pub(crate) fn max_attempts_schema() -> Value {
+    json!({
+        "type": "integer",
+        "minimum": 1,
+        "maximum": MAX_DRAFT_ATTEMPTS,
+        "default": DEFAULT_DRAFT_ATTEMPTS,
+        "description": "Sampling attempts; drafts that fail structural validation are sent back to the model with the problems found"
+    })
+}
+
+/// Resolve `auto` to the CLI when it is installed or sampling is unavailable
+pub(crate) async fn resolve_generator(
+    generator: Generator,
+    cli: &SpecKitCli,
+    client: &ClientPeer,
+) -> Generator {
+    match generator {
+        Generator::Auto if client.supports_sampling() && !cli.ensure_available().await => {
+            Generator::Sampling
+        }
+        Generator::Auto => Generator::Cli,
+        generator => generator,
+    }
+}
+
+/// A plan or task list to draft through sampling
+pub(crate) struct DraftRequest<'a> {
+    /// Artifact to draft
+    pub kind: DraftKind,
+
+    /// Layout the context artifacts and template are loaded from
+    pub layout: &'a ProjectLayout,
+
+    /// The artifact the draft is derived from (spec for plans, plan for tasks)
+    pub input: (ArtifactKind, &'a Path),
+
+    /// Where the accepted draft is written
+    pub output_path: &'a Path,
+
+    /// Extra instructions for the model
+    pub guidance: Option<String>,
+
+    /// Sampling attempts
+    pub max_attempts: u32,
+
+    /// Commit the written artifact
+    pub commit: bool,
+}
+
+/// Draft a plan or task list with the client's model and write it once it
+/// passes validation; a draft still invalid after the last attempt is
+/// returned as an error result and not written
+pub(crate) async fn draft_artifact(
+    cli: &SpecKitCli,
+    client: &ClientPeer,
+    request: DraftRequest<'_>,
+) -> Result<ToolResult> {
+    let (input_kind, input_path) = request.input;
+    let mut artifacts = ArtifactSet::load_layout(request.layout, cli.paths()).await?;
+    let input = cli
+        .paths()
+        .read_to_string(input_path)
+        .await
+        .with_context(|| format!("Failed to read {}", input_path.display()))?;
+    let mut artifact = Artifact::new(input_kind, input_path, input);
+    artifact.label = request.layout.relative(input_path);
+    artifacts.insert(artifact);
+
+    let template = Template::load(
+        request.layout.root(),
+        request.kind.template_kind(),
+        cli.paths(),
+    )
+    .await?
+    .render(&TemplateContext::for_layout(
+        request.layout,
+        chrono::Local::now().date_naive(),
+    ))?;
+    let draft = sample_draft(
+        client,
+        request.kind,
+        &artifacts,
+        &template,
+        request.guidance.as_deref(),
+        request.max_attempts.clamp(1, MAX_DRAFT_ATTEMPTS),
+    )
+    .await?;
+
+    if !draft.problems.is_empty() {
+        return Ok(ToolResult {
+            content: vec![
+                ContentBlock::text(format!(
+                    "The {} drafted by {} failed validation after {} attempt(s); nothing was written.\n\n{}",
+                    request.kind,
+                    draft.model,
+                    draft.attempts,
+                    draft
+                        .problems
+                        .iter()
+                        .map(|problem| format!("- {}", problem))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )),
+                ContentBlock::text(draft.content),
+            ],
+            is_error: Some(true),
+        });
+    }
+
+    let outcome = write_artifact(
+        cli.paths(),
+        request.output_path,
+        &draft.content,
+        WriteOptions::default(),
+    )
+    .await
+    .with_context(|| format!("Failed to write the {}", request.kind))?;
+
+    let mut message = format!(
+        "Drafted the {} with {} through MCP sampling ({} attempt(s))\n\nFile: {}",
+        request.kind,
+        draft.model,
+        draft.attempts,
+        outcome.summary()
+    );
+    if request.commit {
+        let phase = match request.kind {
+            DraftKind::Plan => "plan",
+            DraftKind::Tasks => "tasks",
+        };
+        message.push_str(&commit_phase(&outcome.resolved, phase).await);
+    }
+
+    Ok(ToolResult {
+        content: vec![
+            ContentBlock::text(message),
+            ContentBlock::text(draft.content),
+        ],
+        is_error: None,
+    })
+}
+
+/// A draft generated by the client's model
+pub(crate) struct SampledDraft {
+    /// The last draft received
+    pub content: String,
+
+    /// Model reported by the client
+    pub model: String,
+
+    /// Number of sampling requests made
+    pub attempts: u32,
+
+    /// Validation problems of the last draft (empty when it was accepted)
+    pub problems: Vec<String>,
+}
+
+/// Draft a plan or task list with the client's model, sending validation
+/// problems back as feedback for up to `max_attempts` requests
+pub(crate) async fn sample_draft(
+    client: &ClientPeer,
+    kind: DraftKind,
+    artifacts: &ArtifactSet,
+    template: &str,
+    guidance: Option<&str>,
+    max_attempts: u32,
+) -> Result<SampledDraft> {
+    let mut messages = vec![SamplingMessage::text(
+        Role::User,
+        draft_prompt(kind, template, artifacts, guidance),
+    )];
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        let result = client
+            .create_message(CreateMessageParams {
+                messages: messages.clone(),
+                system_prompt: Some(system_prompt(kind)),
+                max_tokens: DRAFT_MAX_TOKENS,
+                temperature: None,
+            })
+            .await
+            .with_context(|| format!("Failed to draft the {} through sampling", kind))?;
+
+        let text = result
+            .text()
+            .ok_or_else(|| anyhow::anyhow!("The client's model returned non-text content"))?;
+        let content = clean_draft(text);
+        let problems = validate_draft(kind, &content, artifacts);
+
+        tracing::info!(
+            kind = %kind,
+            attempt = attempts,
+            problems = problems.len(),
+            "Received sampled draft"
+        );
+
+        if problems.is_empty() || attempts >= max_attempts {
+            return Ok(SampledDraft {
+                content,
+                model: result.model,
+                attempts,
+                problems,
+            });
+        }
+
+        messages.push(SamplingMessage::text(Role::Assistant, text));
+        messages.push(SamplingMessage::text(Role::User, retry_prompt(&problems)));
+    }
+}
@@ -0,0 +1,568 @@
+//! Spec-Kit Feature Tools
+//!
+//! Creates numbered feature directories (`specs/NNN-slug`), lists them with
+//! their artifact completeness and switches the active feature.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+use crate::artifacts::{ArtifactPaths, Feature, ProjectLayout};
+use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::speckit::{GitRepo, SpecKitCli, SpecKitError};
+use crate::tools::Tool;
+
+/// Supporting artifacts produced during planning
+const SUPPORTING_FILES: [&str; 3] = ["research.md", "data-model.md", "contracts"];
+
+/// Parameters for the speckit_feature_create tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FeatureCreateParams {
+    /// Natural-language description of the feature
+    description: String,
+
+    /// Explicit slug for the directory name (optional)
+    #[serde(default)]
+    short_name: Option<String>,
+
+    /// Create and check out the feature branch when in a git repository
+    #[serde(default = "default_true")]
+    git_branch: bool,
+
+    /// Project directory
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+}
+
+/// Parameters for the speckit_feature_list tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FeatureListParams {
+    /// Project directory
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+}
+
+/// Parameters for the speckit_feature_switch tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FeatureSwitchParams {
+    /// Feature directory name (`002-billing`) or number (`2`)
+    feature: String,
+
+    /// Check out the feature branch when in a git repository
+    #[serde(default = "default_true")]
+    git_branch: bool,
+
+    /// Project directory
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+/// Check out a feature's `NNN-slug` branch, returning a status line
+async fn checkout_feature_branch(layout: &ProjectLayout, feature: &Feature) -> String {
+    let Some(repo) = GitRepo::discover(layout.root()) else {
+        return "Git: not a git repository, no branch created".to_string();
+    };
+
+    match repo.switch_branch(&feature.name).await {
+        Ok(switch) => format!("Git: {}", switch),
+        Err(e) => {
+            tracing::warn!(error = %e, branch = %feature.name, "Failed to switch branch");
+            format!("Git: could not switch to branch {}: {}", feature.name, e)
+        }
+    }
+}
+
+/// Why a feature recorded as active would not be used: the current git
+/// branch names a different feature, and branches take precedence
+fn branch_conflict(layout: &ProjectLayout, feature: &Feature) -> Option<String> {
+    layout
+        .branch_feature()
+        .filter(|branch| branch.name != feature.name)
+        .map(|branch| {
+            format!(
+                "The current git branch selects feature {}, which takes precedence over the \
+                active feature; check out {} (or pass git_branch=true) to use {}",
+                branch.name, feature.name, feature.name
+            )
+        })
+}
+
+/// Check mark for a file or directory inside a feature
+fn presence(feature: &Feature, name: impl AsRef<Path>) -> &'static str {
+    if feature.file(name).exists() {
+        "✓"
+    } else {
+        "-"
+    }
+}
+
+/// Number of core artifacts (spec, plan, tasks) a feature has, under the
+/// project's configured names
+fn completeness(feature: &Feature, artifacts: &ArtifactPaths) -> usize {
+    artifacts
+        .feature_files()
+        .iter()
+        .filter(|name| feature.file(name).exists())
+        .count()
+}
+
+/// Tool for creating feature directories
+pub struct FeatureCreateTool {
+    cli: SpecKitCli,
+}
+
+impl FeatureCreateTool {
+    /// Create a new feature create tool
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Tool for FeatureCreateTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_feature_create".to_string(),
+            description: "Create the next numbered feature directory (specs/NNN-slug) from a description and make it the active feature".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "description": {
+                        "type": "string",
+                        "description": "Natural-language description of the feature; the directory slug is derived from its first meaningful words"
+                    },
+                    "short_name": {
+                        "type": "string",
+                        "description": "Explicit slug for the directory name (optional, e.g. 'user-auth')"
+                    },
+                    "git_branch": {
+                        "type": "boolean",
+                        "description": "Create and check out a branch named after the feature when the project is a git repository",
+                        "default": true
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory containing specs/",
+                        "default": "."
+                    }
+                },
+                "required": ["description"]
+            })
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: FeatureCreateParams =
+            serde_json::from_value(params).context("Failed to parse feature create parameters")?;
+
+        tracing::info!(description = %params.description, "Creating feature");
+
+        let mut layout =
+            ProjectLayout::resolve_with_feature_in(&params.project_path, None, self.cli.paths())?;
+        let feature = layout
+            .create_feature(
+                &params.description,
+                params.short_name.as_deref(),
+                self.cli.paths(),
+            )
+            .await
+            .context("Failed to create feature")?;
+
+        let branch = if params.git_branch {
+            format!("{}\n", checkout_feature_branch(&layout, &feature).await)
+        } else {
+            String::new()
+        };
+
+        let active = match branch_conflict(&layout, &feature) {
+            Some(conflict) => format!("Warning: {}", conflict),
+            None => "It is now the active feature; spec, plan and tasks tools default to its \
+                directory."
+                .to_string(),
+        };
+        let message = format!(
+            "Feature {} created at {}\n{}\n{}\n\n\
+            Next step: Use speckit_specify tool to define requirements",
+            feature.name,
+            layout.relative(&feature.path),
+            branch,
+            active
+        );
+
+        Ok(ToolResult {
+            content: vec![ContentBlock::text(message)],
+            is_error: None,
+        })
+    }
+}
+
+/// Tool for listing features
+pub struct FeatureListTool {
+    cli: SpecKitCli,
+}
+
+impl FeatureListTool {
+    /// Create a new feature list tool
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Tool for FeatureListTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_feature_list".to_string(),
+            description:
+                "List feature directories with their artifact completeness and the active feature"
+                    .to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory containing specs/",
+                        "default": "."
+                    }
+                },
+                "required": []
+            }),
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: FeatureListParams =
+            serde_json::from_value(params).context("Failed to parse feature list parameters")?;
+
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let features = layout.features();
+        let active = layout.feature().map(|f| f.name.clone());
+
+        tracing::info!(count = features.len(), "Listing features");
+
+        if features.is_empty() {
+            return Ok(ToolResult {
+                content: vec![ContentBlock::text(
+                    "No features found. Use speckit_feature_create tool to start one.",
+                )],
+                is_error: None,
+            });
+        }
+
+        let core = layout.artifact_paths().feature_files();
+        let mut report = String::from("# Features\n\n| Active | Feature |");
+        for name in core {
+            report.push_str(&format!(" {} |", name.display()));
+        }
+        report.push_str(
+            " research.md | data-model.md | contracts/ | Complete |\n|--------|---------|",
+        );
+        report.push_str(&"---|".repeat(core.len() + SUPPORTING_FILES.len()));
+        report.push_str("----------|\n");
+        for feature in &features {
+            let mut row = format!(
+                "| {} | {} |",
+                if active.as_ref() == Some(&feature.name) {
+                    "*"
+                } else {
+                    ""
+                },
+                feature.name
+            );
+            for name in core {
+                row.push_str(&format!(" {} |", presence(feature, name)));
+            }
+            for name in SUPPORTING_FILES {
+                row.push_str(&format!(" {} |", presence(feature, name)));
+            }
+            row.push_str(&format!(
+                " {}/{} |\n",
+                completeness(feature, layout.artifact_paths()),
+                core.len()
+            ));
+            report.push_str(&row);
+        }
+
+        if let Some(active) = active {
+            report.push_str(&format!("\nActive feature: {}\n", active));
+        }
+
+        Ok(ToolResult {
+            content: vec![ContentBlock::text(report)],
+            is_error: None,
+        })
+    }
+}
+
+/// Tool for switching the active feature
+pub struct FeatureSwitchTool {
+    cli: SpecKitCli,
+}
+
+impl FeatureSwitchTool {
+    /// Create a new feature switch tool
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Tool for FeatureSwitchTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_feature_switch".to_string(),
+            description: "Set the active feature that spec, plan and tasks tools default to"
+                .to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "feature": {
+                        "type": "string",
+                        "description": "Feature directory name (e.g. '002-billing') or number (e.g. '2')"
+                    },
+                    "git_branch": {
+                        "type": "boolean",
+                        "description": "Check out the feature's branch (creating it if needed) when the project is a git repository",
+                        "default": true
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory containing specs/",
+                        "default": "."
+                    }
+                },
+                "required": ["feature"]
+            }),
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: FeatureSwitchParams =
+            serde_json::from_value(params).context("Failed to parse feature switch parameters")?;
+
+        tracing::info!(feature = %params.feature, "Switching feature");
+
+        let mut layout =
+            ProjectLayout::resolve_with_feature_in(&params.project_path, None, self.cli.paths())?;
+        let feature = layout.find_feature(&params.feature).ok_or_else(|| {
+            SpecKitError::InvalidPath(format!("Feature not found: {}", params.feature))
+        })?;
+
+        let checkout = if params.git_branch {
+            Some(checkout_feature_branch(&layout, &feature).await)
+        } else {
+            None
+        };
+        // Recording a feature the branch overrides would leave later tools on
+        // the branch's feature
+        if let Some(conflict) = branch_conflict(&layout, &feature) {
+            match checkout {
+                Some(checkout) => anyhow::bail!("{}\n{}", checkout, conflict),
+                None => anyhow::bail!("{}", conflict),
+            }
+        }
+        layout
+            .set_active_feature(feature.clone(), self.cli.paths())
+            .await
+            .context("Failed to record active feature")?;
+
+        let mut message = format!(
+            "Active feature: {} ({}/{} core artifacts)",
+            feature.name,
+            completeness(&feature, layout.artifact_paths()),
+            layout.artifact_paths().feature_files().len()
+        );
+        if let Some(checkout) = checkout {
+            message.push('\n');
+            message.push_str(&checkout);
+        }
+
+        Ok(ToolResult {
+            content: vec![ContentBlock::text(message)],
+            is_error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::PathPolicy;
+    use tempfile::tempdir;
+    use tokio::fs;
+
+    #[tokio::test]
+    async fn test_feature_tool_definitions() {
+        let cli = SpecKitCli::new();
+        assert_eq!(
+            FeatureCreateTool::new(cli.clone()).definition().name,
+            "speckit_feature_create"
+        );
+        assert_eq!(
+            FeatureListTool::new(cli.clone()).definition().name,
+            "speckit_feature_list"
+        );
+        assert_eq!(
+            FeatureSwitchTool::new(cli).definition().name,
+            "speckit_feature_switch"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_feature_tools_execute() {
+        let cli = SpecKitCli::new_test_mode();
+        let dir = tempdir().unwrap();
+        let project = dir.path().to_str().unwrap();
+
+        let create = FeatureCreateTool::new(cli.clone());
+        create
+            .execute(json!({"description": "User authentication", "project_path": project}))
+            .await
+            .unwrap();
+        create
+            .execute(json!({"description": "Billing", "project_path": project}))
+            .await
+            .unwrap();
+        fs::write(
+            dir.path().join("specs/001-user-authentication/spec.md"),
+            "# Spec",
+        )
+        .await
+        .unwrap();
+
+        let switch = FeatureSwitchTool::new(cli.clone());
+        let result = switch
+            .execute(json!({"feature": "1", "project_path": project}))
+            .await
+            .unwrap();
+        assert!(result.is_error.is_none());
+
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "001-user-authentication");
+
+        let list = FeatureListTool::new(cli.clone());
+        let result = list
+            .execute(json!({"project_path": project}))
+            .await
+            .unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("| * | 001-user-authentication | ✓ |"));
+        assert!(text.contains("| 1/3 |"));
+        assert!(text.contains("| 002-billing |"));
+
+        let missing = switch
+            .execute(json!({"feature": "009", "project_path": project}))
+            .await;
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_feature_list_uses_configured_names() {
+        let dir = tempdir().unwrap();
+        let paths =
+            PathPolicy::new([dir.path().to_path_buf()]).with_artifact_paths(ArtifactPaths {
+                spec: PathBuf::from("requirements.md"),
+                ..ArtifactPaths::default()
+            });
+        let cli = SpecKitCli::new_test_mode().with_path_policy(paths);
+        let project = dir.path().to_str().unwrap();
+
+        FeatureCreateTool::new(cli.clone())
+            .execute(json!({"description": "Search", "project_path": project}))
+            .await
+            .unwrap();
+        let feature = dir.path().join("specs/001-search");
+        fs::write(feature.join("requirements.md"), "# Spec")
+            .await
+            .unwrap();
+        fs::write(feature.join("plan.md"), "# Plan").await.unwrap();
+        fs::write(feature.join("tasks.md"), "# Tasks")
+            .await
+            .unwrap();
+
+        let result = FeatureListTool::new(cli)
+            .execute(json!({"project_path": project}))
+            .await
+            .unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("| Active | Feature | requirements.md | plan.md | tasks.md |"));
+        assert!(text.contains("| * | 001-search | ✓ | ✓ | ✓ |"));
+        assert!(text.contains("| 3/3 |"));
+    }
+
+    #[tokio::test]
+    async fn test_feature_tools_git_branches() {
+        let cli = SpecKitCli::new_test_mode();
+        let dir = tempdir().unwrap();
+        crate::speckit::git::init_test_repo(dir.path());
+        let project = dir.path().to_str().unwrap();
+        let repo = GitRepo::discover(dir.path()).unwrap();
+
+        let create = FeatureCreateTool::new(cli.clone());
+        create
+            .execute(json!({"description": "User authentication", "project_path": project}))
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.current_branch().as_deref(),
+            Some("001-user-authentication")
+        );
+
+        create
+            .execute(
+                json!({"description": "Billing", "project_path": project, "git_branch": false}),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.current_branch().as_deref(),
+            Some("001-user-authentication")
+        );
+
+        let switch = FeatureSwitchTool::new(cli);
+        switch
+            .execute(json!({"feature": "002", "project_path": project}))
+            .await
+            .unwrap();
+        assert_eq!(repo.current_branch().as_deref(), Some("002-billing"));
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+
+        // Without a checkout the branch would keep selecting 002-billing, so
+        // the switch is refused rather than recorded
+        let error = switch
+            .execute(json!({"feature": "001", "project_path": project, "git_branch": false}))
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("The current git branch selects feature 002-billing"));
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "002-billing");
+        let recorded = std::fs::read_to_string(layout.active_feature_file()).unwrap();
+        assert_eq!(recorded.trim(), "002-billing");
+
+        // On a non-feature branch the record decides
+        repo.switch_branch("main").await.unwrap();
+        switch
+            .execute(json!({"feature": "001", "project_path": project, "git_branch": false}))
+            .await
+            .unwrap();
+        let layout = ProjectLayout::resolve_with_feature(dir.path(), None);
+        assert_eq!(layout.feature().unwrap().name, "001-user-authentication");
+    }
+}
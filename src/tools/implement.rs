@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{parse_tasks, ArtifactKind, MarkdownDocument, ProjectLayout};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
 use crate::tools::Tool;
@@ -15,8 +16,9 @@ use crate::tools::Tool;
 /// Parameters for the speckit_implement tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ImplementParams {
-    /// Path to tasks file
-    task_file: PathBuf,
+    /// Path to tasks file (defaults to the project layout)
+    #[serde(default)]
+    task_file: Option<PathBuf>,
 
     /// Additional context for implementation
     #[serde(default)]
@@ -25,12 +27,20 @@ pub struct ImplementParams {
     /// Output directory for implementation
     #[serde(default = "default_output_dir")]
     output_dir: PathBuf,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
 }
 
 fn default_output_dir() -> PathBuf {
     PathBuf::from("./src")
 }
 
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
 /// Tool for executing implementation
 pub struct ImplementTool {
     #[allow(dead_code)]
@@ -55,7 +65,7 @@ impl Tool for ImplementTool {
                 "properties": {
                     "task_file": {
                         "type": "string",
-                        "description": "Path to the tasks file (speckit.tasks)"
+                        "description": "Path to the tasks file (defaults to the active feature's tasks.md, or ./speckit.tasks)"
                     },
                     "context": {
                         "type": "string",
@@ -65,9 +75,14 @@ impl Tool for ImplementTool {
                         "type": "string",
                         "description": "Directory where code will be generated",
                         "default": "./src"
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     }
                 },
-                "required": ["task_file"]
+                "required": []
             })
         }
     }
@@ -76,14 +91,23 @@ impl Tool for ImplementTool {
         let params: ImplementParams =
             serde_json::from_value(params).context("Failed to parse implement parameters")?;
 
+        let task_file = match params.task_file.clone() {
+            Some(task_file) => task_file,
+            None => ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?
+                .read_path(ArtifactKind::Tasks),
+        };
+
         tracing::info!(
-            task_file = %params.task_file.display(),
+            task_file = %task_file.display(),
             output_dir = %params.output_dir.display(),
             "Executing implementation"
         );
 
         // Read the tasks file
-        let tasks_content = tokio::fs::read_to_string(&params.task_file)
+        let tasks_content = self
+            .cli
+            .paths()
+            .read_to_string(&task_file)
             .await
             .context("Failed to read tasks file")?;
 
@@ -102,12 +126,8 @@ impl Tool for ImplementTool {
             Context: {}\n\n\
             Output directory: {}\n\n\
             Next step: Begin implementing the first task",
-            params.task_file.display(),
-            tasks_content
-                .lines()
-                .take(10)
-                .collect::<Vec<_>>()
-                .join("\n"),
+            task_file.display(),
+            task_summary(&tasks_content),
             params.context.as_deref().unwrap_or("None provided"),
             params.output_dir.display()
         );
@@ -119,6 +139,35 @@ impl Tool for ImplementTool {
     }
 }
 
+/// The first tasks of a task list, or its first prose lines when it has no
+/// recognizable task entries
+fn task_summary(content: &str) -> String {
+    const LIMIT: usize = 10;
+
+    let tasks = parse_tasks(content);
+    if tasks.is_empty() {
+        return MarkdownDocument::parse(content)
+            .prose_lines()
+            .into_iter()
+            .map(|(_, text)| text)
+            .filter(|text| !text.trim().is_empty())
+            .take(LIMIT)
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    let mut summary = tasks
+        .iter()
+        .take(LIMIT)
+        .map(|task| format!("- {} {}", task.id, task.text))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if tasks.len() > LIMIT {
+        summary.push_str(&format!("\n... and {} more", tasks.len() - LIMIT));
+    }
+    summary
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +206,20 @@ mod tests {
         let result = tool.execute(params).await.unwrap();
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
     }
+
+    #[test]
+    fn test_task_summary() {
+        let tasks = "# Tasks\n\n\
+            ```bash\n- [ ] T099 Not a task\n```\n\n\
+            - [ ] T001 Create model\n\
+            - [x] T002 Add endpoint (FR-001)\n";
+        assert_eq!(
+            task_summary(tasks),
+            "- T001 Create model\n- T002 Add endpoint (FR-001)"
+        );
+        assert_eq!(
+            task_summary("Task 1: Implement feature\nTask 2: Write tests"),
+            "Task 1: Implement feature\nTask 2: Write tests"
+        );
+    }
 }
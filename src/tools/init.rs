@@ -1,26 +1,48 @@
 //! Spec-Kit Init Tool
 //!
-//! Initializes a new spec-kit project.
+//! Initializes a new spec-kit project, optionally set up for an AI agent
+//! and script flavor, and reports the command files written for the agent.
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
-use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::speckit::{AiAgent, InitOptions, ScriptType, SpecKitCli};
+use crate::tools::{CliRequirement, Tool};
 
 /// Parameters for the speckit_init tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct InitParams {
-    /// Project name
-    project_name: String,
+    /// Project name; required unless `here` is set
+    #[serde(default)]
+    project_name: Option<String>,
 
-    /// Project path (defaults to current directory)
+    /// Directory the project is created in (defaults to current directory)
     #[serde(default = "default_project_path")]
     project_path: PathBuf,
+
+    /// Initialize `project_path` itself instead of a new subdirectory
+    #[serde(default)]
+    here: bool,
+
+    /// AI agent to set up (`--ai`)
+    #[serde(default)]
+    ai_agent: Option<AiAgent>,
+
+    /// Script flavor (`--script`)
+    #[serde(default)]
+    script_type: Option<ScriptType>,
+
+    /// Skip git repository initialization
+    #[serde(default)]
+    no_git: bool,
+
+    /// Skip the check for the agent's CLI tool
+    #[serde(default)]
+    ignore_agent_tools: bool,
 }
 
 fn default_project_path() -> PathBuf {
@@ -50,47 +72,95 @@ impl Tool for InitTool {
                 "properties": {
                     "project_name": {
                         "type": "string",
-                        "description": "Name of the project to initialize"
+                        "description": "Name of the project to initialize; a directory of this name is created under project_path. Required unless here is true"
                     },
                     "project_path": {
                         "type": "string",
-                        "description": "Path where the project should be created",
+                        "description": "Directory the project is created in",
                         "default": "."
+                    },
+                    "here": {
+                        "type": "boolean",
+                        "description": "Initialize project_path itself instead of creating a subdirectory",
+                        "default": false
+                    },
+                    "ai_agent": {
+                        "type": "string",
+                        "enum": ["claude", "copilot", "gemini", "cursor", "windsurf", "qwen", "opencode", "codex", "kilocode", "auggie", "roo", "q"],
+                        "description": "AI agent to set up; spec-kit writes its command files (e.g. .claude/commands/*.md, .github/prompts/*.prompt.md)"
+                    },
+                    "script_type": {
+                        "type": "string",
+                        "enum": ["sh", "ps"],
+                        "description": "Script flavor of the helper scripts: POSIX shell (sh) or PowerShell (ps)"
+                    },
+                    "no_git": {
+                        "type": "boolean",
+                        "description": "Skip git repository initialization",
+                        "default": false
+                    },
+                    "ignore_agent_tools": {
+                        "type": "boolean",
+                        "description": "Skip the check for the AI agent's CLI tool",
+                        "default": false
                     }
-                },
-                "required": ["project_name"]
+                }
             })
         }
     }
 
+    fn cli_requirement(&self) -> CliRequirement {
+        CliRequirement::Required
+    }
+
     async fn execute(&self, params: Value) -> Result<ToolResult> {
         let params: InitParams =
             serde_json::from_value(params).context("Failed to parse init parameters")?;
 
+        if params.project_name.is_none() && !params.here {
+            return Ok(ToolResult {
+                content: vec![ContentBlock::text(
+                    "project_name is required unless here is true".to_string(),
+                )],
+                is_error: Some(true),
+            });
+        }
+
+        let options = InitOptions {
+            project_name: params.project_name,
+            directory: params.project_path,
+            here: params.here,
+            ai: params.ai_agent,
+            script: params.script_type,
+            no_git: params.no_git,
+            ignore_agent_tools: params.ignore_agent_tools,
+        };
+        let project_dir = options.project_dir();
+
         tracing::info!(
-            project_name = %params.project_name,
-            project_path = %params.project_path.display(),
+            project_name = ?options.project_name,
+            project_dir = %project_dir.display(),
+            ai = ?options.ai,
             "Initializing spec-kit project"
         );
 
         // Execute spec-kit init command
-        let result = self
-            .cli
-            .init(&params.project_name, &params.project_path)
-            .await?;
+        let result = self.cli.init(&options).await?;
+
+        let outcome = result.outcome();
 
-        if !result.is_success() {
+        if !outcome.success {
             return Ok(ToolResult {
                 content: vec![ContentBlock::text(format!(
                     "Failed to initialize project: {}",
-                    result.stderr
+                    outcome.error_text()
                 ))],
                 is_error: Some(true),
             });
         }
 
-        let message = format!(
-            "Successfully initialized spec-kit project '{}' at {}\n\n\
+        let mut message = format!(
+            "Successfully initialized spec-kit project at {}\n\n\
             Next steps:\n\
             1. Navigate to the project: cd {}\n\
             2. Create constitution: Use speckit_constitution tool\n\
@@ -98,11 +168,21 @@ impl Tool for InitTool {
             4. Create technical plan: Use speckit_plan tool\n\
             5. Generate tasks: Use speckit_tasks tool\n\
             6. Implement: Use speckit_implement tool",
-            params.project_name,
-            params.project_path.display(),
-            params.project_path.display()
+            project_dir.display(),
+            project_dir.display()
         );
 
+        if let Some(agent) = options.ai {
+            message.push_str("\n\n");
+            message.push_str(&agent_files_section(agent, &project_dir));
+        }
+
+        let details = outcome.to_markdown();
+        if !details.is_empty() {
+            message.push_str("\n\n");
+            message.push_str(&details);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -110,6 +190,24 @@ impl Tool for InitTool {
     }
 }
 
+/// The agent's command files in the initialized project
+fn agent_files_section(agent: AiAgent, project_dir: &Path) -> String {
+    let files = agent.command_files(project_dir);
+    if files.is_empty() {
+        return format!(
+            "No command files for {} found ({})",
+            agent,
+            agent.command_glob()
+        );
+    }
+
+    let mut section = format!("Command files for {} ({}):", agent, files.len());
+    for file in files {
+        section.push_str(&format!("\n- {}", file.display()));
+    }
+    section
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,4 +237,58 @@ mod tests {
         let result = tool.execute(params).await.unwrap();
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_init_requires_name_unless_here() {
+        let tool = InitTool::new(SpecKitCli::new_test_mode());
+        let dir = tempdir().unwrap();
+
+        let result = tool
+            .execute(json!({ "project_path": dir.path() }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path(),
+                "here": true,
+                "ai_agent": "claude",
+                "no_git": true
+            }))
+            .await
+            .unwrap();
+        assert!(result.is_error.is_none());
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains(&dir.path().display().to_string()));
+        assert!(text.contains("No command files for claude found (.claude/commands/*.md)"));
+    }
+
+    #[tokio::test]
+    async fn test_init_rejects_unknown_agent() {
+        let tool = InitTool::new(SpecKitCli::new_test_mode());
+        let err = tool
+            .execute(json!({ "project_name": "demo", "ai_agent": "vim" }))
+            .await
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("unknown variant `vim`"));
+    }
+
+    #[test]
+    fn test_agent_files_section() {
+        let dir = tempdir().unwrap();
+        let commands = dir.path().join(".claude/commands");
+        std::fs::create_dir_all(&commands).unwrap();
+        std::fs::write(commands.join("speckit.plan.md"), "").unwrap();
+        std::fs::write(commands.join("speckit.specify.md"), "").unwrap();
+
+        assert_eq!(
+            agent_files_section(AiAgent::Claude, dir.path()),
+            "Command files for claude (2):\n\
+             - .claude/commands/speckit.plan.md\n\
+             - .claude/commands/speckit.specify.md"
+        );
+    }
 }
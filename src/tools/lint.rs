@@ -0,0 +1,377 @@
+//! Spec-Kit Lint Tool
+//!
+//! Checks artifacts against configurable structural lint rules and
+//! optionally applies their safe fixes.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+use crate::artifacts::{
+    fix_artifact, lint_artifacts, write_artifact, Artifact, ArtifactSet, LintFix, LintRule,
+    LintSeverity, ProjectLayout, WriteMode, WriteOptions, WriteOutcome,
+};
+use crate::config::LintConfig;
+use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::speckit::SpecKitCli;
+use crate::tools::Tool;
+
+/// Parameters for the speckit_lint tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LintParams {
+    /// Project directory used to resolve artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Rules to skip for this run, on top of the configured ones
+    #[serde(default)]
+    allow: Vec<LintRule>,
+
+    /// Apply the safe fixes and write the fixed artifacts
+    #[serde(default)]
+    fix: bool,
+
+    /// With `fix`, report the fixes and diffs without writing anything
+    #[serde(default)]
+    dry_run: bool,
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+/// Tool for linting spec-kit artifacts
+pub struct LintTool {
+    cli: SpecKitCli,
+    config: LintConfig,
+}
+
+impl LintTool {
+    /// Create a new lint tool with the default rule levels
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self {
+            cli,
+            config: LintConfig::default(),
+        }
+    }
+
+    /// Use configured rule levels
+    pub fn with_config(mut self, config: LintConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+#[async_trait]
+impl Tool for LintTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_lint".to_string(),
+            description: format!(
+                "Lint spec-kit artifacts and return a diagnostic list (severity, rule, file:line, message). Rules: missing_section (spec lacks Requirements or User Stories), needs_clarification (unresolved [NEEDS CLARIFICATION] markers), acceptance_format (acceptance criteria not in Given/When/Then form), task_file_path (tasks without a file path), plan_data_model (plan without a data model), duplicate_task_id (two tasks share an ID), checkbox_syntax (checkboxes other than - [ ] / - [x]), user_story_format (stories not in As a / I want / so that form), requirement_id (requirements without FR-/NFR- IDs). With fix, safe fixes for {} are applied and reported as diffs (dry_run previews them). Rules are configured in [lint] allow/warn/deny or allowed inline with <!-- speckit-lint: allow(rule) -->",
+                LintRule::ALL
+                    .iter()
+                    .filter(|rule| rule.is_fixable())
+                    .map(LintRule::id)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
+                    },
+                    "allow": {
+                        "type": "array",
+                        "items": {
+                            "type": "string",
+                            "enum": ["missing_section", "needs_clarification", "acceptance_format", "task_file_path", "plan_data_model", "duplicate_task_id", "checkbox_syntax", "user_story_format", "requirement_id"]
+                        },
+                        "description": "Rules to skip for this run, on top of the configured ones (even rules configured as warn or deny)"
+                    },
+                    "fix": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Apply safe fixes (renumber duplicate task IDs, add missing sections, normalize checkboxes, rewrite user stories, assign requirement IDs) and report each change as a diff"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "With fix, return the fixes and unified diffs without writing anything"
+                    }
+                },
+                "required": []
+            })
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: LintParams =
+            serde_json::from_value(params).context("Failed to parse lint parameters")?;
+
+        tracing::info!(
+            project_path = %params.project_path.display(),
+            fix = params.fix,
+            dry_run = params.dry_run,
+            "Linting artifacts"
+        );
+
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let mut artifacts = ArtifactSet::load_layout(&layout, self.cli.paths()).await?;
+        if artifacts.present().is_empty() {
+            return Ok(ToolResult {
+                content: vec![ContentBlock::text(format!(
+                    "No artifacts found in {}; create them with speckit_specify, speckit_plan and speckit_tasks",
+                    layout.root().display()
+                ))],
+                is_error: Some(true),
+            });
+        }
+
+        // Per-call allows win over the configured levels
+        let mut config = self.config.clone();
+        config.warn.retain(|rule| !params.allow.contains(rule));
+        config.deny.retain(|rule| !params.allow.contains(rule));
+        config.allow.extend(params.allow);
+
+        // Fix first, so the report lists what is left
+        let mut fixed: Vec<(WriteOutcome, Vec<LintFix>)> = Vec::new();
+        if params.fix {
+            let present: Vec<Artifact> = artifacts.present().into_iter().cloned().collect();
+            for artifact in present {
+                let result = fix_artifact(&artifact, &config);
+                if result.fixes.is_empty() {
+                    continue;
+                }
+                let options = WriteOptions {
+                    mode: WriteMode::Overwrite,
+                    dry_run: params.dry_run,
+                };
+                let outcome =
+                    write_artifact(self.cli.paths(), &artifact.path, &result.content, options)
+                        .await?;
+                artifacts.insert(Artifact::new(artifact.kind, artifact.path, result.content));
+                fixed.push((outcome, result.fixes));
+            }
+        }
+        let report = lint_artifacts(&artifacts, &config);
+
+        let fix_count: usize = fixed.iter().map(|(_, fixes)| fixes.len()).sum();
+        let fix_line = match (params.fix, params.dry_run) {
+            (false, _) => String::new(),
+            (true, false) => format!("Fixed: {} in {} file(s)\n", fix_count, fixed.len()),
+            (true, true) => format!(
+                "Fixable: {} in {} file(s) (dry run, nothing written)\n",
+                fix_count,
+                fixed.len()
+            ),
+        };
+        let message = format!(
+            "Lint complete!\n\n\
+            Artifacts: {}\n\
+            {}\
+            Errors: {}, warnings: {}\n\n\
+            {}",
+            report.artifacts,
+            fix_line,
+            report.count(LintSeverity::Error),
+            report.count(LintSeverity::Warning),
+            if report.diagnostics.is_empty() {
+                "✓ No lint issues"
+            } else if report.has_errors() {
+                "⚠ Fix the errors before moving to the next phase"
+            } else {
+                "Warnings only; fix them or allow the rules that do not apply"
+            }
+        );
+
+        let mut content = vec![ContentBlock::text(message)];
+        content.extend(
+            fixed
+                .iter()
+                .map(|(outcome, fixes)| ContentBlock::text(fix_report(outcome, fixes))),
+        );
+        content.push(ContentBlock::text(report.to_markdown()));
+        content.push(ContentBlock::text(report.to_json()));
+
+        Ok(ToolResult {
+            content,
+            is_error: None,
+        })
+    }
+}
+
+/// The fixes made to one artifact, with the write summary and diff
+fn fix_report(outcome: &WriteOutcome, fixes: &[LintFix]) -> String {
+    let mut report = format!(
+        "## Fixes: {}\n\n{}\n\n",
+        outcome.path.display(),
+        outcome.summary()
+    );
+    for fix in fixes {
+        report.push_str(&format!(
+            "- {} `{}`: {}\n",
+            fix.location, fix.rule, fix.description
+        ));
+    }
+    report.push_str(&format!("\n```diff\n{}```\n", outcome.diff));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tokio::fs;
+
+    #[tokio::test]
+    async fn test_lint_tool_definition() {
+        let cli = SpecKitCli::new();
+        let tool = LintTool::new(cli);
+        let def = tool.definition();
+
+        assert_eq!(def.name, "speckit_lint");
+        assert!(!def.description.is_empty());
+        assert!(def.description.contains(
+            "safe fixes for missing_section, duplicate_task_id, checkbox_syntax, user_story_format, requirement_id are applied"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lint_tool_execute() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("speckit.specify"),
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export [NEEDS CLARIFICATION: formats?]\n",
+        )
+        .await
+        .unwrap();
+        fs::write(
+            dir.path().join("speckit.tasks"),
+            "# Tasks\n\n- [ ] T001 Build the export\n",
+        )
+        .await
+        .unwrap();
+
+        let tool = LintTool::new(SpecKitCli::new_test_mode()).with_config(LintConfig {
+            warn: vec![LintRule::NeedsClarification],
+            ..LintConfig::default()
+        });
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "allow": ["task_file_path"]
+            }))
+            .await
+            .unwrap();
+
+        assert!(result.is_error.is_none());
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Errors: 1, warnings: 1"));
+        let ContentBlock::Text { text } = &result.content[1] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("| error | `missing_section` | speckit.specify | "));
+        assert!(text.contains("| warning | `needs_clarification` | speckit.specify:5 | "));
+        assert!(!text.contains("task_file_path"));
+    }
+
+    #[tokio::test]
+    async fn test_lint_tool_allow_overrides_configured_levels() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("speckit.specify"),
+            "# Spec\n\n## Requirements\n\n- **FR-001**: Export [NEEDS CLARIFICATION: formats?]\n\n\
+             ## User Stories\n\nAs a user, I want exports, so that I can share reports\n",
+        )
+        .await
+        .unwrap();
+
+        let tool = LintTool::new(SpecKitCli::new_test_mode()).with_config(LintConfig {
+            warn: vec![LintRule::NeedsClarification],
+            deny: vec![LintRule::PlanDataModel],
+            ..LintConfig::default()
+        });
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "allow": ["needs_clarification"]
+            }))
+            .await
+            .unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Errors: 0, warnings: 0"));
+    }
+
+    #[tokio::test]
+    async fn test_lint_tool_fix() {
+        let dir = tempdir().unwrap();
+        let tasks_path = dir.path().join("speckit.tasks");
+        let original = "# Tasks\n\n* [X] T001 Create src/model.rs\n- [ ] T001 Add src/api.rs\n";
+        fs::write(&tasks_path, original).await.unwrap();
+        let tool = LintTool::new(SpecKitCli::new_test_mode());
+
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "fix": true,
+                "dry_run": true
+            }))
+            .await
+            .unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Fixable: 2 in 1 file(s) (dry run, nothing written)"));
+        assert!(text.contains("Errors: 0, warnings: 0"));
+        let ContentBlock::Text { text } = &result.content[1] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("- speckit.tasks:3 `checkbox_syntax`: "));
+        assert!(text.contains("- speckit.tasks:4 `duplicate_task_id`: "));
+        assert!(text.contains("\n-- [ ] T001 Add src/api.rs\n"));
+        assert!(text.contains("\n+- [ ] T002 Add src/api.rs\n"));
+        assert_eq!(fs::read_to_string(&tasks_path).await.unwrap(), original);
+
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "fix": true
+            }))
+            .await
+            .unwrap();
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("Fixed: 2 in 1 file(s)"));
+        assert_eq!(
+            fs::read_to_string(&tasks_path).await.unwrap(),
+            "# Tasks\n\n- [x] T001 Create src/model.rs\n- [ ] T002 Add src/api.rs\n"
+        );
+        let ContentBlock::Text { text } = &result.content[1] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("(backup: "));
+    }
+
+    #[tokio::test]
+    async fn test_lint_tool_without_artifacts() {
+        let dir = tempdir().unwrap();
+        let tool = LintTool::new(SpecKitCli::new_test_mode());
+        let result = tool
+            .execute(json!({ "project_path": dir.path().to_str().unwrap() }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+    }
+}
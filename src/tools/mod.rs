@@ -2,36 +2,158 @@
 //!
 //! This module provides all the spec-kit tools exposed via MCP.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
-use crate::mcp::types::{ToolDefinition, ToolResult};
-use crate::speckit::SpecKitCli;
+use crate::artifacts::{amend_document, AmendOutcome, Amendment, WriteOutcome};
+use crate::config::Config;
+use crate::mcp::peer::ClientPeer;
+use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::speckit::{commit_artifact, CliStatus, SpecKitCli, REPROBE_INTERVAL, SPEC_KIT_SOURCE};
+use crate::utils::PathPolicy;
 
 pub mod analyze;
 pub mod check;
 pub mod checklist;
 pub mod clarify;
+pub mod compliance;
 pub mod constitution;
+pub mod drafting;
+pub mod feature;
 pub mod implement;
 pub mod init;
+pub mod lint;
 pub mod plan;
 pub mod specify;
 pub mod tasks;
+pub mod trace;
 
 pub use analyze::AnalyzeTool;
 pub use check::CheckTool;
 pub use checklist::ChecklistTool;
 pub use clarify::ClarifyTool;
+pub use compliance::ComplianceTool;
 pub use constitution::ConstitutionTool;
+pub use drafting::Generator;
+pub use feature::{FeatureCreateTool, FeatureListTool, FeatureSwitchTool};
 pub use implement::ImplementTool;
 pub use init::InitTool;
+pub use lint::LintTool;
 pub use plan::PlanTool;
 pub use specify::SpecifyTool;
 pub use tasks::TasksTool;
+pub use trace::TraceTool;
+
+/// Commit an artifact written by a workflow phase, returning a status line
+/// to append to the tool's message
+pub(crate) async fn commit_phase(path: &Path, phase: &str) -> String {
+    match commit_artifact(path, phase).await {
+        Ok(outcome) => format!("\n\nGit: {}", outcome),
+        Err(e) => {
+            tracing::warn!(error = %e, path = %path.display(), "Failed to commit artifact");
+            format!("\n\nGit: commit failed: {}", e)
+        }
+    }
+}
+
+/// Result of a dry-run write: a summary, the unified diff and the would-be
+/// content
+pub(crate) fn dry_run_result(outcome: &WriteOutcome) -> ToolResult {
+    let diff = if outcome.diff.is_empty() {
+        "No changes.".to_string()
+    } else {
+        format!("```diff\n{}```", outcome.diff)
+    };
+
+    ToolResult {
+        content: vec![
+            ContentBlock::text(outcome.summary()),
+            ContentBlock::text(diff),
+            ContentBlock::text(outcome.content.clone()),
+        ],
+        is_error: None,
+    }
+}
+
+/// Apply a section-level amendment to an existing artifact, dated today
+pub(crate) async fn amend_artifact(
+    paths: &PathPolicy,
+    path: &Path,
+    amendment: &Amendment,
+) -> Result<AmendOutcome> {
+    let existing = paths
+        .read_to_string(path)
+        .await
+        .with_context(|| format!("Nothing to amend at {}", path.display()))?;
+    amend_document(&existing, amendment, chrono::Local::now().date_naive())
+}
+
+/// JSON schema of the `amend` parameter; `unit` names the `###` subsections
+/// (e.g. "principle")
+pub(crate) fn amend_schema(unit: &str) -> Value {
+    json!({
+        "type": "object",
+        "description": "Section-level change instead of a full rewrite: add, replace or remove one ## section (or one ### subsection inside it), preserving the rest of the document. Bumps the semantic version (remove: major, add: minor, replace: patch), updates the Last Amended date and appends an Amendment History entry",
+        "properties": {
+            "action": {
+                "type": "string",
+                "enum": ["add", "replace", "remove"]
+            },
+            "section": {
+                "type": "string",
+                "description": "## section heading to change (case-insensitive)"
+            },
+            "principle": {
+                "type": "string",
+                "description": format!("### {} inside the section to change instead of the whole section; spec-kit numbering such as \"I.\" is ignored", unit)
+            },
+            "content": {
+                "type": "string",
+                "description": "New body (required for add and replace)"
+            },
+            "bump": {
+                "type": "string",
+                "enum": ["major", "minor", "patch"],
+                "description": "Override the version bump implied by the action"
+            },
+            "rationale": {
+                "type": "string",
+                "description": "Reason recorded in the history entry"
+            }
+        },
+        "required": ["action", "section"]
+    })
+}
+
+/// Version change and history entry of an amendment, for tool messages
+pub(crate) fn amendment_report(outcome: &AmendOutcome) -> String {
+    format!(
+        "- Version: {} → {} ({})\n- History: {}",
+        outcome
+            .previous_version
+            .map_or_else(|| "unversioned".to_string(), |v| v.to_string()),
+        outcome.version,
+        outcome.bump,
+        outcome.entry.trim_start_matches("- ")
+    )
+}
+
+/// How a tool depends on the spec-kit CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliRequirement {
+    /// Runs without the CLI
+    None,
+
+    /// Needs the CLI
+    Required,
+
+    /// Needs the CLI unless the client supports sampling
+    UnlessSampling,
+}
 
 /// Trait for all MCP tools
 #[async_trait]
@@ -42,6 +164,11 @@ pub trait Tool: Send + Sync {
     /// Execute the tool
     async fn execute(&self, params: Value) -> Result<ToolResult>;
 
+    /// How the tool depends on the spec-kit CLI
+    fn cli_requirement(&self) -> CliRequirement {
+        CliRequirement::None
+    }
+
     /// Get the tool name
     fn name(&self) -> String {
         self.definition().name.clone()
@@ -51,6 +178,9 @@ pub trait Tool: Send + Sync {
 /// Tool registry
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn Tool>>,
+    client: ClientPeer,
+    paths: PathPolicy,
+    cli: Option<SpecKitCli>,
 }
 
 impl ToolRegistry {
@@ -58,25 +188,129 @@ impl ToolRegistry {
     pub fn new() -> Self {
         Self {
             tools: HashMap::new(),
+            client: ClientPeer::new(),
+            paths: PathPolicy::default(),
+            cli: None,
         }
     }
 
+    /// Set the spec-kit CLI whose availability decides which tools are
+    /// offered; without it every tool is offered
+    pub fn with_cli(mut self, cli: SpecKitCli) -> Self {
+        self.cli = Some(cli);
+        self
+    }
+
+    /// Set the workspace path policy shared with the tools
+    pub fn with_paths(mut self, paths: PathPolicy) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Workspace path policy shared with the tools, updated by the server
+    /// from the client's roots
+    pub fn paths(&self) -> &PathPolicy {
+        &self.paths
+    }
+
+    /// Handle to the client, shared with tools that send it requests and
+    /// connected by the server
+    pub fn client(&self) -> &ClientPeer {
+        &self.client
+    }
+
     /// Register a tool
     pub fn register(&mut self, tool: Arc<dyn Tool>) {
         let name = tool.name();
         self.tools.insert(name, tool);
     }
 
+    /// Keep only the tools whose names match a predicate
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.tools.retain(|name, _| keep(name));
+    }
+
     /// Get a tool by name
     pub fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
         self.tools.get(name).cloned()
     }
 
+    /// Execute a tool by name
+    ///
+    /// A tool that needs the spec-kit CLI re-probes it when it is not known
+    /// to be available, and returns an `is_error` result with installation
+    /// instructions when it is still missing.
+    pub async fn call(&self, name: &str, arguments: Value) -> Result<ToolResult> {
+        let tool = self
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Tool not found: {}", name))?;
+
+        if let (Some(cli), requirement) = (&self.cli, tool.cli_requirement()) {
+            if requirement != CliRequirement::None && cli.status() != CliStatus::Available {
+                let before = cli.status();
+                cli.ensure_available().await;
+                notify_if_listing_changed(&self.client, before, cli.status());
+            }
+            if !self.is_available(tool.as_ref()) {
+                return Ok(cli_unavailable_result(name, requirement));
+            }
+        }
+
+        tool.execute(arguments)
+            .await
+            .context("Tool execution failed")
+    }
+
     /// List all tool definitions
     pub fn list_tools(&self) -> Vec<ToolDefinition> {
         self.tools.values().map(|tool| tool.definition()).collect()
     }
 
+    /// List the tools that can run: tools that need the spec-kit CLI are
+    /// left out while it is missing
+    ///
+    /// A missing CLI is re-probed in the background when the last probe is
+    /// stale, and the client is sent `notifications/tools/list_changed`
+    /// when the result changes the list.
+    pub fn available_tools(&self) -> Vec<ToolDefinition> {
+        if self
+            .cli
+            .as_ref()
+            .is_some_and(|cli| cli.status() != CliStatus::Available)
+        {
+            self.refresh_cli();
+        }
+
+        self.tools
+            .values()
+            .filter(|tool| self.is_available(tool.as_ref()))
+            .map(|tool| tool.definition())
+            .collect()
+    }
+
+    /// Check the spec-kit CLI in the background when the last probe is
+    /// stale, using the probe cache first; a real probe also warms the uvx
+    /// environment
+    pub fn refresh_cli(&self) {
+        if let Some(cli) = &self.cli {
+            refresh_cli_status(cli, &self.client);
+        }
+    }
+
+    /// Whether a tool can run with the last known CLI status; a CLI that
+    /// was not probed yet counts as available
+    fn is_available(&self, tool: &dyn Tool) -> bool {
+        let Some(cli) = &self.cli else {
+            return true;
+        };
+        let missing = cli.status() == CliStatus::Missing;
+        match tool.cli_requirement() {
+            CliRequirement::None => true,
+            CliRequirement::Required => !missing,
+            CliRequirement::UnlessSampling => !missing || self.client.supports_sampling(),
+        }
+    }
+
     /// Check if a tool exists
     pub fn has_tool(&self, name: &str) -> bool {
         self.tools.contains_key(name)
@@ -99,21 +333,99 @@ impl Default for ToolRegistry {
     }
 }
 
+/// Probe the CLI in the background when the last probe is stale
+fn refresh_cli_status(cli: &SpecKitCli, client: &ClientPeer) {
+    if !cli.needs_probe() {
+        return;
+    }
+
+    let cli = cli.clone();
+    let client = client.clone();
+    tokio::spawn(async move {
+        let before = cli.status();
+        cli.ensure_available().await;
+        notify_if_listing_changed(&client, before, cli.status());
+    });
+}
+
+/// Tell the client the tool list changed when the CLI appeared or went
+/// missing
+fn notify_if_listing_changed(client: &ClientPeer, before: CliStatus, after: CliStatus) {
+    if (before == CliStatus::Missing) == (after == CliStatus::Missing) {
+        return;
+    }
+
+    tracing::info!(%before, %after, "Spec-kit CLI availability changed");
+    if client.is_connected() {
+        if let Err(e) = client.notify("notifications/tools/list_changed", None) {
+            tracing::warn!(error = %e, "Failed to send tools/list_changed");
+        }
+    }
+}
+
+/// Result for a tool that needs the spec-kit CLI while it is missing
+fn cli_unavailable_result(name: &str, requirement: CliRequirement) -> ToolResult {
+    let mut message = format!(
+        "{} needs the spec-kit CLI, which could not be run.\n\n\
+        Install it with: uv tool install specify-cli --from {}\n\n\
+        The server checks again on the next call (at most every {} seconds), \
+        so no restart is needed. Run speckit_check to diagnose the installation.",
+        name,
+        SPEC_KIT_SOURCE,
+        REPROBE_INTERVAL.as_secs()
+    );
+    if requirement == CliRequirement::UnlessSampling {
+        message.push_str(
+            "\n\nWithout the CLI, this tool can also draft through a client that supports MCP sampling.",
+        );
+    }
+
+    ToolResult {
+        content: vec![ContentBlock::text(message)],
+        is_error: Some(true),
+    }
+}
+
 /// Create and populate the default tool registry
 pub fn create_registry(cli: SpecKitCli) -> ToolRegistry {
-    let mut registry = ToolRegistry::new();
+    create_registry_with_config(cli, &Config::default())
+}
+
+/// Create a tool registry with the tools enabled by a configuration
+pub fn create_registry_with_config(cli: SpecKitCli, config: &Config) -> ToolRegistry {
+    let mut registry = ToolRegistry::new()
+        .with_paths(cli.paths().clone())
+        .with_cli(cli.clone());
 
     // Register all tools
     registry.register(Arc::new(InitTool::new(cli.clone())));
-    registry.register(Arc::new(CheckTool::new(cli.clone())));
+    registry.register(Arc::new(
+        CheckTool::new(cli.clone()).with_config(config.clone()),
+    ));
+    registry.register(Arc::new(FeatureCreateTool::new(cli.clone())));
+    registry.register(Arc::new(FeatureListTool::new(cli.clone())));
+    registry.register(Arc::new(FeatureSwitchTool::new(cli.clone())));
     registry.register(Arc::new(ConstitutionTool::new(cli.clone())));
     registry.register(Arc::new(SpecifyTool::new(cli.clone())));
-    registry.register(Arc::new(PlanTool::new(cli.clone())));
-    registry.register(Arc::new(TasksTool::new(cli.clone())));
+    registry.register(Arc::new(
+        PlanTool::new(cli.clone()).with_client(registry.client().clone()),
+    ));
+    registry.register(Arc::new(
+        TasksTool::new(cli.clone()).with_client(registry.client().clone()),
+    ));
     registry.register(Arc::new(ImplementTool::new(cli.clone())));
-    registry.register(Arc::new(ClarifyTool::new(cli.clone())));
+    registry.register(Arc::new(
+        ClarifyTool::new(cli.clone()).with_client(registry.client().clone()),
+    ));
     registry.register(Arc::new(AnalyzeTool::new(cli.clone())));
-    registry.register(Arc::new(ChecklistTool::new(cli)));
+    registry.register(Arc::new(ComplianceTool::new(cli.clone())));
+    registry.register(Arc::new(ChecklistTool::new(cli.clone())));
+    registry.register(Arc::new(
+        LintTool::new(cli.clone()).with_config(config.lint.clone()),
+    ));
+    registry.register(Arc::new(TraceTool::new(cli)));
+
+    registry.retain(|name| config.tools.is_enabled(name));
 
     tracing::info!(tool_count = registry.len(), "Tool registry created");
 
@@ -123,6 +435,7 @@ pub fn create_registry(cli: SpecKitCli) -> ToolRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mcp::types::ClientCapabilities;
 
     #[test]
     fn test_registry_creation() {
@@ -140,6 +453,79 @@ mod tests {
         assert!(registry.has_tool("speckit_init"));
         assert!(registry.has_tool("speckit_constitution"));
         assert!(registry.has_tool("speckit_specify"));
+        assert!(registry.has_tool("speckit_trace"));
+        assert!(registry.has_tool("speckit_compliance"));
+        assert!(registry.has_tool("speckit_lint"));
+        assert!(registry.has_tool("speckit_feature_create"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_degrades_without_cli() {
+        let cli = SpecKitCli::new()
+            .with_cli_path("/nonexistent/uvx")
+            .with_path_policy(PathPolicy::unrestricted());
+        let registry = create_registry(cli.clone());
+        let mut outgoing = registry.client().connect();
+
+        // Not probed yet: every tool is listed
+        assert_eq!(registry.available_tools().len(), registry.len());
+
+        let result = registry
+            .call("speckit_init", json!({ "project_name": "demo" }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(true));
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains(
+            "uv tool install specify-cli --from git+https://github.com/github/spec-kit.git"
+        ));
+
+        let notification = outgoing.recv().await.unwrap();
+        assert_eq!(notification["method"], "notifications/tools/list_changed");
+
+        let names: Vec<String> = registry
+            .available_tools()
+            .into_iter()
+            .map(|tool| tool.name)
+            .collect();
+        assert_eq!(names.len(), registry.len() - 3);
+        assert!(!names.contains(&"speckit_init".to_string()));
+        assert!(!names.contains(&"speckit_plan".to_string()));
+        assert!(names.contains(&"speckit_analyze".to_string()));
+
+        // Tools that don't need the CLI still run
+        let dir = tempfile::tempdir().unwrap();
+        let result = registry
+            .call(
+                "speckit_feature_list",
+                json!({ "project_path": dir.path().to_str().unwrap() }),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_error.is_none());
+
+        // Sampling-capable clients keep plan and tasks
+        registry.client().set_capabilities(ClientCapabilities {
+            sampling: Some(json!({})),
+            ..Default::default()
+        });
+        assert_eq!(registry.available_tools().len(), registry.len() - 1);
+    }
+
+    #[test]
+    fn test_registry_respects_enabled_tools() {
+        let mut config = Config::default();
+        config.tools.enabled = Some(vec![
+            "speckit_plan".to_string(),
+            "speckit_tasks".to_string(),
+        ]);
+        config.tools.disabled = vec!["speckit_tasks".to_string()];
+
+        let registry = create_registry_with_config(SpecKitCli::new(), &config);
+        assert_eq!(registry.len(), 1);
+        assert!(registry.has_tool("speckit_plan"));
     }
 
     #[test]
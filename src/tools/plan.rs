@@ -8,38 +8,75 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{ArtifactKind, DraftKind, ProjectLayout, DEFAULT_DRAFT_ATTEMPTS};
+use crate::mcp::peer::ClientPeer;
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
-use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::speckit::{CommandOptions, SpecKitCli};
+use crate::tools::drafting::{
+    draft_artifact, generator_schema, max_attempts_schema, resolve_generator, DraftRequest,
+    Generator,
+};
+use crate::tools::{commit_phase, CliRequirement, Tool};
 
 /// Parameters for the speckit_plan tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PlanParams {
-    /// Path to specification file
-    spec_file: PathBuf,
+    /// Path to specification file (defaults to the project layout)
+    #[serde(default)]
+    spec_file: Option<PathBuf>,
 
     /// Tech stack to use
     #[serde(default)]
     tech_stack: Option<String>,
 
-    /// Output path for plan file
-    #[serde(default = "default_plan_path")]
-    output_path: PathBuf,
+    /// Output path for plan file (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to produce the artifact
+    #[serde(default)]
+    generator: Generator,
+
+    /// Sampling attempts before giving up
+    #[serde(default = "default_max_attempts")]
+    max_attempts: u32,
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
 }
 
-fn default_plan_path() -> PathBuf {
-    PathBuf::from("./speckit.plan")
+fn default_max_attempts() -> u32 {
+    DEFAULT_DRAFT_ATTEMPTS
 }
 
 /// Tool for creating technical plans
 pub struct PlanTool {
     cli: SpecKitCli,
+    client: ClientPeer,
 }
 
 impl PlanTool {
     /// Create a new plan tool
     pub fn new(cli: SpecKitCli) -> Self {
-        Self { cli }
+        Self {
+            cli,
+            client: ClientPeer::new(),
+        }
+    }
+
+    /// Draft through this client's model when it supports sampling
+    pub fn with_client(mut self, client: ClientPeer) -> Self {
+        self.client = client;
+        self
     }
 }
 
@@ -48,13 +85,13 @@ impl Tool for PlanTool {
     fn definition(&self) -> ToolDefinition {
         ToolDefinition {
             name: "speckit_plan".to_string(),
-            description: "Create a technical implementation plan based on the specification, including architecture, tech stack, and approach".to_string(),
+            description: "Create a technical implementation plan based on the specification, including architecture, tech stack, and approach; without the spec-kit CLI, the plan is drafted by the client's model through MCP sampling and validated before it is written".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "spec_file": {
                         "type": "string",
-                        "description": "Path to the specification file (speckit.specify)"
+                        "description": "Path to the specification file (defaults to the active feature's spec.md, or ./speckit.specify)"
                     },
                     "tech_stack": {
                         "type": "string",
@@ -62,42 +99,85 @@ impl Tool for PlanTool {
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where the plan file will be written",
-                        "default": "./speckit.plan"
+                        "description": "Path where the plan file will be written (defaults to the active feature's plan.md, or ./speckit.plan)"
+                    },
+                    "commit": {
+                        "type": "boolean",
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
+                    },
+                    "generator": generator_schema(),
+                    "max_attempts": max_attempts_schema(),
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     }
                 },
-                "required": ["spec_file"]
+                "required": []
             })
         }
     }
 
+    fn cli_requirement(&self) -> CliRequirement {
+        CliRequirement::UnlessSampling
+    }
+
     async fn execute(&self, params: Value) -> Result<ToolResult> {
         let params: PlanParams =
             serde_json::from_value(params).context("Failed to parse plan parameters")?;
 
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let spec_file = params
+            .spec_file
+            .unwrap_or_else(|| layout.read_path(ArtifactKind::Specification));
+        let output_path = params
+            .output_path
+            .unwrap_or_else(|| layout.artifact_path(ArtifactKind::Plan));
+
         tracing::info!(
-            spec_file = %params.spec_file.display(),
-            output_path = %params.output_path.display(),
+            spec_file = %spec_file.display(),
+            output_path = %output_path.display(),
             "Creating technical plan"
         );
 
+        if resolve_generator(params.generator, &self.cli, &self.client).await == Generator::Sampling
+        {
+            return draft_artifact(
+                &self.cli,
+                &self.client,
+                DraftRequest {
+                    kind: DraftKind::Plan,
+                    layout: &layout,
+                    input: (ArtifactKind::Specification, &spec_file),
+                    output_path: &output_path,
+                    guidance: params
+                        .tech_stack
+                        .map(|stack| format!("Use this technology stack: {}", stack)),
+                    max_attempts: params.max_attempts,
+                    commit: params.commit,
+                },
+            )
+            .await;
+        }
+
         // Execute spec-kit plan command
-        let result = self
-            .cli
-            .plan(&params.spec_file, &params.output_path)
-            .await?;
+        let options = CommandOptions::in_dir(layout.root()).with_feature(layout.feature());
+        let result = self.cli.plan(&spec_file, &output_path, &options).await?;
+
+        let outcome = result.outcome();
 
-        if !result.is_success() {
+        if !outcome.success {
             return Ok(ToolResult {
                 content: vec![ContentBlock::text(format!(
                     "Failed to create plan: {}",
-                    result.stderr
+                    outcome.error_text()
                 ))],
                 is_error: Some(true),
             });
         }
 
-        let message = format!(
+        let mut message = format!(
             "Technical plan created successfully at {}\n\n\
             The plan includes:\n\
             - Architecture and system design\n\
@@ -105,9 +185,19 @@ impl Tool for PlanTool {
             - Implementation approach\n\
             - Module breakdown\n\n\
             Next step: Use speckit_tasks tool to generate actionable tasks",
-            params.output_path.display()
+            output_path.display()
         );
 
+        let details = outcome.to_markdown();
+        if !details.is_empty() {
+            message.push_str("\n\n");
+            message.push_str(&details);
+        }
+
+        if params.commit {
+            message.push_str(&commit_phase(&self.cli.paths().check(&output_path)?, "plan").await);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -152,4 +242,76 @@ mod tests {
         let result = tool.execute(params).await.unwrap();
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_plan_drafted_through_sampling() {
+        let client = ClientPeer::new();
+        let mut outgoing = client.connect();
+        client.set_capabilities(crate::mcp::types::ClientCapabilities {
+            sampling: Some(json!({})),
+            ..Default::default()
+        });
+        let tool = PlanTool::new(SpecKitCli::new_test_mode()).with_client(client.clone());
+
+        let dir = tempdir().unwrap();
+        let spec_file = dir.path().join("spec.md");
+        let output_path = dir.path().join("plan.md");
+        fs::write(&spec_file, "# Spec\n\n- **FR-001**: Export reports\n")
+            .await
+            .unwrap();
+
+        // The first draft misses a section, the second one is complete
+        let responder = tokio::spawn(async move {
+            let drafts = [
+                "# Plan: Export\n\n## Summary\n\nExport.\n",
+                "```markdown\n# Plan: Export\n\n## Summary\n\nExport.\n\n## Technical Context\n\nRust\n\n## Constitution Check\n\nPASS\n\n## Project Structure\n\nsrc/\n```",
+            ];
+            let mut requests = Vec::new();
+            for draft in drafts {
+                let request = outgoing.recv().await.unwrap();
+                let id = serde_json::from_value(request["id"].clone()).unwrap();
+                requests.push(request);
+                client.handle_response(crate::mcp::types::JsonRpcResponse::success(
+                    id,
+                    json!({
+                        "role": "assistant",
+                        "content": { "type": "text", "text": draft },
+                        "model": "test-model"
+                    }),
+                ));
+            }
+            requests
+        });
+
+        let result = tool
+            .execute(json!({
+                "spec_file": spec_file.to_str().unwrap(),
+                "output_path": output_path.to_str().unwrap(),
+                "tech_stack": "Rust + Tokio",
+                "generator": "sampling"
+            }))
+            .await
+            .unwrap();
+        let requests = responder.await.unwrap();
+
+        assert_eq!(requests[0]["method"], "sampling/createMessage");
+        let prompt = requests[0]["params"]["messages"][0]["content"]["text"]
+            .as_str()
+            .unwrap();
+        assert!(prompt.contains("Use this technology stack: Rust + Tokio"));
+        assert!(prompt.contains("- **FR-001**: Export reports"));
+        let retry = requests[1]["params"]["messages"][2]["content"]["text"]
+            .as_str()
+            .unwrap();
+        assert!(retry.contains("Missing `## Technical Context` section"));
+
+        assert!(result.is_error.is_none());
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("with test-model through MCP sampling (2 attempt(s))"));
+        let plan = fs::read_to_string(&output_path).await.unwrap();
+        assert!(plan.starts_with("# Plan: Export\n"));
+        assert!(plan.ends_with("src/\n"));
+    }
 }
@@ -8,31 +8,63 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{
+    extract_requirements, heading_matches, Amendment, ArtifactKind, MarkdownDocument,
+    ProjectLayout, RequirementKind, RequirementNumbering, Template, TemplateContext, TemplateKind,
+    WriteMode, WriteOptions,
+};
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
 use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::tools::{
+    amend_artifact, amend_schema, amendment_report, commit_phase, dry_run_result, Tool,
+};
 
 /// Parameters for the speckit_specify tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SpecifyParams {
-    /// Requirements to specify
-    requirements: String,
+    /// Requirements to specify (required unless amending)
+    #[serde(default)]
+    requirements: Option<String>,
+
+    /// Non-functional requirements (optional)
+    #[serde(default)]
+    non_functional_requirements: Option<String>,
 
     /// User stories (optional)
     #[serde(default)]
     user_stories: Option<String>,
 
-    /// Output path for specification file
-    #[serde(default = "default_specify_path")]
-    output_path: PathBuf,
+    /// Output path for specification file (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to treat an existing file
+    #[serde(default)]
+    mode: WriteMode,
+
+    /// Preview the write without touching the file
+    #[serde(default)]
+    dry_run: bool,
 
     /// Output format
     #[serde(default = "default_format")]
     format: String,
+
+    /// Section-level change to the existing specification
+    #[serde(default)]
+    amend: Option<Amendment>,
 }
 
-fn default_specify_path() -> PathBuf {
-    PathBuf::from("./speckit.specify")
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
 }
 
 fn default_format() -> String {
@@ -62,7 +94,11 @@ impl Tool for SpecifyTool {
                 "properties": {
                     "requirements": {
                         "type": "string",
-                        "description": "The requirements to specify. Can include features, constraints, user needs, etc."
+                        "description": "The requirements to specify, one per line. Each line is assigned a stable ID (FR-001, FR-002, ...); lines that already start with an ID keep it. Required unless amend is given"
+                    },
+                    "non_functional_requirements": {
+                        "type": "string",
+                        "description": "Optional non-functional requirements (performance, security, ...), one per line. Assigned NFR-001, NFR-002, ..."
                     },
                     "user_stories": {
                         "type": "string",
@@ -70,17 +106,39 @@ impl Tool for SpecifyTool {
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where the specification file will be written",
-                        "default": "./speckit.specify"
+                        "description": "Path where the specification file will be written (defaults to specs/NNN-feature/spec.md, or ./speckit.specify in flat projects)"
                     },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["create_only", "overwrite", "merge"],
+                        "default": "overwrite",
+                        "description": "How to treat an existing file: fail (create_only), replace it keeping a timestamped backup (overwrite), or replace matching ## sections and keep the rest (merge)"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "default": false,
+                        "description": "Return the would-be content and a unified diff without writing anything"
+                    },
+                    "commit": {
+                        "type": "boolean",
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
+                    },
+
                     "format": {
                         "type": "string",
                         "enum": ["markdown", "yaml", "json"],
                         "default": "markdown",
                         "description": "Output format for the specification"
-                    }
+                    },
+                    "amend": amend_schema("subsection")
                 },
-                "required": ["requirements"]
+                "required": []
             })
         }
     }
@@ -89,48 +147,168 @@ impl Tool for SpecifyTool {
         let params: SpecifyParams =
             serde_json::from_value(params).context("Failed to parse specify parameters")?;
 
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let output_path = params
+            .output_path
+            .clone()
+            .unwrap_or_else(|| layout.artifact_path(ArtifactKind::Specification));
+
         tracing::info!(
-            output_path = %params.output_path.display(),
+            output_path = %output_path.display(),
             format = %params.format,
             "Creating specification"
         );
 
-        // Format the specification content
-        let mut content = format!(
-            "# Specification\n\n## Requirements\n\n{}\n",
-            params.requirements
-        );
+        // Reuse IDs from an existing specification so plan and task references stay valid
+        let existing = match tokio::fs::read_to_string(self.cli.paths().check(&output_path)?).await
+        {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!(
+                        "Failed to read existing specification {}",
+                        output_path.display()
+                    )
+                })
+            }
+        };
+        let mut numbering = RequirementNumbering::from_existing(&existing);
+
+        let options = WriteOptions {
+            mode: params.mode,
+            dry_run: params.dry_run,
+        };
+
+        if let Some(mut amendment) = params.amend {
+            // Requirements added through an amendment get IDs like any others
+            let (kind, subsection) = match amendment.section.trim().to_lowercase().as_str() {
+                "requirements" | "functional requirements" => {
+                    (Some(RequirementKind::Functional), "Functional Requirements")
+                }
+                "non-functional requirements" => (
+                    Some(RequirementKind::NonFunctional),
+                    "Non-Functional Requirements",
+                ),
+                _ => (None, ""),
+            };
+            // Upstream-style specs keep requirements in `###` subsections of
+            // `## Requirements`; amend the subsection so its heading (and the
+            // other kind's list) survive
+            let has_subsection = MarkdownDocument::parse(&existing)
+                .sections()
+                .iter()
+                .any(|section| section.level == 3 && heading_matches(&section.heading, subsection));
+            let kind = kind.filter(|_| amendment.principle.is_none());
+            if kind.is_some() && has_subsection {
+                amendment.section = "Requirements".to_string();
+                amendment.principle = Some(subsection.to_string());
+            }
+            if let (Some(kind), Some(text)) = (kind, &amendment.content) {
+                amendment.content = Some(numbering.format_list(kind, text));
+            }
+
+            let amended = amend_artifact(self.cli.paths(), &output_path, &amendment).await?;
+            let options = WriteOptions {
+                mode: WriteMode::Overwrite,
+                ..options
+            };
+            let outcome = self
+                .cli
+                .specify(&amended.content, &output_path, &params.format, options)
+                .await?;
+            if outcome.dry_run {
+                return Ok(dry_run_result(&outcome));
+            }
+
+            let mut message = format!(
+                "Specification amended at {}\n\n{}\n\nFile: {}",
+                output_path.display(),
+                amendment_report(&amended),
+                outcome.summary()
+            );
+            if params.commit {
+                message.push_str(&commit_phase(&outcome.resolved, "specify").await);
+            }
+
+            return Ok(ToolResult {
+                content: vec![ContentBlock::text(message)],
+                is_error: None,
+            });
+        }
+
+        let requirements = params
+            .requirements
+            .context("requirements is required unless amend is given")?;
 
-        if let Some(stories) = params.user_stories {
-            content.push_str(&format!("\n## User Stories\n\n{}\n", stories));
+        // Render the specification from the project's template
+        let template = Template::load(layout.root(), TemplateKind::Spec, self.cli.paths()).await?;
+        let description = requirements
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("; ");
+        // Upstream's layout: requirements (and any non-functional ones) as
+        // subsections of `## Requirements`, stories under `## User Scenarios &
+        // Testing`. Sections without input are dropped rather than left as
+        // placeholders, including the older `## User Stories` and
+        // `## Non-Functional Requirements` of project templates.
+        let mut body = format!(
+            "### Functional Requirements\n\n{}",
+            numbering.format_list(RequirementKind::Functional, &requirements)
+        );
+        if let Some(non_functional) = &params.non_functional_requirements {
+            body.push_str(&format!(
+                "\n\n### Non-Functional Requirements\n\n{}",
+                numbering.format_list(RequirementKind::NonFunctional, non_functional)
+            ));
         }
+        let mut context = TemplateContext::for_layout(&layout, chrono::Local::now().date_naive())
+            .set("$ARGUMENTS", description)
+            .omit("Non-Functional Requirements")
+            .omit("User Stories")
+            .section("Requirements", body)
+            .omit("Success Criteria");
+        context = match &params.user_stories {
+            Some(stories) => context.section("User Scenarios & Testing", stories.trim()),
+            None => context.omit("User Scenarios & Testing"),
+        };
+        let content = template.render(&context)?;
 
         // Write specification file
-        let result = self
+        let outcome = self
             .cli
-            .specify(&content, &params.output_path, &params.format)
+            .specify(&content, &output_path, &params.format, options)
             .await?;
-
-        if !result.is_success() {
-            return Ok(ToolResult {
-                content: vec![ContentBlock::text(format!(
-                    "Failed to create specification: {}",
-                    result.stderr
-                ))],
-                is_error: Some(true),
-            });
+        if outcome.dry_run {
+            return Ok(dry_run_result(&outcome));
         }
 
-        let message = format!(
+        let requirement_ids = extract_requirements(&content)
+            .iter()
+            .map(|r| r.id.to_string())
+            .collect::<Vec<_>>();
+
+        let mut message = format!(
             "Specification created successfully at {}\n\n\
             The specification defines:\n\
             - What needs to be built (requirements)\n\
             - Who it's for and why (user stories)\n\
             - Success criteria (acceptance criteria)\n\n\
+            Requirement IDs: {}\n\
+            Reference these IDs in plan sections and tasks so speckit_trace can verify coverage.\n\n\
             Next step: Use speckit_plan tool to create a technical plan",
-            params.output_path.display()
+            output_path.display(),
+            requirement_ids.join(", ")
         );
 
+        message.push_str(&format!("\n\nFile: {}", outcome.summary()));
+
+        if params.commit {
+            message.push_str(&commit_phase(&outcome.resolved, "specify").await);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -141,6 +319,7 @@ impl Tool for SpecifyTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::PathPolicy;
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -171,4 +350,222 @@ mod tests {
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
         assert!(output_path.exists());
     }
+
+    #[tokio::test]
+    async fn test_specify_assigns_stable_ids() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = SpecifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let output_path = dir.path().join("specification.md");
+
+        let params = json!({
+            "requirements": "- Users can log in\n- Users can log out",
+            "non_functional_requirements": "Login responds within 200ms",
+            "output_path": output_path.to_str().unwrap()
+        });
+        tool.execute(params).await.unwrap();
+
+        let content = tokio::fs::read_to_string(&output_path).await.unwrap();
+        assert!(content.contains("- **FR-001**: Users can log in"));
+        assert!(content.contains("- **FR-002**: Users can log out"));
+        assert!(content.contains("- **NFR-001**: Login responds within 200ms"));
+
+        // Re-specifying keeps existing IDs and appends new ones
+        let params = json!({
+            "requirements": "- Users can log out\n- Users can reset passwords",
+            "output_path": output_path.to_str().unwrap()
+        });
+        tool.execute(params).await.unwrap();
+
+        let content = tokio::fs::read_to_string(&output_path).await.unwrap();
+        assert!(content.contains("- **FR-002**: Users can log out"));
+        assert!(content.contains("- **FR-003**: Users can reset passwords"));
+    }
+
+    #[tokio::test]
+    async fn test_specify_uses_feature_directory() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = SpecifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let feature = dir.path().join("specs/001-user-auth");
+        tokio::fs::create_dir_all(&feature).await.unwrap();
+
+        let params = json!({
+            "requirements": "Users can log in",
+            "project_path": dir.path().to_str().unwrap()
+        });
+        tool.execute(params).await.unwrap();
+
+        assert!(feature.join("spec.md").exists());
+        assert!(!dir.path().join("speckit.specify").exists());
+    }
+
+    #[tokio::test]
+    async fn test_specify_commits_artifact() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = SpecifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        crate::speckit::git::init_test_repo(dir.path());
+        let feature = dir.path().join("specs/001-user-auth");
+        tokio::fs::create_dir_all(&feature).await.unwrap();
+
+        let params = json!({
+            "requirements": "Users can log in",
+            "project_path": dir.path().to_str().unwrap(),
+            "commit": true
+        });
+        let result = tool.execute(params).await.unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("Git: committed as"));
+    }
+
+    #[tokio::test]
+    async fn test_specify_commits_relative_path_in_base_dir() {
+        let dir = tempdir().unwrap();
+        crate::speckit::git::init_test_repo(dir.path());
+        let paths = PathPolicy::new([dir.path().to_path_buf()]);
+        paths.set_base_dir(Some(dir.path().to_path_buf()));
+        let tool = SpecifyTool::new(SpecKitCli::new_test_mode().with_path_policy(paths));
+
+        let result = tool
+            .execute(json!({
+                "requirements": "Users can log in",
+                "output_path": "specs/001-user-auth/spec.md",
+                "commit": true
+            }))
+            .await
+            .unwrap();
+
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.contains("Git: committed as"), "{}", text);
+
+        let log = std::process::Command::new("git")
+            .args(["log", "-1", "--format=%s"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&log.stdout).trim(),
+            "docs(speckit): specify specs/001-user-auth/spec.md"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_specify_dry_run_and_create_only() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = SpecifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let output_path = dir.path().join("spec.md");
+        std::fs::write(&output_path, "# Old spec\n").unwrap();
+
+        let result = tool
+            .execute(json!({
+                "requirements": "Users can log in",
+                "output_path": output_path.to_str().unwrap(),
+                "dry_run": true
+            }))
+            .await
+            .unwrap();
+        let texts: Vec<&str> = result
+            .content
+            .iter()
+            .map(|block| match block {
+                ContentBlock::Text { text } => text.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert!(texts[0].contains("nothing written"));
+        assert!(texts[1].starts_with("```diff"));
+        assert!(texts[1].contains("-# Old spec"));
+        assert!(texts[2].contains("Users can log in"));
+        assert_eq!(
+            std::fs::read_to_string(&output_path).unwrap(),
+            "# Old spec\n"
+        );
+
+        let error = tool
+            .execute(json!({
+                "requirements": "Users can log in",
+                "output_path": output_path.to_str().unwrap(),
+                "mode": "create_only"
+            }))
+            .await
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("already exists"));
+    }
+
+    #[tokio::test]
+    async fn test_specify_amend_assigns_ids_and_keeps_sections() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = SpecifyTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let output_path = dir.path().join("spec.md");
+        tool.execute(json!({
+            "requirements": "Users can log in\nUsers can log out",
+            "user_stories": "As a user, I want to log in",
+            "output_path": output_path.to_str().unwrap()
+        }))
+        .await
+        .unwrap();
+
+        tool.execute(json!({
+            "output_path": output_path.to_str().unwrap(),
+            "amend": {
+                "action": "replace",
+                "section": "Requirements",
+                "content": "Users can log in\nUsers can reset their password"
+            }
+        }))
+        .await
+        .unwrap();
+
+        let content = std::fs::read_to_string(&output_path).unwrap();
+        assert!(content.contains("- **FR-001**: Users can log in"));
+        assert!(content.contains("- **FR-003**: Users can reset their password"));
+        assert!(content.contains(
+            "## Requirements *(mandatory)*\n\n### Functional Requirements\n\n- **FR-001**"
+        ));
+        assert!(!content.contains("- **FR-002**"));
+        assert!(content
+            .contains("## User Scenarios & Testing *(mandatory)*\n\nAs a user, I want to log in"));
+        assert!(content.contains("**Version**: 1.0.1"));
+
+        let error = tool
+            .execute(json!({ "output_path": output_path.to_str().unwrap() }))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("requirements is required"));
+    }
+
+    #[tokio::test]
+    async fn test_specify_unreadable_spec_is_an_error() {
+        let tool = SpecifyTool::new(SpecKitCli::new_test_mode());
+
+        let dir = tempdir().unwrap();
+        let output_path = dir.path().join("spec.md");
+        std::fs::write(&output_path, [0xff, 0xfe, 0x00]).unwrap();
+
+        // Only a missing spec starts numbering afresh
+        let error = tool
+            .execute(json!({
+                "requirements": "Users can log in",
+                "output_path": output_path.to_str().unwrap()
+            }))
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Failed to read existing specification"));
+        assert_eq!(std::fs::read(&output_path).unwrap(), [0xff, 0xfe, 0x00]);
+    }
 }
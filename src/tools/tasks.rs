@@ -8,42 +8,79 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::path::PathBuf;
 
+use crate::artifacts::{ArtifactKind, DraftKind, ProjectLayout, DEFAULT_DRAFT_ATTEMPTS};
+use crate::mcp::peer::ClientPeer;
 use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
-use crate::speckit::SpecKitCli;
-use crate::tools::Tool;
+use crate::speckit::{CommandOptions, SpecKitCli};
+use crate::tools::drafting::{
+    draft_artifact, generator_schema, max_attempts_schema, resolve_generator, DraftRequest,
+    Generator,
+};
+use crate::tools::{commit_phase, CliRequirement, Tool};
 
 /// Parameters for the speckit_tasks tool
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TasksParams {
-    /// Path to plan file
-    plan_file: PathBuf,
+    /// Path to plan file (defaults to the project layout)
+    #[serde(default)]
+    plan_file: Option<PathBuf>,
 
     /// Breakdown level
     #[serde(default = "default_breakdown_level")]
     breakdown_level: String,
 
-    /// Output path for tasks file
-    #[serde(default = "default_tasks_path")]
-    output_path: PathBuf,
+    /// Output path for tasks file (defaults to the project layout)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+
+    /// Commit the written artifact when in a git repository
+    #[serde(default)]
+    commit: bool,
+
+    /// How to produce the artifact
+    #[serde(default)]
+    generator: Generator,
+
+    /// Sampling attempts before giving up
+    #[serde(default = "default_max_attempts")]
+    max_attempts: u32,
 }
 
 fn default_breakdown_level() -> String {
     "medium".to_string()
 }
 
-fn default_tasks_path() -> PathBuf {
-    PathBuf::from("./speckit.tasks")
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+fn default_max_attempts() -> u32 {
+    DEFAULT_DRAFT_ATTEMPTS
 }
 
 /// Tool for generating task lists
 pub struct TasksTool {
     cli: SpecKitCli,
+    client: ClientPeer,
 }
 
 impl TasksTool {
     /// Create a new tasks tool
     pub fn new(cli: SpecKitCli) -> Self {
-        Self { cli }
+        Self {
+            cli,
+            client: ClientPeer::new(),
+        }
+    }
+
+    /// Draft through this client's model when it supports sampling
+    pub fn with_client(mut self, client: ClientPeer) -> Self {
+        self.client = client;
+        self
     }
 }
 
@@ -52,13 +89,13 @@ impl Tool for TasksTool {
     fn definition(&self) -> ToolDefinition {
         ToolDefinition {
             name: "speckit_tasks".to_string(),
-            description: "Generate actionable task lists from the technical plan, breaking down work into manageable items".to_string(),
+            description: "Generate actionable task lists from the technical plan, breaking down work into manageable items; without the spec-kit CLI, the tasks are drafted by the client's model through MCP sampling and validated before they are written".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "plan_file": {
                         "type": "string",
-                        "description": "Path to the plan file (speckit.plan)"
+                        "description": "Path to the plan file (defaults to the active feature's plan.md, or ./speckit.plan)"
                     },
                     "breakdown_level": {
                         "type": "string",
@@ -68,43 +105,87 @@ impl Tool for TasksTool {
                     },
                     "output_path": {
                         "type": "string",
-                        "description": "Path where the tasks file will be written",
-                        "default": "./speckit.tasks"
+                        "description": "Path where the tasks file will be written (defaults to the active feature's tasks.md, or ./speckit.tasks)"
+                    },
+                    "commit": {
+                        "type": "boolean",
+                        "description": "Commit the written artifact with a generated message when the project is a git repository",
+                        "default": false
+                    },
+                    "generator": generator_schema(),
+                    "max_attempts": max_attempts_schema(),
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
                     }
                 },
-                "required": ["plan_file"]
+                "required": []
             })
         }
     }
 
+    fn cli_requirement(&self) -> CliRequirement {
+        CliRequirement::UnlessSampling
+    }
+
     async fn execute(&self, params: Value) -> Result<ToolResult> {
         let params: TasksParams =
             serde_json::from_value(params).context("Failed to parse tasks parameters")?;
 
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let plan_file = params
+            .plan_file
+            .unwrap_or_else(|| layout.read_path(ArtifactKind::Plan));
+        let output_path = params
+            .output_path
+            .unwrap_or_else(|| layout.artifact_path(ArtifactKind::Tasks));
+
         tracing::info!(
-            plan_file = %params.plan_file.display(),
+            plan_file = %plan_file.display(),
             breakdown_level = %params.breakdown_level,
-            output_path = %params.output_path.display(),
+            output_path = %output_path.display(),
             "Generating task list"
         );
 
+        if resolve_generator(params.generator, &self.cli, &self.client).await == Generator::Sampling
+        {
+            return draft_artifact(
+                &self.cli,
+                &self.client,
+                DraftRequest {
+                    kind: DraftKind::Tasks,
+                    layout: &layout,
+                    input: (ArtifactKind::Plan, &plan_file),
+                    output_path: &output_path,
+                    guidance: Some(format!(
+                        "Use a {} level of breakdown (high = major milestones, detailed = granular tasks).",
+                        params.breakdown_level
+                    )),
+                    max_attempts: params.max_attempts,
+                    commit: params.commit,
+                },
+            )
+            .await;
+        }
+
         // Execute spec-kit tasks command
-        let result = self
-            .cli
-            .tasks(&params.plan_file, &params.output_path)
-            .await?;
+        let options = CommandOptions::in_dir(layout.root()).with_feature(layout.feature());
+        let result = self.cli.tasks(&plan_file, &output_path, &options).await?;
 
-        if !result.is_success() {
+        let outcome = result.outcome();
+
+        if !outcome.success {
             return Ok(ToolResult {
                 content: vec![ContentBlock::text(format!(
                     "Failed to generate tasks: {}",
-                    result.stderr
+                    outcome.error_text()
                 ))],
                 is_error: Some(true),
             });
         }
 
-        let message = format!(
+        let mut message = format!(
             "Task list generated successfully at {}\n\n\
             The task list includes:\n\
             - Prioritized actionable items\n\
@@ -112,9 +193,19 @@ impl Tool for TasksTool {
             - Dependencies between tasks\n\
             - Estimated effort levels\n\n\
             Next step: Use speckit_implement tool to execute the tasks",
-            params.output_path.display()
+            output_path.display()
         );
 
+        let details = outcome.to_markdown();
+        if !details.is_empty() {
+            message.push_str("\n\n");
+            message.push_str(&details);
+        }
+
+        if params.commit {
+            message.push_str(&commit_phase(&self.cli.paths().check(&output_path)?, "tasks").await);
+        }
+
         Ok(ToolResult {
             content: vec![ContentBlock::text(message)],
             is_error: None,
@@ -159,4 +250,66 @@ mod tests {
         let result = tool.execute(params).await.unwrap();
         assert!(result.is_error.is_none() || !result.is_error.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_tasks_sampling_gives_up_after_max_attempts() {
+        let client = ClientPeer::new();
+        let mut outgoing = client.connect();
+        client.set_capabilities(crate::mcp::types::ClientCapabilities {
+            sampling: Some(json!({})),
+            ..Default::default()
+        });
+        let tool = TasksTool::new(SpecKitCli::new_test_mode()).with_client(client.clone());
+
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("speckit.specify"),
+            "# Spec\n\n- **FR-001**: Export\n- **FR-002**: Import\n",
+        )
+        .await
+        .unwrap();
+        let plan_file = dir.path().join("plan.md");
+        let output_path = dir.path().join("tasks.md");
+        fs::write(&plan_file, "# Plan\n").await.unwrap();
+
+        let responder = tokio::spawn(async move {
+            let mut count = 0;
+            while let Some(request) = outgoing.recv().await {
+                count += 1;
+                let id = serde_json::from_value(request["id"].clone()).unwrap();
+                client.handle_response(crate::mcp::types::JsonRpcResponse::success(
+                    id,
+                    json!({
+                        "role": "assistant",
+                        "content": { "type": "text", "text": "# Tasks\n\n- [ ] T001 Export (FR-001)\n" },
+                        "model": "test-model"
+                    }),
+                ));
+                if count == 2 {
+                    break;
+                }
+            }
+            count
+        });
+
+        let result = tool
+            .execute(json!({
+                "project_path": dir.path().to_str().unwrap(),
+                "plan_file": plan_file.to_str().unwrap(),
+                "output_path": output_path.to_str().unwrap(),
+                "generator": "sampling",
+                "max_attempts": 2
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(responder.await.unwrap(), 2);
+        assert_eq!(result.is_error, Some(true));
+        let ContentBlock::Text { text } = &result.content[0] else {
+            panic!("expected text");
+        };
+        assert!(text.contains("failed validation after 2 attempt(s)"));
+        assert!(text.contains("No task references FR-002"));
+        assert!(!output_path.exists());
+    }
 }
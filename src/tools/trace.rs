@@ -0,0 +1,240 @@
+//! Spec-Kit Trace Tool
+//!
+//! Builds a requirement traceability matrix across spec, plan and tasks.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+use crate::artifacts::{ArtifactKind, ProjectLayout, TraceFormat, TraceabilityMatrix};
+use crate::mcp::types::{ContentBlock, ToolDefinition, ToolResult};
+use crate::speckit::SpecKitCli;
+use crate::tools::Tool;
+use crate::utils::PathPolicy;
+
+/// Parameters for the speckit_trace tool
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TraceParams {
+    /// Path to specification file (defaults to the project layout)
+    #[serde(default)]
+    spec_file: Option<PathBuf>,
+
+    /// Path to plan file (defaults to the project layout, if present)
+    #[serde(default)]
+    plan_file: Option<PathBuf>,
+
+    /// Path to tasks file (defaults to the project layout, if present)
+    #[serde(default)]
+    tasks_file: Option<PathBuf>,
+
+    /// Report format
+    #[serde(default)]
+    format: TraceFormat,
+
+    /// Output path for the report (optional)
+    #[serde(default)]
+    output_path: Option<PathBuf>,
+
+    /// Project directory used to resolve default artifact paths
+    #[serde(default = "default_project_path")]
+    project_path: PathBuf,
+}
+
+fn default_project_path() -> PathBuf {
+    PathBuf::from(".")
+}
+
+/// Tool for tracing requirements through plan and tasks
+pub struct TraceTool {
+    cli: SpecKitCli,
+}
+
+impl TraceTool {
+    /// Create a new trace tool
+    pub fn new(cli: SpecKitCli) -> Self {
+        Self { cli }
+    }
+}
+
+/// Read an optional artifact, treating a missing path as absent
+async fn read_optional(
+    paths: &PathPolicy,
+    path: Option<&Path>,
+    name: &str,
+) -> Result<Option<String>> {
+    match path {
+        Some(path) => paths
+            .read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read {} file", name))
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+#[async_trait]
+impl Tool for TraceTool {
+    fn definition(&self) -> ToolDefinition {
+        ToolDefinition {
+            name: "speckit_trace".to_string(),
+            description: "Build a requirement traceability matrix linking requirement IDs (FR-001, NFR-001, ...) to plan sections and tasks, flagging uncovered requirements and orphan tasks".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "spec_file": {
+                        "type": "string",
+                        "description": "Path to the specification file defining requirement IDs (defaults to the active feature's spec.md, or ./speckit.specify)"
+                    },
+                    "plan_file": {
+                        "type": "string",
+                        "description": "Path to the plan file (defaults to the active feature's plan.md, if present)"
+                    },
+                    "tasks_file": {
+                        "type": "string",
+                        "description": "Path to the tasks file (defaults to the active feature's tasks.md, if present)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "csv", "json"],
+                        "default": "markdown",
+                        "description": "Report format"
+                    },
+                    "output_path": {
+                        "type": "string",
+                        "description": "Path where the report will be written (optional - report is always returned)"
+                    },
+                    "project_path": {
+                        "type": "string",
+                        "description": "Project directory used to resolve default artifact paths (.specify/ and specs/NNN-feature/, or flat speckit.* files)",
+                        "default": "."
+                    }
+                },
+                "required": []
+            })
+        }
+    }
+
+    async fn execute(&self, params: Value) -> Result<ToolResult> {
+        let params: TraceParams =
+            serde_json::from_value(params).context("Failed to parse trace parameters")?;
+
+        let layout = ProjectLayout::resolve_in(&params.project_path, self.cli.paths())?;
+        let spec_file = params
+            .spec_file
+            .clone()
+            .unwrap_or_else(|| layout.read_path(ArtifactKind::Specification));
+        let plan_file = params
+            .plan_file
+            .clone()
+            .or_else(|| layout.find(ArtifactKind::Plan));
+        let tasks_file = params
+            .tasks_file
+            .clone()
+            .or_else(|| layout.find(ArtifactKind::Tasks));
+
+        tracing::info!(
+            spec_file = %spec_file.display(),
+            format = ?params.format,
+            "Building traceability matrix"
+        );
+
+        let spec = self
+            .cli
+            .paths()
+            .read_to_string(&spec_file)
+            .await
+            .context("Failed to read specification file")?;
+        let plan = read_optional(self.cli.paths(), plan_file.as_deref(), "plan").await?;
+        let tasks = read_optional(self.cli.paths(), tasks_file.as_deref(), "tasks").await?;
+
+        let matrix = TraceabilityMatrix::build(&spec, plan.as_deref(), tasks.as_deref());
+        let report = matrix.render(params.format);
+
+        if let Some(output_path) = &params.output_path {
+            self.cli
+                .paths()
+                .write(output_path, &report)
+                .await
+                .context("Failed to write traceability report")?;
+        }
+
+        let summary = format!(
+            "Traceability analysis complete!\n\n\
+            Requirements: {}\n\
+            Uncovered requirements: {}\n\
+            Orphan tasks: {}\n\
+            Undefined references: {}\n\
+            {}",
+            matrix.requirements.len(),
+            matrix.uncovered().len(),
+            matrix.orphan_tasks.len(),
+            matrix.dangling_references.len(),
+            match &params.output_path {
+                Some(path) => format!("Report: {}\n", path.display()),
+                None => String::new(),
+            }
+        );
+
+        Ok(ToolResult {
+            content: vec![ContentBlock::text(summary), ContentBlock::text(report)],
+            is_error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tokio::fs;
+
+    #[tokio::test]
+    async fn test_trace_tool_definition() {
+        let cli = SpecKitCli::new();
+        let tool = TraceTool::new(cli);
+        let def = tool.definition();
+
+        assert_eq!(def.name, "speckit_trace");
+        assert!(!def.description.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trace_tool_execute() {
+        let cli = SpecKitCli::new_test_mode();
+        let tool = TraceTool::new(cli);
+
+        let dir = tempdir().unwrap();
+        let spec_file = dir.path().join("spec.md");
+        let tasks_file = dir.path().join("tasks.md");
+        let output_path = dir.path().join("trace.csv");
+
+        fs::write(
+            &spec_file,
+            "- **FR-001**: Users MUST log in\n- **FR-002**: Users MUST log out\n",
+        )
+        .await
+        .unwrap();
+        fs::write(
+            &tasks_file,
+            "- [ ] T001 Login form (FR-001)\n- [ ] T002 CI setup\n",
+        )
+        .await
+        .unwrap();
+
+        let params = json!({
+            "spec_file": spec_file.to_str().unwrap(),
+            "tasks_file": tasks_file.to_str().unwrap(),
+            "format": "csv",
+            "output_path": output_path.to_str().unwrap()
+        });
+
+        let result = tool.execute(params).await.unwrap();
+        assert!(result.is_error.is_none() || !result.is_error.unwrap());
+
+        let report = fs::read_to_string(output_path).await.unwrap();
+        assert!(report.contains("FR-001,Users MUST log in,,T001,true"));
+        assert!(report.contains("FR-002,Users MUST log out,,,false"));
+    }
+}
@@ -1,5 +1,9 @@
 //! Utility Functions
 //!
-//! Placeholder for future utility features.
+//! Shared helpers used across the server.
 
-// Utilities will be added as needed
+pub mod atomic;
+pub mod sandbox;
+
+pub use atomic::*;
+pub use sandbox::*;
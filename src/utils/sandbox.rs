@@ -0,0 +1,310 @@
+//! Workspace Sandboxing
+//!
+//! Confines tool file access to a set of allowed workspace roots. Paths are
+//! made absolute, symlinks are resolved and `..` components are normalized
+//! before being compared against the roots, so neither traversal nor a
+//! symlink pointing outside the workspace can escape it.
+
+use anyhow::{Context, Result};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use super::atomic::write_atomic;
+use crate::artifacts::ArtifactPaths;
+use crate::speckit::SpecKitError;
+
+/// Policy deciding which paths tools may read and write
+///
+/// Clones share their roots and base directory, so changes made at runtime
+/// (for example from the client's `roots/list`) apply to every tool.
+#[derive(Debug, Clone)]
+pub struct PathPolicy {
+    /// Canonical allowed roots; `None` allows any path
+    roots: Arc<RwLock<Option<Vec<PathBuf>>>>,
+
+    /// Directory relative paths resolve against; the process's working
+    /// directory when `None`
+    base_dir: Arc<RwLock<Option<PathBuf>>>,
+
+    /// Canonical roots the policy was created with; `None` when unrestricted
+    configured: Option<Vec<PathBuf>>,
+
+    /// Whether the roots were given explicitly, so runtime updates must stay
+    /// inside them; `false` for the working-directory default
+    explicit: bool,
+
+    /// Artifact locations inside spec-kit projects
+    artifacts: ArtifactPaths,
+}
+
+impl PathPolicy {
+    /// A policy allowing paths under the given roots
+    pub fn new(roots: impl IntoIterator<Item = PathBuf>) -> Self {
+        let mut policy = Self::unrestricted();
+        policy.set_roots(roots);
+        policy.configured = policy.roots();
+        policy.explicit = true;
+        policy
+    }
+
+    /// A policy allowing any path
+    pub fn unrestricted() -> Self {
+        Self {
+            roots: Arc::new(RwLock::new(None)),
+            base_dir: Arc::new(RwLock::new(None)),
+            configured: None,
+            explicit: false,
+            artifacts: ArtifactPaths::default(),
+        }
+    }
+
+    /// A policy allowing paths under the current working directory
+    ///
+    /// The working directory is only a default: runtime updates such as the
+    /// client's roots replace it rather than being confined to it.
+    pub fn current_dir() -> Self {
+        let mut policy = match std::env::current_dir() {
+            Ok(dir) => Self::new([dir]),
+            Err(_) => Self::new(Vec::new()),
+        };
+        policy.explicit = false;
+        policy
+    }
+
+    /// Set where project layouts resolved through this policy keep their
+    /// artifacts
+    pub fn with_artifact_paths(mut self, artifacts: ArtifactPaths) -> Self {
+        self.artifacts = artifacts;
+        self
+    }
+
+    /// Artifact locations inside spec-kit projects
+    pub fn artifact_paths(&self) -> &ArtifactPaths {
+        &self.artifacts
+    }
+
+    /// Replace the allowed roots
+    ///
+    /// Roots that cannot be canonicalized (e.g. do not exist) are skipped.
+    pub fn set_roots(&self, roots: impl IntoIterator<Item = PathBuf>) {
+        let roots: Vec<PathBuf> = roots
+            .into_iter()
+            .filter_map(|root| match root.canonicalize() {
+                Ok(root) => Some(root),
+                Err(e) => {
+                    tracing::warn!(root = %root.display(), error = %e, "Ignoring workspace root");
+                    None
+                }
+            })
+            .collect();
+
+        tracing::info!(roots = ?roots, "Workspace roots updated");
+        *self.roots.write().unwrap_or_else(|e| e.into_inner()) = Some(roots);
+    }
+
+    /// Allowed roots, or `None` when unrestricted
+    pub fn roots(&self) -> Option<Vec<PathBuf>> {
+        self.roots.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Roots the policy was created with, or `None` when unrestricted
+    pub fn configured_roots(&self) -> Option<&[PathBuf]> {
+        self.configured.as_deref()
+    }
+
+    /// Roots runtime updates must stay inside: the explicitly configured
+    /// roots, or `None` when unrestricted or defaulting to the working
+    /// directory
+    ///
+    /// Runtime updates such as the client's roots may narrow the sandbox to
+    /// directories inside these roots, but never widen it.
+    pub fn bounding_roots(&self) -> Option<&[PathBuf]> {
+        self.configured.as_deref().filter(|_| self.explicit)
+    }
+
+    /// Set the directory relative paths (including the default `.`
+    /// project path) resolve against
+    pub fn set_base_dir(&self, base_dir: Option<PathBuf>) {
+        tracing::info!(base_dir = ?base_dir, "Workspace base directory updated");
+        *self.base_dir.write().unwrap_or_else(|e| e.into_inner()) = base_dir;
+    }
+
+    /// Directory relative paths resolve against, or `None` for the
+    /// process's working directory
+    pub fn base_dir(&self) -> Option<PathBuf> {
+        self.base_dir
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Resolve a path and check that it lies under an allowed root
+    ///
+    /// Returns the resolved absolute path. The path does not need to exist.
+    pub fn check(&self, path: &Path) -> std::result::Result<PathBuf, SpecKitError> {
+        let resolved = match self.base_dir() {
+            Some(base_dir) if path.is_relative() => resolve(&base_dir.join(path))?,
+            _ => resolve(path)?,
+        };
+
+        let Some(roots) = self.roots() else {
+            return Ok(resolved);
+        };
+        if roots.iter().any(|root| resolved.starts_with(root)) {
+            return Ok(resolved);
+        }
+
+        let allowed: Vec<String> = roots.iter().map(|r| r.display().to_string()).collect();
+        Err(SpecKitError::InvalidPath(format!(
+            "{} is outside the allowed workspace roots ({})",
+            path.display(),
+            if allowed.is_empty() {
+                "none configured".to_string()
+            } else {
+                allowed.join(", ")
+            }
+        )))
+    }
+
+    /// Read a file inside the workspace
+    pub async fn read_to_string(&self, path: &Path) -> Result<String> {
+        let resolved = self.check(path)?;
+        tokio::fs::read_to_string(&resolved)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))
+    }
+
+    /// Atomically write a file inside the workspace, creating its parent
+    /// directory
+    pub async fn write(&self, path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+        let resolved = self.check(path)?;
+        write_atomic(&resolved, contents).await
+    }
+}
+
+impl Default for PathPolicy {
+    fn default() -> Self {
+        Self::current_dir()
+    }
+}
+
+/// Make a path absolute, resolving symlinks and `.`/`..` components
+///
+/// Components are resolved one at a time so that `..` after a symlink moves
+/// to the parent of the link target, as the filesystem would. Components
+/// past the first missing one cannot be symlinks and are applied lexically.
+fn resolve(path: &Path) -> std::result::Result<PathBuf, SpecKitError> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+
+    let mut resolved = PathBuf::new();
+    let mut exists = true;
+    for component in absolute.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => resolved.push(component),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::Normal(name) => {
+                resolved.push(name);
+                if exists {
+                    match resolved.canonicalize() {
+                        Ok(canonical) => resolved = canonical,
+                        Err(_) => exists = false,
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_allows_paths_inside_roots() {
+        let dir = tempdir().unwrap();
+        let policy = PathPolicy::new([dir.path().to_path_buf()]);
+
+        let root = dir.path().canonicalize().unwrap();
+        assert_eq!(
+            policy
+                .check(&dir.path().join("specs/001-x/spec.md"))
+                .unwrap(),
+            root.join("specs/001-x/spec.md")
+        );
+        assert_eq!(
+            policy.check(&dir.path().join("new/../spec.md")).unwrap(),
+            root.join("spec.md")
+        );
+    }
+
+    #[test]
+    fn test_rejects_traversal() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path().join("workspace");
+        std::fs::create_dir(&workspace).unwrap();
+        let policy = PathPolicy::new([workspace.clone()]);
+
+        let error = policy.check(&workspace.join("../outside.md")).unwrap_err();
+        assert!(matches!(error, SpecKitError::InvalidPath(_)));
+        assert!(error
+            .to_string()
+            .contains("outside the allowed workspace roots"));
+
+        assert!(policy.check(&workspace.join("a/b/../../../x")).is_err());
+        assert!(policy.check(Path::new("/etc/passwd")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_symlink_escape() {
+        let dir = tempdir().unwrap();
+        let workspace = dir.path().join("workspace");
+        let outside = dir.path().join("outside");
+        std::fs::create_dir(&workspace).unwrap();
+        std::fs::create_dir(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, workspace.join("link")).unwrap();
+
+        let policy = PathPolicy::new([workspace.clone()]);
+        assert!(policy.check(&workspace.join("link/secret.md")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_roots_are_shared_between_clones() {
+        let dir = tempdir().unwrap();
+        let policy = PathPolicy::new(Vec::new());
+        let clone = policy.clone();
+        let file = dir.path().join("spec.md");
+
+        assert!(clone.write(&file, "# Spec").await.is_err());
+        policy.set_roots([dir.path().to_path_buf()]);
+        clone.write(&file, "# Spec").await.unwrap();
+        assert_eq!(clone.read_to_string(&file).await.unwrap(), "# Spec");
+    }
+
+    #[test]
+    fn test_relative_paths_resolve_against_base_dir() {
+        let dir = tempdir().unwrap();
+        let policy = PathPolicy::new([dir.path().to_path_buf()]);
+        policy
+            .clone()
+            .set_base_dir(Some(dir.path().join("project")));
+
+        let root = dir.path().canonicalize().unwrap();
+        assert_eq!(policy.check(Path::new(".")).unwrap(), root.join("project"));
+        assert_eq!(
+            policy.check(Path::new("specs/spec.md")).unwrap(),
+            root.join("project/specs/spec.md")
+        );
+        assert!(policy.check(Path::new("../../x")).is_err());
+    }
+}